@@ -57,6 +57,32 @@ fn bench_aes_encrypt(b: &mut Bencher) {
     let plaintext = rand!(16);
 
     b.iter(|| {
-        AES::new(&key).encrypt(&plaintext);
+        AES::new(&key).unwrap().encrypt(&plaintext);
+    });
+}
+
+#[bench]
+fn bench_aes_portable_encrypt(b: &mut Bencher) {
+    use aes::AES;
+    use aes::cipher::SingleBlockEncrypt;
+
+    let key = rand!(16);
+    let plaintext = rand!(16);
+
+    b.iter(|| {
+        AES::portable(&key).unwrap().encrypt(&plaintext);
+    });
+}
+
+#[bench]
+fn bench_aes_ttable_encrypt(b: &mut Bencher) {
+    use aes::ttable::AesTable;
+    use aes::cipher::SingleBlockEncrypt;
+
+    let key = rand!(16);
+    let plaintext = rand!(16);
+
+    b.iter(|| {
+        AesTable::new(&key).unwrap().encrypt(&plaintext);
     });
 }
@@ -1,5 +1,6 @@
 use test::Bencher;
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Cbc;
 use aes::utils::padding::Pkcs7Padding;
 use aes::cipher::BlockEncrypt;
@@ -7,11 +8,11 @@ use aes::cipher::BlockEncrypt;
 
 #[bench]
 fn bench_aes_cbc(b: &mut Bencher) {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let iv = rand!(16);
     let plaintext = rand!(63);
 
-    b.iter(|| Cbc::new(&key, &iv).encrypt::<Pkcs7Padding>(&plaintext));
+    b.iter(|| Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext));
 }
 
 #[bench]
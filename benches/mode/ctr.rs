@@ -1,16 +1,17 @@
 use test::Bencher;
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Ctr;
 use aes::cipher::StreamEncrypt;
 
 
 #[bench]
 fn bench_aes_ctr(b: &mut Bencher) {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let ctr = rand!(16);
     let plaintext = rand!(63);
 
-    b.iter(|| Ctr::new(&key, &ctr).encrypt(&plaintext));
+    b.iter(|| Ctr::<Aes128>::new(&key, &ctr).unwrap().encrypt(&plaintext));
 }
 
 #[bench]
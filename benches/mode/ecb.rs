@@ -1,5 +1,6 @@
 use test::Bencher;
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Ecb;
 use aes::utils::padding::Pkcs7Padding;
 use aes::cipher::BlockEncrypt;
@@ -7,10 +8,10 @@ use aes::cipher::BlockEncrypt;
 
 #[bench]
 fn bench_aes_ecb(b: &mut Bencher) {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let plaintext = rand!(63);
 
-    b.iter(|| Ecb::new(&key).encrypt::<Pkcs7Padding>(&plaintext));
+    b.iter(|| Ecb::<Aes128>::new(&key).unwrap().encrypt::<Pkcs7Padding>(&plaintext));
 }
 
 #[bench]
@@ -2,17 +2,18 @@ use test::Bencher;
 use crypto::aes::KeySize;
 use crypto::aes_gcm::AesGcm;
 use crypto::aead::AeadEncryptor;
+use aes::Aes128;
 use aes::mode::Gcm;
 use aes::cipher::AeadStreamEncrypt;
 
 
 #[bench]
 fn bench_aes_gcm(b: &mut Bencher) {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let nonce = rand!(12);
     let plaintext = rand!(63);
 
-    b.iter(|| Gcm::new(&key, &nonce, &nonce).encrypt(&plaintext));
+    b.iter(|| Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().encrypt(&plaintext));
 }
 
 #[bench]
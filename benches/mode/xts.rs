@@ -1,17 +1,18 @@
 use test::Bencher;
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Xts;
 use aes::cipher::CtsBlockEncrypt;
 
 
 #[bench]
 fn bench_aes_xts(b: &mut Bencher) {
-    let key1 = rand!(16);
-    let key2 = rand!(16);
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
     let i = rand!(16);
     let plaintext = rand!(63);
 
-    b.iter(|| Xts::new(&key1, &key2, &i).encrypt(&plaintext));
+    b.iter(|| Xts::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt(&plaintext));
 }
 
 #[bench]
@@ -20,11 +20,11 @@ use aes::utils::padding::{
 #[bench]
 fn bench_pkcs7padding(b: &mut Bencher) {
     let text = rand!(55);
-    b.iter(|| Pkcs7Padding::unpadding(&Pkcs7Padding::padding(&text, 16), 16));
+    b.iter(|| Pkcs7Padding::unpadding(&Pkcs7Padding::padding(&text, 16).unwrap(), 16));
 }
 
 #[bench]
 fn bench_nopadding(b: &mut Bencher) {
     let text = rand!(55);
-    b.iter(|| NoPadding::unpadding(&NoPadding::padding(&text, 16), 16));
+    b.iter(|| NoPadding::unpadding(&NoPadding::padding(&text, 16).unwrap(), 16));
 }
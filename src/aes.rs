@@ -1,5 +1,10 @@
-use ::utils::state::{ State, Ops, create_state };
-use ::cipher::{ SingleBlockEncrypt, SingleBlockDecrypt };
+use std::fmt;
+use std::marker::PhantomData;
+use ::utils::state::{ State, Ops };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, Block, KeyInit, FixedBlockEncrypt, FixedBlockDecrypt };
+#[cfg(target_arch = "x86_64")]
+use ::aesni::AesNi;
 
 
 lazy_static!{
@@ -34,9 +39,10 @@ lazy_static!{
         rsbox
     };
 
-    /// Only the first some of these constants are actually used – up to `rcon[10]` for AES-128 (as 11 round keys are needed).
+    /// Only the first some of these constants are actually used – up to `rcon[10]` for AES-128 (as 11 round keys are needed),
+    /// `ReducedAes` with 14 rounds and a 128-bit key goes up to `rcon[14]`.
     /// `rcon[0]` is not used in AES algorithm.
-    pub static ref RCON: [u8; 10] = {
+    pub static ref RCON: [u8; 14] = {
         fn rcon(mut x: u8) -> u8 {
             let mut c = 1;
             if x == 0 { return 0x8d };
@@ -49,8 +55,8 @@ lazy_static!{
             c
         }
 
-        let mut rconbox = [0; 10];
-        for i in 1..11 {
+        let mut rconbox = [0; 14];
+        for i in 1..15 {
             rconbox[i-1] = rcon(i as u8);
         }
         rconbox
@@ -58,10 +64,12 @@ lazy_static!{
 }
 
 
+/// Fills every round key in `round_keys`, so their count picks the number of rounds.
+///
 /// ```
 /// use aes::aes::key_expansion;
 /// let mut output = [[[0; 4]; 4]; 11];
-/// key_expansion(&[0; 16], &mut output);
+/// key_expansion(&[0; 16], &mut output).unwrap();
 /// assert_eq!(output, [
 ///     [[0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x00, 0x00]],
 ///     [[0x62, 0x63, 0x63, 0x63], [0x62, 0x63, 0x63, 0x63], [0x62, 0x63, 0x63, 0x63], [0x62, 0x63, 0x63, 0x63]],
@@ -80,7 +88,7 @@ lazy_static!{
 /// key_expansion(&[
 ///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
 ///     0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f
-/// ], &mut output);
+/// ], &mut output).unwrap();
 /// assert_eq!(output, [
 ///     [[0x00, 0x01, 0x02, 0x03], [0x04, 0x05, 0x06, 0x07], [0x08, 0x09, 0x0a, 0x0b], [0x0c, 0x0d, 0x0e, 0x0f]],
 ///     [[0x10, 0x11, 0x12, 0x13], [0x14, 0x15, 0x16, 0x17], [0x18, 0x19, 0x1a, 0x1b], [0x1c, 0x1d, 0x1e, 0x1f]],
@@ -98,11 +106,15 @@ lazy_static!{
 ///     [[0x4e, 0x5a, 0x66, 0x99], [0xa9, 0xf2, 0x4f, 0xe0], [0x7e, 0x57, 0x2b, 0xaa], [0xcd, 0xf8, 0xcd, 0xea]],
 ///     [[0x24, 0xfc, 0x79, 0xcc], [0xbf, 0x09, 0x79, 0xe9], [0x37, 0x1a, 0xc2, 0x3c], [0x6d, 0x68, 0xde, 0x36]]
 /// ]);
+///
+/// assert!(key_expansion(&[0; 20], &mut output).is_err());
 /// ```
-pub fn key_expansion(key: &[u8], round_keys: &mut [State]) {
-    let key_words = key.len() / 4;
-    debug_assert!(match key_words { 4 | 6 | 8 => true, _ => false });
-    let rounds = 10 + key_words - 4;
+pub fn key_expansion(key: &[u8], round_keys: &mut [State]) -> Result<(), DecryptFail> {
+    let key_words = match key.len() {
+        16 | 24 | 32 => key.len() / 4,
+        _ => Err(DecryptFail::InvalidKeyLength)?
+    };
+    let rounds = round_keys.len() - 1;
 
     for (i, j) in (0..key.len()).step_by(4).enumerate() {
         for n in 0..4 {
@@ -121,10 +133,12 @@ pub fn key_expansion(key: &[u8], round_keys: &mut [State]) {
         round_keys[i / 4][i % 4] =
             round_keys[(i-key_words) / 4][(i-key_words) % 4].xor(&tmp);
     }
+
+    Ok(())
 }
 
 
-fn gmul(mut a: u8, mut b: u8) -> u8 {
+pub fn gmul(mut a: u8, mut b: u8) -> u8 {
     let mut p = 0;
     for _ in 0..8 {
         if b & 1 != 0 { p ^= a };
@@ -200,91 +214,483 @@ pub fn transpose(input: &State) -> State {
     out
 }
 
-fn encrypt_core(round_keys: &[State], data: &[u8]) -> Vec<u8> {
+/// A step of the FIPS-197 Appendix C trace, named as it is there.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Step {
+    Input, Start, SBox, SRow, MCol, KSch, Output,
+    IInput, IStart, ISRow, ISBox, IKSch, IKAdd, IOutput
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Step::Input => "input",
+            Step::Start => "start",
+            Step::SBox => "s_box",
+            Step::SRow => "s_row",
+            Step::MCol => "m_col",
+            Step::KSch => "k_sch",
+            Step::Output => "output",
+            Step::IInput => "iinput",
+            Step::IStart => "istart",
+            Step::ISRow => "is_row",
+            Step::ISBox => "is_box",
+            Step::IKSch => "ik_sch",
+            Step::IKAdd => "ik_add",
+            Step::IOutput => "ioutput"
+        }
+    }
+}
+
+/// An intermediate state, displayed like a line of FIPS-197 Appendix C:
+/// `round[ 1].s_box 63cab7040953d051cd60e0e7ba70e18c`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trace {
+    pub round: usize,
+    pub step: Step,
+    pub state: State
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "round[{:2}].{} ", self.round, self.step.name())?;
+        for b in self.state.iter().flat_map(|column| column.iter()) {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// `final_mix` keeps the MixColumns of the last round, standard AES leaves it out.
+fn encrypt_core<F>(round_keys: &[State], final_mix: bool, block: &mut Block, mut trace: F)
+    where F: FnMut(usize, Step, &State)
+{
     let rounds = round_keys.len() - 1;
-    let mut state = create_state(data);
+    let mut state = load_state(block);
+    trace(0, Step::Input, &state);
+    trace(0, Step::KSch, &round_keys[0]);
     state = add_round_key(&state, &round_keys[0]);
 
-    for i in 1..rounds {
+    for i in 1..rounds + 1 {
+        trace(i, Step::Start, &state);
         state = sub_bytes(&state);
+        trace(i, Step::SBox, &state);
         state = shift_rows(&state);
-        state = mix_columns(&state);
+        trace(i, Step::SRow, &state);
+        if i != rounds || final_mix {
+            state = mix_columns(&state);
+            trace(i, Step::MCol, &state);
+        }
+        trace(i, Step::KSch, &round_keys[i]);
         state = add_round_key(&state, &round_keys[i]);
     }
 
-    state = sub_bytes(&state);
-    state = shift_rows(&state);
-    state = add_round_key(&state, &round_keys[rounds]);
-
-    state.concat()
+    trace(rounds, Step::Output, &state);
+    store_state(&state, block);
 }
 
-fn decrypt_core(round_keys: &[State], data: &[u8]) -> Vec<u8> {
+fn decrypt_core<F>(round_keys: &[State], final_mix: bool, block: &mut Block, mut trace: F)
+    where F: FnMut(usize, Step, &State)
+{
     let rounds = round_keys.len() - 1;
-    let mut state = create_state(data);
+    let mut state = load_state(block);
+    trace(0, Step::IInput, &state);
+    trace(0, Step::IKSch, &round_keys[rounds]);
     state = add_round_key(&state, &round_keys[rounds]);
+    if final_mix {
+        trace(0, Step::IKAdd, &state);
+        state = inv_mix_columns(&state);
+    }
 
-    for i in (1..rounds).rev() {
+    for i in 1..rounds + 1 {
+        trace(i, Step::IStart, &state);
         state = inv_shift_rows(&state);
+        trace(i, Step::ISRow, &state);
         state = inv_sub_bytes(&state);
-        state = add_round_key(&state, &round_keys[i]);
-        state = inv_mix_columns(&state);
+        trace(i, Step::ISBox, &state);
+        trace(i, Step::IKSch, &round_keys[rounds - i]);
+        state = add_round_key(&state, &round_keys[rounds - i]);
+        if i != rounds {
+            trace(i, Step::IKAdd, &state);
+            state = inv_mix_columns(&state);
+        }
     }
 
-    state = inv_sub_bytes(&state);
-    state = inv_shift_rows(&state);
-    state = add_round_key(&state, &round_keys[0]);
+    trace(rounds, Step::IOutput, &state);
+    store_state(&state, block);
+}
+
+fn load_state(block: &Block) -> State {
+    let mut state = [[0; 4]; 4];
+    for (i, column) in state.iter_mut().enumerate() {
+        column.copy_from_slice(&block[i * 4..(i + 1) * 4]);
+    }
+    state
+}
 
-    state.concat()
+fn store_state(state: &State, block: &mut Block) {
+    for (i, column) in state.iter().enumerate() {
+        block[i * 4..(i + 1) * 4].copy_from_slice(column);
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct AES {
-    round_keys: Vec<State>
+    round_keys: Vec<State>,
+    #[cfg(target_arch = "x86_64")]
+    ni: Option<AesNi>
 }
 
 impl AES {
-    pub fn new(key: &[u8]) -> AES {
+    /// Uses `AES-NI` when the CPU supports it, otherwise falls back to the portable implementation.
+    pub fn new(key: &[u8]) -> Result<AES, DecryptFail> {
+        let mut cipher = AES::portable(key)?;
+        #[cfg(target_arch = "x86_64")]
+        { cipher.ni = AesNi::new(key); }
+        Ok(cipher)
+    }
+
+    /// Never uses hardware acceleration.
+    pub fn portable(key: &[u8]) -> Result<AES, DecryptFail> {
         let rounds = 10 + (key.len() / 4) - 4;
         let mut round_keys = vec![[[0; 4]; 4]; rounds + 1];
-        key_expansion(key, &mut round_keys);
+        key_expansion(key, &mut round_keys)?;
 
-        AES { round_keys: round_keys }
+        Ok(AES {
+            round_keys: round_keys,
+            #[cfg(target_arch = "x86_64")]
+            ni: None
+        })
+    }
+
+    /// Portable encryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+    ///
+    /// ```
+    /// use aes::AES;
+    /// let mut block = [0; 16];
+    /// let mut trace = Vec::new();
+    /// AES::new(&[0; 16]).unwrap().encrypt_trace(&mut block, |t| trace.push(t.to_string()));
+    /// assert_eq!(trace[0], "round[ 0].input 00000000000000000000000000000000");
+    /// assert_eq!(trace[trace.len() - 1], "round[10].output 66e94bd4ef8a2c3b884cfa59ca342b2e");
+    /// ```
+    pub fn encrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+        encrypt_core(&self.round_keys, false, block, |round, step, state| f(Trace {
+            round: round,
+            step: step,
+            state: *state
+        }))
+    }
+
+    /// Portable decryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+    pub fn decrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+        decrypt_core(&self.round_keys, false, block, |round, step, state| f(Trace {
+            round: round,
+            step: step,
+            state: *state
+        }))
     }
 }
 
-impl SingleBlockEncrypt for AES {
-    fn bs() -> usize { 16 }
+impl Zeroize for AES {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+        #[cfg(target_arch = "x86_64")]
+        { if let Some(ref mut ni) = self.ni { ni.zeroize() }; }
+    }
+}
 
+impl Drop for AES {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for AES {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AES { .. }")
+    }
+}
+
+impl FixedBlockEncrypt for AES {
     /// ```
     /// use aes::AES;
     /// use aes::cipher::SingleBlockEncrypt;
     /// assert_eq!(
-    ///     AES::new(b"0123456789123456").encrypt(b"0987654321123456"),
+    ///     AES::new(b"0123456789123456").unwrap().encrypt(b"0987654321123456").unwrap(),
     ///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
     /// );
+    /// assert!(AES::new(&[0; 20]).is_err());
     /// ```
-    fn encrypt(&self, data: &[u8]) -> Vec<u8> {
-        encrypt_core(&self.round_keys, data)
+    fn encrypt_block(&self, block: &mut Block) {
+        #[cfg(target_arch = "x86_64")]
+        { if let Some(ref ni) = self.ni { return ni.encrypt_block(block) }; }
+        encrypt_core(&self.round_keys, false, block, |_, _, _| ())
     }
 }
 
-impl SingleBlockDecrypt for AES {
-    fn bs() -> usize { 16 }
-
+impl FixedBlockDecrypt for AES {
     /// ```
     /// use aes::AES;
     /// use aes::cipher::{ SingleBlockEncrypt, SingleBlockDecrypt };
     /// assert_eq!(
-    ///     AES::new(b"0123456789123456").decrypt(&AES::new(b"0123456789123456").encrypt(b"0987654321123456")),
+    ///     AES::new(b"0123456789123456").unwrap()
+    ///         .decrypt(&AES::new(b"0123456789123456").unwrap().encrypt(b"0987654321123456").unwrap())
+    ///         .unwrap(),
     ///     b"0987654321123456"
     /// );
     /// assert_eq!(
-    ///     AES::new(b"0123456789123456").encrypt(&AES::new(b"0123456789123456").decrypt(b"0987654321123456")),
+    ///     AES::new(b"0123456789123456").unwrap()
+    ///         .encrypt(&AES::new(b"0123456789123456").unwrap().decrypt(b"0987654321123456").unwrap())
+    ///         .unwrap(),
     ///     b"0987654321123456"
     /// );
     /// ```
-    fn decrypt(&self, data: &[u8]) -> Vec<u8> {
-        decrypt_core(&self.round_keys, data)
+    fn decrypt_block(&self, block: &mut Block) {
+        #[cfg(target_arch = "x86_64")]
+        { if let Some(ref ni) = self.ni { return ni.decrypt_block(block) }; }
+        decrypt_core(&self.round_keys, false, block, |_, _, _| ())
+    }
+}
+
+impl KeyInit for AES {
+    type Key = [u8];
+    fn init(key: &[u8]) -> Result<AES, DecryptFail> {
+        AES::new(key)
+    }
+}
+
+
+macro_rules! aes_fixed {
+    ( $(#[$attr:meta])* $name:ident, $key_len:expr, $rounds:expr ) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        pub struct $name {
+            round_keys: [State; $rounds + 1],
+            #[cfg(target_arch = "x86_64")]
+            ni: Option<AesNi>
+        }
+
+        impl $name {
+            /// Uses `AES-NI` when the CPU supports it, otherwise falls back to the portable implementation.
+            pub fn new(key: &[u8; $key_len]) -> $name {
+                let mut cipher = $name::portable(key);
+                #[cfg(target_arch = "x86_64")]
+                { cipher.ni = AesNi::new(key); }
+                cipher
+            }
+
+            /// Never uses hardware acceleration.
+            pub fn portable(key: &[u8; $key_len]) -> $name {
+                let mut round_keys = [[[0; 4]; 4]; $rounds + 1];
+                key_expansion(key, &mut round_keys)
+                    .expect("key length is checked by the type");
+
+                $name {
+                    round_keys: round_keys,
+                    #[cfg(target_arch = "x86_64")]
+                    ni: None
+                }
+            }
+
+            /// Portable encryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+            pub fn encrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+                encrypt_core(&self.round_keys, false, block, |round, step, state| f(Trace {
+                    round: round,
+                    step: step,
+                    state: *state
+                }))
+            }
+
+            /// Portable decryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+            pub fn decrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+                decrypt_core(&self.round_keys, false, block, |round, step, state| f(Trace {
+                    round: round,
+                    step: step,
+                    state: *state
+                }))
+            }
+        }
+
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.round_keys.zeroize();
+                #[cfg(target_arch = "x86_64")]
+                { if let Some(ref mut ni) = self.ni { ni.zeroize() }; }
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.zeroize();
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { .. }"))
+            }
+        }
+
+        impl KeyInit for $name {
+            type Key = [u8; $key_len];
+            fn init(key: &[u8; $key_len]) -> Result<$name, DecryptFail> {
+                Ok($name::new(key))
+            }
+        }
+
+        impl FixedBlockEncrypt for $name {
+            fn encrypt_block(&self, block: &mut Block) {
+                #[cfg(target_arch = "x86_64")]
+                { if let Some(ref ni) = self.ni { return ni.encrypt_block(block) }; }
+                encrypt_core(&self.round_keys, false, block, |_, _, _| ())
+            }
+        }
+
+        impl FixedBlockDecrypt for $name {
+            fn decrypt_block(&self, block: &mut Block) {
+                #[cfg(target_arch = "x86_64")]
+                { if let Some(ref ni) = self.ni { return ni.decrypt_block(block) }; }
+                decrypt_core(&self.round_keys, false, block, |_, _, _| ())
+            }
+        }
+    }
+}
+
+aes_fixed!(
+    /// AES with a 128-bit key, round keys are kept inline.
+    ///
+    /// ```
+    /// use aes::Aes128;
+    /// use aes::cipher::SingleBlockEncrypt;
+    /// assert_eq!(
+    ///     Aes128::new(b"0123456789123456").encrypt(b"0987654321123456").unwrap(),
+    ///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+    /// );
+    /// ```
+    Aes128, 16, 10
+);
+aes_fixed!(
+    /// AES with a 192-bit key, round keys are kept inline.
+    Aes192, 24, 12
+);
+aes_fixed!(
+    /// AES with a 256-bit key, round keys are kept inline.
+    Aes256, 32, 14
+);
+
+
+/// A round count for `ReducedAes`, fixed by the type like the key size of `Aes128`.
+pub trait Rounds {
+    fn rounds() -> usize;
+
+    /// Does the last round keep its MixColumns?
+    fn final_mix() -> bool { false }
+}
+
+macro_rules! rounds {
+    ( $( $name:ident = $n:expr ),* ) => {
+        $(
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl Rounds for $name {
+                fn rounds() -> usize { $n }
+            }
+        )*
+    }
+}
+
+rounds!(
+    R1 = 1, R2 = 2, R3 = 3, R4 = 4, R5 = 5, R6 = 6, R7 = 7,
+    R8 = 8, R9 = 9, R10 = 10, R11 = 11, R12 = 12, R13 = 13, R14 = 14
+);
+
+/// `R` rounds, all of them with MixColumns.
+#[derive(Clone, Copy, Debug)]
+pub struct WithFinalMix<R>(PhantomData<R>);
+
+impl<R> Rounds for WithFinalMix<R> where R: Rounds {
+    fn rounds() -> usize { R::rounds() }
+    fn final_mix() -> bool { true }
+}
+
+
+/// AES with any key size and `R::rounds()` rounds, for cryptanalysis. Never uses hardware acceleration.
+///
+/// ```
+/// use aes::aes::{ ReducedAes, R10 };
+/// use aes::cipher::SingleBlockEncrypt;
+/// assert_eq!(
+///     ReducedAes::<R10>::new(b"0123456789123456").unwrap().encrypt(b"0987654321123456").unwrap(),
+///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ReducedAes<R> {
+    round_keys: Vec<State>,
+    rounds: PhantomData<R>
+}
+
+impl<R> ReducedAes<R> where R: Rounds {
+    pub fn new(key: &[u8]) -> Result<ReducedAes<R>, DecryptFail> {
+        let mut round_keys = vec![[[0; 4]; 4]; R::rounds() + 1];
+        key_expansion(key, &mut round_keys)?;
+        Ok(ReducedAes { round_keys: round_keys, rounds: PhantomData })
+    }
+
+    /// Encryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+    pub fn encrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+        encrypt_core(&self.round_keys, R::final_mix(), block, |round, step, state| f(Trace {
+            round: round,
+            step: step,
+            state: *state
+        }))
+    }
+
+    /// Decryption, calling `f` with every intermediate state in FIPS-197 Appendix C order.
+    /// With `WithFinalMix` the extra InvMixColumns follows a `round[ 0].ik_add` step.
+    pub fn decrypt_trace<F>(&self, block: &mut Block, mut f: F) where F: FnMut(Trace) {
+        decrypt_core(&self.round_keys, R::final_mix(), block, |round, step, state| f(Trace {
+            round: round,
+            step: step,
+            state: *state
+        }))
+    }
+}
+
+impl<R> KeyInit for ReducedAes<R> where R: Rounds {
+    type Key = [u8];
+    fn init(key: &[u8]) -> Result<ReducedAes<R>, DecryptFail> {
+        ReducedAes::new(key)
+    }
+}
+
+impl<R> Zeroize for ReducedAes<R> {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+impl<R> Drop for ReducedAes<R> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<R> fmt::Debug for ReducedAes<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ReducedAes { .. }")
+    }
+}
+
+impl<R> FixedBlockEncrypt for ReducedAes<R> where R: Rounds {
+    fn encrypt_block(&self, block: &mut Block) {
+        encrypt_core(&self.round_keys, R::final_mix(), block, |_, _, _| ())
+    }
+}
+
+impl<R> FixedBlockDecrypt for ReducedAes<R> where R: Rounds {
+    fn decrypt_block(&self, block: &mut Block) {
+        decrypt_core(&self.round_keys, R::final_mix(), block, |_, _, _| ())
     }
 }
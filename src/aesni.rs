@@ -0,0 +1,230 @@
+use std::fmt;
+use std::arch::x86_64::*;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ Block, FixedBlockEncrypt, FixedBlockDecrypt };
+
+
+#[derive(Clone)]
+pub struct AesNi {
+    rounds: usize,
+    enc_keys: [__m128i; 15],
+    dec_keys: [__m128i; 15]
+}
+
+impl AesNi {
+    pub fn is_supported() -> bool {
+        is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2")
+    }
+
+    /// Returns `None` if the CPU lacks `AES-NI` or the key is not 128/192/256-bit.
+    pub fn new(key: &[u8]) -> Option<AesNi> {
+        if !AesNi::is_supported() { return None };
+
+        let mut enc_keys = unsafe { [_mm_setzero_si128(); 15] };
+        let rounds = match key.len() {
+            16 => unsafe { expand_key_128(key, &mut enc_keys) },
+            24 => unsafe { expand_key_192(key, &mut enc_keys) },
+            32 => unsafe { expand_key_256(key, &mut enc_keys) },
+            _ => return None
+        };
+
+        let mut dec_keys = enc_keys;
+        unsafe { inv_key(rounds, &enc_keys, &mut dec_keys) };
+
+        Some(AesNi { rounds: rounds, enc_keys: enc_keys, dec_keys: dec_keys })
+    }
+}
+
+impl Zeroize for AesNi {
+    fn zeroize(&mut self) {
+        self.enc_keys.zeroize();
+        self.dec_keys.zeroize();
+    }
+}
+
+impl Drop for AesNi {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for AesNi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AesNi { .. }")
+    }
+}
+
+impl FixedBlockEncrypt for AesNi {
+    /// ```
+    /// use aes::aesni::AesNi;
+    /// use aes::cipher::SingleBlockEncrypt;
+    /// if let Some(cipher) = AesNi::new(b"0123456789123456") {
+    ///     assert_eq!(
+    ///         cipher.encrypt(b"0987654321123456").unwrap(),
+    ///         [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+    ///     );
+    /// }
+    /// ```
+    fn encrypt_block(&self, block: &mut Block) {
+        unsafe { encrypt_core(&self.enc_keys[..self.rounds + 1], block) };
+    }
+}
+
+impl FixedBlockDecrypt for AesNi {
+    fn decrypt_block(&self, block: &mut Block) {
+        unsafe { decrypt_core(&self.dec_keys[..self.rounds + 1], block) };
+    }
+}
+
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn encrypt_core(round_keys: &[__m128i], block: &mut Block) {
+    let rounds = round_keys.len() - 1;
+    let mut state = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+    state = _mm_xor_si128(state, round_keys[0]);
+    for round_key in &round_keys[1..rounds] {
+        state = _mm_aesenc_si128(state, *round_key);
+    }
+    state = _mm_aesenclast_si128(state, round_keys[rounds]);
+    _mm_storeu_si128(block.as_mut_ptr() as *mut __m128i, state);
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn decrypt_core(round_keys: &[__m128i], block: &mut Block) {
+    let rounds = round_keys.len() - 1;
+    let mut state = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+    state = _mm_xor_si128(state, round_keys[0]);
+    for round_key in &round_keys[1..rounds] {
+        state = _mm_aesdec_si128(state, *round_key);
+    }
+    state = _mm_aesdeclast_si128(state, round_keys[rounds]);
+    _mm_storeu_si128(block.as_mut_ptr() as *mut __m128i, state);
+}
+
+/// Equivalent inverse cipher: reverse the schedule and apply `InvMixColumns` to the inner round keys.
+#[target_feature(enable = "aes,sse2")]
+unsafe fn inv_key(rounds: usize, enc_keys: &[__m128i; 15], dec_keys: &mut [__m128i; 15]) {
+    dec_keys[0] = enc_keys[rounds];
+    for i in 1..rounds {
+        dec_keys[i] = _mm_aesimc_si128(enc_keys[rounds - i]);
+    }
+    dec_keys[rounds] = enc_keys[0];
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn xor_shift(mut x: __m128i) -> __m128i {
+    let mut t = _mm_slli_si128(x, 4);
+    x = _mm_xor_si128(x, t);
+    t = _mm_slli_si128(t, 4);
+    x = _mm_xor_si128(x, t);
+    t = _mm_slli_si128(t, 4);
+    _mm_xor_si128(x, t)
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn expand_key_128(key: &[u8], round_keys: &mut [__m128i; 15]) -> usize {
+    macro_rules! expand {
+        ( $i:expr, $rcon:expr ) => {
+            let assist = _mm_shuffle_epi32(_mm_aeskeygenassist_si128(round_keys[$i - 1], $rcon), 0xff);
+            round_keys[$i] = _mm_xor_si128(xor_shift(round_keys[$i - 1]), assist);
+        }
+    }
+
+    round_keys[0] = _mm_loadu_si128(key.as_ptr() as *const __m128i);
+    expand!(1, 0x01);
+    expand!(2, 0x02);
+    expand!(3, 0x04);
+    expand!(4, 0x08);
+    expand!(5, 0x10);
+    expand!(6, 0x20);
+    expand!(7, 0x40);
+    expand!(8, 0x80);
+    expand!(9, 0x1b);
+    expand!(10, 0x36);
+    10
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn expand_key_192(key: &[u8], round_keys: &mut [__m128i; 15]) -> usize {
+    // Each step yields 6 words: `lo` holds 4 of them, the low half of `hi` the other 2.
+    macro_rules! expand {
+        ( $lo:ident, $hi:ident, $rcon:expr ) => {
+            let assist = _mm_shuffle_epi32(_mm_aeskeygenassist_si128($hi, $rcon), 0x55);
+            $lo = _mm_xor_si128(xor_shift($lo), assist);
+            $hi = _mm_xor_si128(
+                _mm_xor_si128($hi, _mm_slli_si128($hi, 4)),
+                _mm_shuffle_epi32($lo, 0xff)
+            );
+        }
+    }
+
+    macro_rules! concat {
+        ( $a:expr, $b:expr, $imm:expr ) => {
+            _mm_castpd_si128(_mm_shuffle_pd(_mm_castsi128_pd($a), _mm_castsi128_pd($b), $imm))
+        }
+    }
+
+    let mut lo = _mm_loadu_si128(key.as_ptr() as *const __m128i);
+    let mut hi = _mm_loadl_epi64(key[16..].as_ptr() as *const __m128i);
+    round_keys[0] = lo;
+    round_keys[1] = hi;
+
+    expand!(lo, hi, 0x01);
+    round_keys[1] = concat!(round_keys[1], lo, 0);
+    round_keys[2] = concat!(lo, hi, 1);
+    expand!(lo, hi, 0x02);
+    round_keys[3] = lo;
+    round_keys[4] = hi;
+    expand!(lo, hi, 0x04);
+    round_keys[4] = concat!(round_keys[4], lo, 0);
+    round_keys[5] = concat!(lo, hi, 1);
+    expand!(lo, hi, 0x08);
+    round_keys[6] = lo;
+    round_keys[7] = hi;
+    expand!(lo, hi, 0x10);
+    round_keys[7] = concat!(round_keys[7], lo, 0);
+    round_keys[8] = concat!(lo, hi, 1);
+    expand!(lo, hi, 0x20);
+    round_keys[9] = lo;
+    round_keys[10] = hi;
+    expand!(lo, hi, 0x40);
+    round_keys[10] = concat!(round_keys[10], lo, 0);
+    round_keys[11] = concat!(lo, hi, 1);
+    let assist = _mm_shuffle_epi32(_mm_aeskeygenassist_si128(hi, 0x80), 0x55);
+    round_keys[12] = _mm_xor_si128(xor_shift(lo), assist);
+    12
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn expand_key_256(key: &[u8], round_keys: &mut [__m128i; 15]) -> usize {
+    macro_rules! expand {
+        ( $i:expr, $rcon:expr ) => {
+            let assist = _mm_shuffle_epi32(_mm_aeskeygenassist_si128(round_keys[$i - 1], $rcon), 0xff);
+            round_keys[$i] = _mm_xor_si128(xor_shift(round_keys[$i - 2]), assist);
+        }
+    }
+
+    macro_rules! expand_odd {
+        ( $i:expr ) => {
+            let assist = _mm_shuffle_epi32(_mm_aeskeygenassist_si128(round_keys[$i - 1], 0x00), 0xaa);
+            round_keys[$i] = _mm_xor_si128(xor_shift(round_keys[$i - 2]), assist);
+        }
+    }
+
+    round_keys[0] = _mm_loadu_si128(key.as_ptr() as *const __m128i);
+    round_keys[1] = _mm_loadu_si128(key[16..].as_ptr() as *const __m128i);
+    expand!(2, 0x01);
+    expand_odd!(3);
+    expand!(4, 0x02);
+    expand_odd!(5);
+    expand!(6, 0x04);
+    expand_odd!(7);
+    expand!(8, 0x08);
+    expand_odd!(9);
+    expand!(10, 0x10);
+    expand_odd!(11);
+    expand!(12, 0x20);
+    expand_odd!(13);
+    expand!(14, 0x40);
+    14
+}
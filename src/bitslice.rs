@@ -0,0 +1,484 @@
+//! Constant-time AES, bitsliced over `u64` lanes (after BearSSL's `aes_ct64`).
+//!
+//! Four blocks are processed at once; each of the 8 words holds one bit of every byte.
+//! There are no table lookups and no secret-dependent branches, in the key schedule too.
+
+use std::{ fmt, slice };
+use ::aes::RCON;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, Block, KeyInit, FixedBlockEncrypt, FixedBlockDecrypt };
+
+
+#[derive(Clone)]
+pub struct AesBitslice {
+    round_keys: Vec<[u64; 8]>
+}
+
+impl AesBitslice {
+    pub fn new(key: &[u8]) -> Result<AesBitslice, DecryptFail> {
+        let key_words = match key.len() {
+            16 | 24 | 32 => key.len() / 4,
+            _ => Err(DecryptFail::InvalidKeyLength)?
+        };
+        let rounds = 10 + key_words - 4;
+        let mut round_keys = vec![[0; 8]; rounds + 1];
+        key_expansion(key, &mut round_keys);
+
+        Ok(AesBitslice { round_keys: round_keys })
+    }
+}
+
+impl Zeroize for AesBitslice {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+impl Drop for AesBitslice {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for AesBitslice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AesBitslice { .. }")
+    }
+}
+
+impl KeyInit for AesBitslice {
+    type Key = [u8];
+    fn init(key: &[u8]) -> Result<AesBitslice, DecryptFail> {
+        AesBitslice::new(key)
+    }
+}
+
+impl FixedBlockEncrypt for AesBitslice {
+    /// ```
+    /// use aes::bitslice::AesBitslice;
+    /// use aes::cipher::SingleBlockEncrypt;
+    /// assert_eq!(
+    ///     AesBitslice::new(b"0123456789123456").unwrap().encrypt(b"0987654321123456").unwrap(),
+    ///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+    /// );
+    /// ```
+    fn encrypt_block(&self, block: &mut Block) {
+        self.encrypt_blocks(slice::from_mut(block));
+    }
+
+    /// Four blocks at a time.
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        for chunk in blocks.chunks_mut(4) {
+            let mut q = load(chunk);
+            encrypt_core(&self.round_keys, &mut q);
+            store(&q, chunk);
+        }
+    }
+}
+
+impl FixedBlockDecrypt for AesBitslice {
+    fn decrypt_block(&self, block: &mut Block) {
+        self.decrypt_blocks(slice::from_mut(block));
+    }
+
+    /// Four blocks at a time.
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        for chunk in blocks.chunks_mut(4) {
+            let mut q = load(chunk);
+            decrypt_core(&self.round_keys, &mut q);
+            store(&q, chunk);
+        }
+    }
+}
+
+
+/// Load up to 4 blocks into bitsliced form, missing blocks are zero.
+fn load(blocks: &[Block]) -> [u64; 8] {
+    let mut q = [0; 8];
+    for (i, block) in blocks.iter().enumerate() {
+        let mut w = [0; 4];
+        for (j, b) in block.chunks(4).enumerate() {
+            w[j] = u32_le(b);
+        }
+        let (q0, q1) = interleave_in(&w);
+        q[i] = q0;
+        q[i + 4] = q1;
+    }
+    ortho(&mut q);
+    q
+}
+
+fn store(q: &[u64; 8], blocks: &mut [Block]) {
+    let mut q = *q;
+    ortho(&mut q);
+    for (i, block) in blocks.iter_mut().enumerate() {
+        let w = interleave_out(q[i], q[i + 4]);
+        for (j, b) in block.chunks_mut(4).enumerate() {
+            b.copy_from_slice(&[w[j] as u8, (w[j] >> 8) as u8, (w[j] >> 16) as u8, (w[j] >> 24) as u8]);
+        }
+    }
+}
+
+fn u32_le(b: &[u8]) -> u32 {
+    (b[0] as u32) | (b[1] as u32) << 8 | (b[2] as u32) << 16 | (b[3] as u32) << 24
+}
+
+fn key_expansion(key: &[u8], round_keys: &mut [[u64; 8]]) {
+    let key_words = key.len() / 4;
+    let total = round_keys.len() * 4;
+    let mut words = vec![0; total];
+
+    for (i, b) in key.chunks(4).enumerate() {
+        words[i] = u32_le(b);
+    }
+
+    for i in key_words..total {
+        let mut tmp = words[i - 1];
+        if i % key_words == 0 {
+            tmp = sub_word(tmp.rotate_right(8)) ^ RCON[i / key_words - 1] as u32;
+        } else if key_words > 6 && i % key_words == 4 {
+            tmp = sub_word(tmp);
+        }
+        words[i] = words[i - key_words] ^ tmp;
+    }
+
+    for (round_key, w) in round_keys.iter_mut().zip(words.chunks(4)) {
+        let mut q = [0; 8];
+        let (q0, q1) = interleave_in(&[w[0], w[1], w[2], w[3]]);
+        for i in 0..4 {
+            q[i] = q0;
+            q[i + 4] = q1;
+        }
+        ortho(&mut q);
+
+        // Keep one copy of each bit, then spread it over the 4 block lanes.
+        let comp = [
+            (q[0] & 0x1111111111111111) | (q[1] & 0x2222222222222222)
+                | (q[2] & 0x4444444444444444) | (q[3] & 0x8888888888888888),
+            (q[4] & 0x1111111111111111) | (q[5] & 0x2222222222222222)
+                | (q[6] & 0x4444444444444444) | (q[7] & 0x8888888888888888)
+        ];
+        for (u, &c) in comp.iter().enumerate() {
+            for v in 0..4 {
+                let x = (c >> v) & 0x1111111111111111;
+                round_key[u * 4 + v] = (x << 4).wrapping_sub(x);
+            }
+        }
+    }
+
+    words.zeroize();
+}
+
+fn sub_word(x: u32) -> u32 {
+    let mut q = [0; 8];
+    q[0] = x as u64;
+    ortho(&mut q);
+    sub_bytes(&mut q);
+    ortho(&mut q);
+    q[0] as u32
+}
+
+fn encrypt_core(round_keys: &[[u64; 8]], q: &mut [u64; 8]) {
+    let rounds = round_keys.len() - 1;
+    add_round_key(q, &round_keys[0]);
+
+    for round_key in &round_keys[1..rounds] {
+        sub_bytes(q);
+        shift_rows(q);
+        mix_columns(q);
+        add_round_key(q, round_key);
+    }
+
+    sub_bytes(q);
+    shift_rows(q);
+    add_round_key(q, &round_keys[rounds]);
+}
+
+fn decrypt_core(round_keys: &[[u64; 8]], q: &mut [u64; 8]) {
+    let rounds = round_keys.len() - 1;
+    add_round_key(q, &round_keys[rounds]);
+
+    for round_key in round_keys[1..rounds].iter().rev() {
+        inv_shift_rows(q);
+        inv_sub_bytes(q);
+        add_round_key(q, round_key);
+        inv_mix_columns(q);
+    }
+
+    inv_shift_rows(q);
+    inv_sub_bytes(q);
+    add_round_key(q, &round_keys[0]);
+}
+
+fn add_round_key(q: &mut [u64; 8], round_key: &[u64; 8]) {
+    for (x, k) in q.iter_mut().zip(round_key.iter()) {
+        *x ^= *k;
+    }
+}
+
+/// Boyar-Peralta S-box circuit.
+fn sub_bytes(q: &mut [u64; 8]) {
+    let (x0, x1, x2, x3, x4, x5, x6, x7) = (q[7], q[6], q[5], q[4], q[3], q[2], q[1], q[0]);
+
+    // Top linear transformation.
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section.
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation.
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+/// The inverse S-box is the forward circuit wrapped in the inverse affine transform.
+fn inv_sub_bytes(q: &mut [u64; 8]) {
+    fn inv_affine(q: &mut [u64; 8]) {
+        let (q0, q1, q2, q3, q4, q5, q6, q7) = (!q[0], !q[1], q[2], q[3], q[4], !q[5], !q[6], q[7]);
+        *q = [
+            q2 ^ q5 ^ q7,
+            q3 ^ q6 ^ q0,
+            q4 ^ q7 ^ q1,
+            q5 ^ q0 ^ q2,
+            q6 ^ q1 ^ q3,
+            q7 ^ q2 ^ q4,
+            q0 ^ q3 ^ q5,
+            q1 ^ q4 ^ q6
+        ];
+    }
+
+    inv_affine(q);
+    sub_bytes(q);
+    inv_affine(q);
+}
+
+fn shift_rows(q: &mut [u64; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x000000000000FFFF)
+            | ((*x & 0x00000000FFF00000) >> 4)
+            | ((*x & 0x00000000000F0000) << 12)
+            | ((*x & 0x0000FF0000000000) >> 8)
+            | ((*x & 0x000000FF00000000) << 8)
+            | ((*x & 0xF000000000000000) >> 12)
+            | ((*x & 0x0FFF000000000000) << 4);
+    }
+}
+
+fn inv_shift_rows(q: &mut [u64; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x000000000000FFFF)
+            | ((*x & 0x000000000FFF0000) << 4)
+            | ((*x & 0x00000000F0000000) >> 12)
+            | ((*x & 0x000000FF00000000) << 8)
+            | ((*x & 0x0000FF0000000000) >> 8)
+            | ((*x & 0x000F000000000000) << 12)
+            | ((*x & 0xFFF0000000000000) >> 4);
+    }
+}
+
+fn mix_columns(q: &mut [u64; 8]) {
+    let (q0, q1, q2, q3, q4, q5, q6, q7) = (q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]);
+    let (r0, r1, r2, r3, r4, r5, r6, r7) = (
+        q0.rotate_right(16), q1.rotate_right(16), q2.rotate_right(16), q3.rotate_right(16),
+        q4.rotate_right(16), q5.rotate_right(16), q6.rotate_right(16), q7.rotate_right(16)
+    );
+
+    *q = [
+        q7 ^ r7 ^ r0 ^ (q0 ^ r0).rotate_right(32),
+        q0 ^ r0 ^ q7 ^ r7 ^ r1 ^ (q1 ^ r1).rotate_right(32),
+        q1 ^ r1 ^ r2 ^ (q2 ^ r2).rotate_right(32),
+        q2 ^ r2 ^ q7 ^ r7 ^ r3 ^ (q3 ^ r3).rotate_right(32),
+        q3 ^ r3 ^ q7 ^ r7 ^ r4 ^ (q4 ^ r4).rotate_right(32),
+        q4 ^ r4 ^ r5 ^ (q5 ^ r5).rotate_right(32),
+        q5 ^ r5 ^ r6 ^ (q6 ^ r6).rotate_right(32),
+        q6 ^ r6 ^ r7 ^ (q7 ^ r7).rotate_right(32)
+    ];
+}
+
+fn inv_mix_columns(q: &mut [u64; 8]) {
+    let (q0, q1, q2, q3, q4, q5, q6, q7) = (q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]);
+    let (r0, r1, r2, r3, r4, r5, r6, r7) = (
+        q0.rotate_right(16), q1.rotate_right(16), q2.rotate_right(16), q3.rotate_right(16),
+        q4.rotate_right(16), q5.rotate_right(16), q6.rotate_right(16), q7.rotate_right(16)
+    );
+
+    *q = [
+        q5 ^ q6 ^ q7 ^ r0 ^ r5 ^ r7 ^ (q0 ^ q5 ^ q6 ^ r0 ^ r5).rotate_right(32),
+        q0 ^ q5 ^ r0 ^ r1 ^ r5 ^ r6 ^ r7 ^ (q1 ^ q5 ^ q7 ^ r1 ^ r5 ^ r6).rotate_right(32),
+        q0 ^ q1 ^ q6 ^ r1 ^ r2 ^ r6 ^ r7 ^ (q0 ^ q2 ^ q6 ^ r2 ^ r6 ^ r7).rotate_right(32),
+        q0 ^ q1 ^ q2 ^ q5 ^ q6 ^ r0 ^ r2 ^ r3 ^ r5
+            ^ (q0 ^ q1 ^ q3 ^ q5 ^ q6 ^ q7 ^ r0 ^ r3 ^ r5 ^ r7).rotate_right(32),
+        q1 ^ q2 ^ q3 ^ q5 ^ r1 ^ r3 ^ r4 ^ r5 ^ r6 ^ r7
+            ^ (q1 ^ q2 ^ q4 ^ q5 ^ q7 ^ r1 ^ r4 ^ r5 ^ r6).rotate_right(32),
+        q2 ^ q3 ^ q4 ^ q6 ^ r2 ^ r4 ^ r5 ^ r6 ^ r7
+            ^ (q2 ^ q3 ^ q5 ^ q6 ^ r2 ^ r5 ^ r6 ^ r7).rotate_right(32),
+        q3 ^ q4 ^ q5 ^ q7 ^ r3 ^ r5 ^ r6 ^ r7 ^ (q3 ^ q4 ^ q6 ^ q7 ^ r3 ^ r6 ^ r7).rotate_right(32),
+        q4 ^ q5 ^ q6 ^ r4 ^ r6 ^ r7 ^ (q4 ^ q5 ^ q7 ^ r4 ^ r7).rotate_right(32)
+    ];
+}
+
+/// Transpose the 8 words so that word `i` holds bit `i` of every byte.
+fn ortho(q: &mut [u64; 8]) {
+    macro_rules! swap {
+        ( $cl:expr, $ch:expr, $s:expr, $x:expr, $y:expr ) => {{
+            let (a, b) = (q[$x], q[$y]);
+            q[$x] = (a & $cl) | ((b & $cl) << $s);
+            q[$y] = ((a & $ch) >> $s) | (b & $ch);
+        }}
+    }
+
+    for &(x, y) in &[(0, 1), (2, 3), (4, 5), (6, 7)] {
+        swap!(0x5555555555555555, 0xAAAAAAAAAAAAAAAA, 1, x, y);
+    }
+    for &(x, y) in &[(0, 2), (1, 3), (4, 6), (5, 7)] {
+        swap!(0x3333333333333333, 0xCCCCCCCCCCCCCCCC, 2, x, y);
+    }
+    for &(x, y) in &[(0, 4), (1, 5), (2, 6), (3, 7)] {
+        swap!(0x0F0F0F0F0F0F0F0F, 0xF0F0F0F0F0F0F0F0, 4, x, y);
+    }
+}
+
+fn interleave_in(w: &[u32; 4]) -> (u64, u64) {
+    let mut x = [w[0] as u64, w[1] as u64, w[2] as u64, w[3] as u64];
+    for x in x.iter_mut() {
+        *x |= *x << 16;
+        *x &= 0x0000FFFF0000FFFF;
+        *x |= *x << 8;
+        *x &= 0x00FF00FF00FF00FF;
+    }
+    (x[0] | (x[2] << 8), x[1] | (x[3] << 8))
+}
+
+fn interleave_out(q0: u64, q1: u64) -> [u32; 4] {
+    let mut x = [
+        q0 & 0x00FF00FF00FF00FF,
+        q1 & 0x00FF00FF00FF00FF,
+        (q0 >> 8) & 0x00FF00FF00FF00FF,
+        (q1 >> 8) & 0x00FF00FF00FF00FF
+    ];
+    for x in x.iter_mut() {
+        *x |= *x >> 8;
+        *x &= 0x0000FFFF0000FFFF;
+    }
+    [
+        x[0] as u32 | (x[0] >> 16) as u32,
+        x[1] as u32 | (x[1] >> 16) as u32,
+        x[2] as u32 | (x[2] >> 16) as u32,
+        x[3] as u32 | (x[3] >> 16) as u32
+    ]
+}
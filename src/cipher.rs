@@ -1,10 +1,29 @@
+use ::utils::as_blocks_mut;
 use ::utils::padding::{ Padding, PaddingError };
+use ::utils::zeroize::Zeroize;
 
 
 #[derive(Debug, PartialEq)]
 pub enum DecryptFail {
     Auth,
-    UnPadding(PaddingError)
+    UnPadding(PaddingError),
+    InvalidKeyLength,
+    InvalidIvLength,
+    InvalidNonceLength,
+    InvalidTweakLength,
+    InvalidTagLength,
+    /// Input is not a whole number of blocks.
+    MisalignedInput,
+    /// Input is shorter than one block.
+    ShortInput,
+    /// Input is longer than the mode can encode.
+    LongInput,
+    /// Associated data came after the ciphertext had started.
+    AadAfterText,
+    /// The nonce has already sealed a message.
+    NonceReuse,
+    /// Merged parts of a message do not join up end to start.
+    NotAdjacent
 }
 
 impl From<PaddingError> for DecryptFail {
@@ -14,20 +33,109 @@ impl From<PaddingError> for DecryptFail {
 }
 
 
+pub type Block = [u8; 16];
+
+
+/// Build a cipher from its key; fixed-size ciphers take an array, so a wrong key size does not compile.
+pub trait KeyInit: Sized {
+    type Key: ?Sized;
+    fn init(key: &Self::Key) -> Result<Self, DecryptFail>;
+}
+
+
 pub trait SingleBlockEncrypt {
     fn bs() -> usize;
-    fn encrypt(&self, data: &[u8]) -> Vec<u8>;
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
+
+    /// Encrypt whole blocks in place. This goes through `encrypt` a block at a time,
+    /// 128-bit ciphers get `FixedBlockEncrypt::encrypt_blocks` instead and do not allocate.
+    fn encrypt_slice(&self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % Self::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for b in data.chunks_mut(Self::bs()) {
+            let mut out = self.encrypt(b)?;
+            b.copy_from_slice(&out);
+            out.zeroize();
+        }
+        Ok(())
+    }
 }
 
 pub trait SingleBlockDecrypt {
     fn bs() -> usize;
-    fn decrypt(&self, data: &[u8]) -> Vec<u8>;
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
+
+    /// Decrypt whole blocks in place, see `SingleBlockEncrypt::encrypt_slice`.
+    fn decrypt_slice(&self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % Self::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for b in data.chunks_mut(Self::bs()) {
+            let mut out = self.decrypt(b)?;
+            b.copy_from_slice(&out);
+            out.zeroize();
+        }
+        Ok(())
+    }
+}
+
+
+/// Allocation-free block encryption, 128-bit ciphers implement this instead of `SingleBlockEncrypt`.
+pub trait FixedBlockEncrypt {
+    fn encrypt_block(&self, block: &mut Block);
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        for block in blocks {
+            self.encrypt_block(block);
+        }
+    }
+}
+
+/// Allocation-free block decryption, 128-bit ciphers implement this instead of `SingleBlockDecrypt`.
+pub trait FixedBlockDecrypt {
+    fn decrypt_block(&self, block: &mut Block);
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        for block in blocks {
+            self.decrypt_block(block);
+        }
+    }
+}
+
+fn to_block(data: &[u8]) -> Result<Block, DecryptFail> {
+    if data.len() != 16 { Err(DecryptFail::MisalignedInput)? };
+    let mut block = [0; 16];
+    block.copy_from_slice(data);
+    Ok(block)
+}
+
+impl<C> SingleBlockEncrypt for C where C: FixedBlockEncrypt {
+    fn bs() -> usize { 16 }
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut block = to_block(data)?;
+        self.encrypt_block(&mut block);
+        Ok(block.to_vec())
+    }
+
+    fn encrypt_slice(&self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        self.encrypt_blocks(as_blocks_mut(data)?);
+        Ok(())
+    }
+}
+
+impl<C> SingleBlockDecrypt for C where C: FixedBlockDecrypt {
+    fn bs() -> usize { 16 }
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut block = to_block(data)?;
+        self.decrypt_block(&mut block);
+        Ok(block.to_vec())
+    }
+
+    fn decrypt_slice(&self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        self.decrypt_blocks(as_blocks_mut(data)?);
+        Ok(())
+    }
 }
 
 
 pub trait BlockEncrypt {
     fn bs(&self) -> usize;
-    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Vec<u8>;
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
 }
 
 pub trait BlockDecrypt {
@@ -36,26 +144,45 @@ pub trait BlockDecrypt {
 }
 
 
+/// In-place, unpadded; `data` must be a whole number of blocks.
+pub trait InPlaceBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail>;
+}
+
+pub trait InPlaceBlockDecrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail>;
+}
+
+
 pub trait StreamEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> Vec<u8>;
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
 }
 
 pub trait StreamDecrypt {
-    fn decrypt(&mut self, data: &[u8]) -> Vec<u8>;
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
+}
+
+
+pub trait InPlaceStreamEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]);
+}
+
+pub trait InPlaceStreamDecrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]);
 }
 
 
 pub trait CtsBlockEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> Vec<u8>;
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
 }
 
 pub trait CtsBlockDecrypt {
-    fn decrypt(&mut self, data: &[u8]) -> Vec<u8>;
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail>;
 }
 
 
 pub trait AeadStreamEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> (Vec<u8>, Vec<u8>);
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail>;
 }
 
 pub trait AeadStreamDecrypt {
@@ -5,8 +5,14 @@ extern crate num;
 #[macro_use] extern crate lazy_static;
 
 pub mod aes;
+#[cfg(target_arch = "x86_64")]
+pub mod aesni;
+pub mod bitslice;
+pub mod ttable;
+pub mod rijndael;
 pub mod cipher;
 pub mod mode;
+pub mod mac;
 pub mod utils;
 
-pub use aes::AES;
+pub use aes::{ AES, Aes128, Aes192, Aes256 };
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::marker::PhantomData;
+use ::mode::Cbc;
+use ::utils::eq;
+use ::utils::padding::{ Padding, NoPadding, BitPadding };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt, SingleBlockDecrypt,
+    BlockEncrypt
+};
+
+
+/// ISO/IEC 9797-1 MAC algorithm 1, CBC-MAC: the last block of `Cbc` under a zero IV.
+///
+/// `P` is the padding method, `ZeroPadding`, `BitPadding` or `LengthPadding`, or `NoPadding`
+/// for input that is already whole blocks. Without `LengthPadding` it is only safe for messages
+/// of a single fixed length. Each call is a whole message, padding method 3 needs the length up front.
+#[derive(Clone)]
+pub struct CbcMac<C, P = BitPadding> {
+    cbc: Cbc<C>,
+    tag_len: usize,
+    padding: PhantomData<P>
+}
+
+impl<C, P> CbcMac<C, P> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key) -> Result<Self, DecryptFail> {
+        Ok(CbcMac {
+            cbc: Cbc::new(key, &vec![0; C::bs()])?,
+            tag_len: C::bs(),
+            padding: PhantomData
+        })
+    }
+}
+
+impl<C, P> CbcMac<C, P> where C: SingleBlockEncrypt, P: Padding {
+    /// Keep the leftmost `len` bytes of the tag.
+    pub fn set_tag_len(&mut self, len: usize) -> Result<&mut Self, DecryptFail> {
+        if len == 0 || len > C::bs() { Err(DecryptFail::InvalidTagLength)? };
+        self.tag_len = len;
+        Ok(self)
+    }
+
+    pub fn compute(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut tag = last_block::<C, P>(&mut self.cbc, data)?;
+        let out = tag[..self.tag_len].to_vec();
+        tag.zeroize();
+        Ok(out)
+    }
+
+    /// Compare against `tag` in constant time.
+    pub fn verify(&mut self, data: &[u8], tag: &[u8]) -> Result<(), DecryptFail> {
+        if eq(&self.compute(data)?, tag) {
+            Ok(())
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+impl<C, P> Zeroize for CbcMac<C, P> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cbc.zeroize();
+    }
+}
+
+/// Leaves out the `Cbc`, its chaining value runs through to the untruncated tag.
+impl<C, P> fmt::Debug for CbcMac<C, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CbcMac")
+            .field("tag_len", &self.tag_len)
+            .finish()
+    }
+}
+
+
+/// ISO/IEC 9797-1 MAC algorithm 3, the retail MAC of ANSI X9.19:
+/// CBC-MAC under `key1`, then the last block is decrypted under `key2` and encrypted under `key1` again.
+///
+/// Algorithm 5 is `Cmac`.
+#[derive(Clone)]
+pub struct RetailMac<C, P = BitPadding> {
+    cbc: Cbc<C>,
+    key2: C,
+    tag_len: usize,
+    padding: PhantomData<P>
+}
+
+impl<C, P> RetailMac<C, P> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key1: &C::Key, key2: &C::Key) -> Result<Self, DecryptFail> {
+        Ok(RetailMac {
+            cbc: Cbc::new(key1, &vec![0; C::bs()])?,
+            key2: C::init(key2)?,
+            tag_len: C::bs(),
+            padding: PhantomData
+        })
+    }
+}
+
+impl<C, P> RetailMac<C, P> where C: SingleBlockEncrypt + SingleBlockDecrypt, P: Padding {
+    /// Keep the leftmost `len` bytes of the tag.
+    pub fn set_tag_len(&mut self, len: usize) -> Result<&mut Self, DecryptFail> {
+        if len == 0 || len > <C as SingleBlockEncrypt>::bs() { Err(DecryptFail::InvalidTagLength)? };
+        self.tag_len = len;
+        Ok(self)
+    }
+
+    pub fn compute(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut h = last_block::<C, P>(&mut self.cbc, data)?;
+        let g = self.key2.decrypt(&h);
+        h.zeroize();
+        let mut g = g?;
+        let tag = last_block::<C, NoPadding>(&mut self.cbc, &g);
+        g.zeroize();
+
+        let mut tag = tag?;
+        let out = tag[..self.tag_len].to_vec();
+        tag.zeroize();
+        Ok(out)
+    }
+
+    /// Compare against `tag` in constant time.
+    pub fn verify(&mut self, data: &[u8], tag: &[u8]) -> Result<(), DecryptFail> {
+        if eq(&self.compute(data)?, tag) {
+            Ok(())
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+impl<C, P> Zeroize for RetailMac<C, P> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cbc.zeroize();
+        self.key2.zeroize();
+    }
+}
+
+/// Leaves out the `Cbc`, whose chaining value runs through to the untruncated tag, and the second key.
+impl<C, P> fmt::Debug for RetailMac<C, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetailMac")
+            .field("tag_len", &self.tag_len)
+            .finish()
+    }
+}
+
+
+/// The last output block of `Cbc` over the padded data, from a zero IV.
+/// The IV goes back to zero after, so the untruncated tag does not stay behind in `cbc`.
+fn last_block<C, P>(cbc: &mut Cbc<C>, data: &[u8]) -> Result<Vec<u8>, DecryptFail>
+    where C: SingleBlockEncrypt, P: Padding
+{
+    let zero = vec![0; C::bs()];
+    cbc.set_iv(&zero)?;
+    let out = BlockEncrypt::encrypt::<P>(cbc, data);
+    cbc.set_iv(&zero)?;
+
+    let mut out = out?;
+    if out.is_empty() { Err(DecryptFail::ShortInput)? };
+    let tag = out[out.len() - C::bs()..].to_vec();
+    out.zeroize();
+    Ok(tag)
+}
@@ -0,0 +1,123 @@
+use std::{ cmp, fmt };
+use ::utils::{ xor_in_place, eq };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, SingleBlockEncrypt };
+
+
+/// The CBC chain under `Cmac` and `Xcbc`, they differ only in how their subkeys are made.
+///
+/// The last block is held back until `result`, which masks it with `full` if it is whole
+/// or pads it with `0x80 0x00..` and masks it with `partial` if not.
+#[derive(Clone)]
+pub struct Chain<C> {
+    cipher: C,
+    state: Vec<u8>,
+    buffer: Vec<u8>,
+    tag_len: usize
+}
+
+impl<C> Chain<C> where C: SingleBlockEncrypt {
+    pub fn new(cipher: C) -> Self {
+        Chain {
+            cipher: cipher,
+            state: vec![0; 16],
+            buffer: Vec::with_capacity(16),
+            tag_len: 16
+        }
+    }
+
+    pub fn set_tag_len(&mut self, len: usize) -> Result<(), DecryptFail> {
+        if len == 0 || len > 16 { Err(DecryptFail::InvalidTagLength)? };
+        self.tag_len = len;
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.state.zeroize();
+        self.state.resize(16, 0);
+        self.buffer.zeroize();
+    }
+
+    /// Chain whole blocks as they come, the last one stays in `buffer` in case the input ends there.
+    pub fn input(&mut self, data: &[u8]) -> Result<(), DecryptFail> {
+        let n = cmp::min(16 - self.buffer.len(), data.len());
+        self.buffer.extend_from_slice(&data[..n]);
+        let data = &data[n..];
+        if data.is_empty() { return Ok(()) };
+
+        chain(&self.cipher, &mut self.state, &self.buffer)?;
+        self.buffer.zeroize();
+        let pos = (data.len() - 1) / 16 * 16;
+        for b in data[..pos].chunks(16) {
+            chain(&self.cipher, &mut self.state, b)?;
+        }
+        self.buffer.extend_from_slice(&data[pos..]);
+        Ok(())
+    }
+
+    /// The tag cut to `tag_len`, a fresh `Vec` so the rest of it does not linger in the capacity.
+    pub fn result(&self, full: &[u8], partial: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut last = self.buffer.clone();
+        if last.len() == 16 {
+            xor_in_place(&mut last, full);
+        } else {
+            last.push(0x80);
+            last.resize(16, 0);
+            xor_in_place(&mut last, partial);
+        }
+        xor_in_place(&mut last, &self.state);
+
+        let tag = self.cipher.encrypt(&last);
+        last.zeroize();
+        let mut tag = tag?;
+        let out = tag[..self.tag_len].to_vec();
+        tag.zeroize();
+        Ok(out)
+    }
+
+    /// Compare against `tag` in constant time, a tag of the wrong length fails.
+    pub fn verify(&self, full: &[u8], partial: &[u8], tag: &[u8]) -> Result<(), DecryptFail> {
+        let mut calc_tag = self.result(full, partial)?;
+        let ok = eq(&calc_tag, tag);
+        calc_tag.zeroize();
+        if ok {
+            Ok(())
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+impl<C> Zeroize for Chain<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.state.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl<C> Drop for Chain<C> {
+    fn drop(&mut self) {
+        self.state.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+/// Leaves out the chaining value and the held back input.
+impl<C> fmt::Debug for Chain<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Chain")
+            .field("cipher", &self.cipher)
+            .field("tag_len", &self.tag_len)
+            .finish()
+    }
+}
+
+/// One CBC step, `state = E(state ^ block)`.
+fn chain<C>(cipher: &C, state: &mut Vec<u8>, block: &[u8]) -> Result<(), DecryptFail> where C: SingleBlockEncrypt {
+    xor_in_place(state, block);
+    let next = cipher.encrypt(state);
+    state.zeroize();
+    *state = next?;
+    Ok(())
+}
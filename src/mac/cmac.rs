@@ -0,0 +1,104 @@
+use std::fmt;
+use ::aes::AES;
+use ::utils::dbl;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, KeyInit, SingleBlockEncrypt };
+use super::chain::Chain;
+
+
+/// CMAC, NIST SP 800-38B and RFC 4493 (OMAC1). Needs a 128-bit block cipher.
+///
+/// Input streams in like `Ghash`, the last block is held back until `result`.
+#[derive(Clone)]
+pub struct Cmac<C> {
+    chain: Chain<C>,
+    k1: Vec<u8>,
+    k2: Vec<u8>
+}
+
+impl<C> Cmac<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key) -> Result<Self, DecryptFail> {
+        Cmac::with_cipher(C::init(key)?)
+    }
+}
+
+impl Cmac<AES> {
+    /// AES-CMAC-PRF-128, RFC 4615: a key of any length but 128 bits
+    /// is replaced by its AES-CMAC under the all-zero key.
+    pub fn prf(key: &[u8]) -> Result<Self, DecryptFail> {
+        let mut k = if key.len() == 16 {
+            key.to_vec()
+        } else {
+            Cmac::<AES>::new(&[0; 16])?.input(key)?.result()?
+        };
+        let mac = Cmac::new(&k[..]);
+        k.zeroize();
+        mac
+    }
+}
+
+impl<C> Cmac<C> where C: SingleBlockEncrypt {
+    /// For modes that already hold the keyed cipher, as `Eax` and `Siv` do.
+    pub fn with_cipher(cipher: C) -> Result<Self, DecryptFail> {
+        let mut k1 = cipher.encrypt(&[0; 16])?;
+        dbl(&mut k1)?;
+        let mut k2 = k1.clone();
+        dbl(&mut k2)?;
+
+        Ok(Cmac {
+            chain: Chain::new(cipher),
+            k1: k1,
+            k2: k2
+        })
+    }
+
+    /// Truncate the tag to `len` bytes, SP 800-38B advises at least 8.
+    pub fn set_tag_len(&mut self, len: usize) -> Result<&mut Self, DecryptFail> {
+        self.chain.set_tag_len(len)?;
+        Ok(self)
+    }
+
+    /// Forget the input so far, the subkeys stay.
+    pub fn reset(&mut self) -> &mut Self {
+        self.chain.reset();
+        self
+    }
+
+    pub fn input(&mut self, data: &[u8]) -> Result<&mut Self, DecryptFail> {
+        self.chain.input(data)?;
+        Ok(self)
+    }
+
+    pub fn result(&self) -> Result<Vec<u8>, DecryptFail> {
+        self.chain.result(&self.k1, &self.k2)
+    }
+
+    /// Compare against `tag` in constant time, a tag of the wrong length fails.
+    pub fn verify(&self, tag: &[u8]) -> Result<(), DecryptFail> {
+        self.chain.verify(&self.k1, &self.k2, tag)
+    }
+}
+
+impl<C> Zeroize for Cmac<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.chain.zeroize();
+        self.k1.zeroize();
+        self.k2.zeroize();
+    }
+}
+
+impl<C> Drop for Cmac<C> {
+    fn drop(&mut self) {
+        self.k1.zeroize();
+        self.k2.zeroize();
+    }
+}
+
+/// Leaves out the subkeys.
+impl<C> fmt::Debug for Cmac<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cmac")
+            .field("chain", &self.chain)
+            .finish()
+    }
+}
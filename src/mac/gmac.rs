@@ -0,0 +1,79 @@
+use std::fmt;
+use std::marker::PhantomData;
+use ::utils::{ xor, eq };
+use ::utils::ghash::Ghash;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, KeyInit, SingleBlockEncrypt };
+
+
+/// GMAC, NIST SP 800-38D: `Gcm` with an empty plaintext, the message is all associated data.
+///
+/// Input streams into `Ghash` as it comes, nothing is buffered beyond a partial block.
+#[derive(Clone)]
+pub struct Gmac<C> {
+    mac: Ghash,
+    end_tag: Vec<u8>,
+    cipher: PhantomData<C>
+}
+
+impl<C> Gmac<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, nonce: &[u8]) -> Result<Self, DecryptFail> {
+        if nonce.len() != 12 { Err(DecryptFail::InvalidNonceLength)? };
+        let x: &[u8] = &[0x00, 0x00, 0x00, 0x01];
+        let cipher = C::init(key)?;
+        let mut hash_key = cipher.encrypt(&[0; 16])?;
+        let ghash = Ghash::new(&hash_key);
+        hash_key.zeroize();
+
+        Ok(Gmac {
+            mac: ghash?,
+            end_tag: cipher.encrypt(&[nonce, x].concat())?,
+            cipher: PhantomData
+        })
+    }
+}
+
+impl<C> Gmac<C> {
+    pub fn input(&mut self, data: &[u8]) -> Result<&mut Self, DecryptFail> {
+        self.mac.update_aad(data)?;
+        Ok(self)
+    }
+
+    pub fn result(&self) -> Vec<u8> {
+        let mut hash = self.mac.finalize();
+        let tag = xor(&self.end_tag, &hash);
+        hash.zeroize();
+        tag
+    }
+
+    /// Compare against `tag` in constant time.
+    pub fn verify(&self, tag: &[u8]) -> Result<(), DecryptFail> {
+        if eq(&self.result(), tag) {
+            Ok(())
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+impl<C> Zeroize for Gmac<C> {
+    fn zeroize(&mut self) {
+        self.mac.zeroize();
+        self.end_tag.zeroize();
+    }
+}
+
+impl<C> Drop for Gmac<C> {
+    fn drop(&mut self) {
+        self.end_tag.zeroize();
+    }
+}
+
+/// Leaves out the encrypted initial counter block.
+impl<C> fmt::Debug for Gmac<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Gmac")
+            .field("mac", &self.mac)
+            .finish()
+    }
+}
@@ -0,0 +1,12 @@
+mod chain;
+mod cmac;
+mod gmac;
+mod pmac;
+mod cbc_mac;
+mod xcbc;
+
+pub use self::cmac::Cmac;
+pub use self::gmac::Gmac;
+pub use self::pmac::Pmac;
+pub use self::cbc_mac::{ CbcMac, RetailMac };
+pub use self::xcbc::Xcbc;
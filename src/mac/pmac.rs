@@ -0,0 +1,231 @@
+use std::{ cmp, fmt };
+use ::utils::{ xor, xor_in_place, eq, dbl };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, KeyInit, SingleBlockEncrypt };
+
+
+/// PMAC, Black and Rogaway, with the Gray code offsets. Needs a 128-bit block cipher.
+///
+/// Input streams in like `Cmac`. A long message can also be cut at block boundaries,
+/// each part started with `part` and fed on its own thread, and the results combined with `merge`.
+#[derive(Clone)]
+pub struct Pmac<C> {
+    cipher: C,
+    l: Vec<Vec<u8>>,
+    l_inv: Vec<u8>,
+    offset: Vec<u8>,
+    sum: Vec<u8>,
+    buffer: Vec<u8>,
+    start: usize,
+    next: usize
+}
+
+impl<C> Pmac<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key) -> Result<Self, DecryptFail> {
+        Pmac::with_cipher(C::init(key)?)
+    }
+}
+
+impl<C> Pmac<C> where C: SingleBlockEncrypt {
+    pub fn with_cipher(cipher: C) -> Result<Self, DecryptFail> {
+        let l0 = cipher.encrypt(&[0; 16])?;
+        let l_inv = half(&l0);
+        let mut l = vec![l0];
+        for i in 1..64 {
+            let mut next = l[i - 1].clone();
+            dbl(&mut next)?;
+            l.push(next);
+        }
+
+        Ok(Pmac {
+            cipher: cipher,
+            l: l,
+            l_inv: l_inv,
+            offset: vec![0; 16],
+            sum: vec![0; 16],
+            buffer: Vec::with_capacity(16),
+            start: 0,
+            next: 1
+        })
+    }
+
+    /// Hash the held back block as block `next`, it was not the last one.
+    fn flush(&mut self) -> Result<(), DecryptFail> {
+        xor_in_place(&mut self.offset, &self.l[self.next.trailing_zeros() as usize]);
+        xor_in_place(&mut self.buffer, &self.offset);
+        let out = self.cipher.encrypt(&self.buffer);
+        self.buffer.zeroize();
+        xor_in_place(&mut self.sum, &out?);
+        self.next += 1;
+        Ok(())
+    }
+
+    pub fn input(&mut self, data: &[u8]) -> Result<&mut Self, DecryptFail> {
+        let mut data = data;
+        while !data.is_empty() {
+            if self.buffer.len() == 16 {
+                self.flush()?;
+            }
+            let n = cmp::min(16 - self.buffer.len(), data.len());
+            self.buffer.extend_from_slice(&data[..n]);
+            data = &data[n..];
+        }
+        Ok(self)
+    }
+
+    /// The sum with the held back block hashed as a regular one, for a part that is not the last.
+    fn closed_sum(&self) -> Result<Vec<u8>, DecryptFail> {
+        let mut sum = self.sum.clone();
+        match self.buffer.len() {
+            0 => (),
+            16 => {
+                let mut block = xor(&self.offset, &self.l[self.next.trailing_zeros() as usize]);
+                xor_in_place(&mut block, &self.buffer);
+                let out = self.cipher.encrypt(&block);
+                block.zeroize();
+                xor_in_place(&mut sum, &out?);
+            },
+            _ => {
+                sum.zeroize();
+                Err(DecryptFail::MisalignedInput)?
+            }
+        }
+        Ok(sum)
+    }
+
+    /// The block index just past this part, counting the held back block.
+    fn end(&self) -> usize {
+        if self.buffer.is_empty() { self.next - 1 } else { self.next }
+    }
+
+    /// Combine with the part that ends where this one starts, or starts where this one ends.
+    ///
+    /// Parts that do not join up fail with `NotAdjacent`. Every part but the last must hold
+    /// a whole number of blocks, an empty last part leaves the held back block of the one before it last.
+    pub fn merge(&mut self, other: &Pmac<C>) -> Result<&mut Self, DecryptFail> {
+        if self.end() == other.start {
+            if other.end() == other.start { return Ok(self) };
+            let sum = self.closed_sum()?;
+            self.sum.zeroize();
+            self.sum = sum;
+            xor_in_place(&mut self.sum, &other.sum);
+            self.offset.copy_from_slice(&other.offset);
+            self.buffer.zeroize();
+            self.buffer.extend_from_slice(&other.buffer);
+            self.next = other.next;
+        } else if other.end() == self.start {
+            if self.end() == self.start {
+                self.sum.copy_from_slice(&other.sum);
+                self.offset.copy_from_slice(&other.offset);
+                self.buffer.zeroize();
+                self.buffer.extend_from_slice(&other.buffer);
+                self.next = other.next;
+            } else {
+                let mut sum = other.closed_sum()?;
+                xor_in_place(&mut self.sum, &sum);
+                sum.zeroize();
+            }
+            self.start = other.start;
+        } else {
+            Err(DecryptFail::NotAdjacent)?
+        }
+        Ok(self)
+    }
+
+    pub fn result(&self) -> Result<Vec<u8>, DecryptFail> {
+        let mut last = self.buffer.clone();
+        if last.len() == 16 {
+            xor_in_place(&mut last, &self.l_inv);
+        } else {
+            last.push(0x80);
+            last.resize(16, 0);
+        }
+        xor_in_place(&mut last, &self.sum);
+
+        let tag = self.cipher.encrypt(&last);
+        last.zeroize();
+        tag
+    }
+
+    /// Compare against `tag` in constant time.
+    pub fn verify(&self, tag: &[u8]) -> Result<(), DecryptFail> {
+        if eq(&self.result()?, tag) {
+            Ok(())
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+impl<C> Pmac<C> where C: SingleBlockEncrypt + Clone {
+    /// An empty `Pmac` for the part of the message that starts at block `index`, counting from 0.
+    pub fn part(&self, index: usize) -> Self {
+        let mut gray = index ^ (index >> 1);
+        let mut offset = vec![0; 16];
+        for l in &self.l {
+            if gray & 1 == 1 {
+                xor_in_place(&mut offset, l);
+            }
+            gray >>= 1;
+        }
+
+        Pmac {
+            cipher: self.cipher.clone(),
+            l: self.l.clone(),
+            l_inv: self.l_inv.clone(),
+            offset: offset,
+            sum: vec![0; 16],
+            buffer: Vec::with_capacity(16),
+            start: index,
+            next: index + 1
+        }
+    }
+}
+
+impl<C> Zeroize for Pmac<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        for l in &mut self.l {
+            l.zeroize();
+        }
+        self.l_inv.zeroize();
+        self.offset.zeroize();
+        self.sum.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl<C> Drop for Pmac<C> {
+    fn drop(&mut self) {
+        for l in &mut self.l {
+            l.zeroize();
+        }
+        self.l_inv.zeroize();
+        self.offset.zeroize();
+        self.sum.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+/// Leaves out the L table, the offset, the sum and the held back input.
+impl<C> fmt::Debug for Pmac<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pmac")
+            .field("cipher", &self.cipher)
+            .field("start", &self.start)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+/// Multiply by `x^-1` in GF(2^128), big-endian, `L(-1)` of the paper.
+fn half(b: &[u8]) -> Vec<u8> {
+    let carry = b[15] & 1;
+    let mut out = vec![0; 16];
+    out[0] = (b[0] >> 1) ^ (0x80 * carry);
+    for i in 1..16 {
+        out[i] = (b[i] >> 1) | (b[i - 1] << 7);
+    }
+    out[15] ^= 0x43 * carry;
+    out
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+use ::aes::AES;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, SingleBlockEncrypt };
+use super::chain::Chain;
+
+
+/// AES-XCBC-MAC, RFC 3566, with a 128-bit key.
+///
+/// Input streams in like `Cmac`. The IPsec integrity algorithm AES-XCBC-MAC-96 is `mac_96`,
+/// the IKEv2 PRF AES-XCBC-PRF-128 of RFC 4434 is `prf`.
+#[derive(Clone)]
+pub struct Xcbc {
+    chain: Chain<AES>,
+    k2: Vec<u8>,
+    k3: Vec<u8>
+}
+
+impl Xcbc {
+    pub fn new(key: &[u8]) -> Result<Self, DecryptFail> {
+        if key.len() != 16 { Err(DecryptFail::InvalidKeyLength)? };
+        let derive = AES::new(key)?;
+        let mut k1 = derive.encrypt(&[0x01; 16])?;
+        let cipher = AES::new(&k1);
+        k1.zeroize();
+
+        Ok(Xcbc {
+            chain: Chain::new(cipher?),
+            k2: derive.encrypt(&[0x02; 16])?,
+            k3: derive.encrypt(&[0x03; 16])?
+        })
+    }
+
+    /// AES-XCBC-MAC-96, the tag truncated to 96 bits.
+    pub fn mac_96(key: &[u8]) -> Result<Self, DecryptFail> {
+        let mut mac = Xcbc::new(key)?;
+        mac.chain.set_tag_len(12)?;
+        Ok(mac)
+    }
+
+    /// AES-XCBC-PRF-128, RFC 4434: a shorter key is padded with zeros,
+    /// a longer one is replaced by its AES-XCBC-MAC under the all-zero key.
+    pub fn prf(key: &[u8]) -> Result<Self, DecryptFail> {
+        let mut k = if key.len() <= 16 {
+            let mut k = key.to_vec();
+            k.resize(16, 0);
+            k
+        } else {
+            Xcbc::new(&[0; 16])?.input(key)?.result()?
+        };
+        let mac = Xcbc::new(&k);
+        k.zeroize();
+        mac
+    }
+
+    /// Truncate the tag to `len` bytes.
+    pub fn set_tag_len(&mut self, len: usize) -> Result<&mut Self, DecryptFail> {
+        self.chain.set_tag_len(len)?;
+        Ok(self)
+    }
+
+    /// Forget the input so far, the derived keys stay.
+    pub fn reset(&mut self) -> &mut Self {
+        self.chain.reset();
+        self
+    }
+
+    pub fn input(&mut self, data: &[u8]) -> Result<&mut Self, DecryptFail> {
+        self.chain.input(data)?;
+        Ok(self)
+    }
+
+    pub fn result(&self) -> Result<Vec<u8>, DecryptFail> {
+        self.chain.result(&self.k2, &self.k3)
+    }
+
+    /// Compare against `tag` in constant time, a tag of the wrong length fails.
+    pub fn verify(&self, tag: &[u8]) -> Result<(), DecryptFail> {
+        self.chain.verify(&self.k2, &self.k3, tag)
+    }
+}
+
+impl Zeroize for Xcbc {
+    fn zeroize(&mut self) {
+        self.chain.zeroize();
+        self.k2.zeroize();
+        self.k3.zeroize();
+    }
+}
+
+impl Drop for Xcbc {
+    fn drop(&mut self) {
+        self.k2.zeroize();
+        self.k3.zeroize();
+    }
+}
+
+/// Leaves out the derived keys.
+impl fmt::Debug for Xcbc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xcbc")
+            .field("chain", &self.chain)
+            .finish()
+    }
+}
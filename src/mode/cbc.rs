@@ -1,69 +1,187 @@
-use ::AES;
-use ::utils::xor;
-use ::utils::padding::Padding;
+use std::mem;
+use std::marker::PhantomData;
+use ::utils::{ xor, xor_in_place };
+use ::utils::padding::{ Padding, NoPadding };
+use ::utils::zeroize::Zeroize;
 use ::cipher::{
-    DecryptFail,
+    DecryptFail, KeyInit,
     SingleBlockEncrypt, SingleBlockDecrypt,
-    BlockEncrypt, BlockDecrypt
+    BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt,
+    CtsBlockEncrypt, CtsBlockDecrypt
 };
 
 
+/// Where ciphertext stealing puts the partial block, NIST SP 800-38A addendum.
+pub trait Stealing {
+    /// Do the last two blocks trade places? `partial` is whether the last one is short.
+    fn swap(partial: bool) -> bool;
+}
+
+/// The partial block stays second to last.
+#[derive(Clone, Copy, Debug)]
+pub struct Cs1;
+
+/// The partial block goes last, full blocks keep plain CBC order.
+#[derive(Clone, Copy, Debug)]
+pub struct Cs2;
+
+/// The last two blocks always trade places, as in Kerberos (RFC 3962).
+#[derive(Clone, Copy, Debug)]
+pub struct Cs3;
+
+impl Stealing for Cs1 {
+    fn swap(_: bool) -> bool { false }
+}
+
+impl Stealing for Cs2 {
+    fn swap(partial: bool) -> bool { partial }
+}
+
+impl Stealing for Cs3 {
+    fn swap(_: bool) -> bool { true }
+}
+
+
+/// `V` only picks the ciphertext stealing variant, plain CBC is the same for all of them.
 #[derive(Clone, Debug)]
-pub struct Cbc<C> {
+pub struct Cbc<C, V = Cs3> {
     cipher: C,
-    iv: Vec<u8>
+    iv: Vec<u8>,
+    next_iv: Vec<u8>,
+    stealing: PhantomData<V>
+}
+
+impl<C, V> Cbc<C, V> where C: SingleBlockEncrypt {
+    pub fn set_iv(&mut self, iv: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        self.iv.zeroize();
+        self.iv.extend_from_slice(iv);
+        Ok(self)
+    }
+}
+
+impl<C, V> Cbc<C, V> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Cbc<C, V>, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        Ok(Cbc {
+            cipher: C::init(key)?,
+            iv: iv.into(),
+            next_iv: vec![0; C::bs()],
+            stealing: PhantomData
+        })
+    }
 }
 
-impl<C> Cbc<C> {
-    pub fn set_iv(&mut self, iv: &[u8]) -> &mut Self {
-        self.iv = iv.into();
-        self
+impl<C, V> Zeroize for Cbc<C, V> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.iv.zeroize();
     }
 }
 
-impl Cbc<AES> {
-    pub fn new(key: &[u8], iv: &[u8]) -> Cbc<AES> {
-        Cbc { cipher: AES::new(key), iv: iv.into() }
+/// The cipher wipes itself when dropped. The chaining value is wiped too, `CbcMac` keeps its tag there.
+impl<C, V> Drop for Cbc<C, V> {
+    fn drop(&mut self) {
+        self.iv.zeroize();
     }
 }
 
-impl<C> BlockEncrypt for Cbc<C> where C: SingleBlockEncrypt {
+impl<C, V> BlockEncrypt for Cbc<C, V> where C: SingleBlockEncrypt {
     fn bs(&self) -> usize { C::bs() }
-    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Vec<u8> {
-        debug_assert_eq!(self.iv.len(), self.bs());
-        P::padding(data, self.bs()).chunks(self.bs())
-            .map(|b| {
-                let text = self.cipher.encrypt(&xor(
-                    b,
-                    &self.iv
-                ));
-                self.set_iv(&text);
-                text
-            })
-            .fold(Vec::new(), |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            })
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = P::padding(data, self.bs())?;
+        InPlaceBlockEncrypt::encrypt_in_place(self, &mut out)?;
+        Ok(out)
     }
 }
 
-impl<C> BlockDecrypt for Cbc<C> where C: SingleBlockDecrypt {
+impl<C, V> BlockDecrypt for Cbc<C, V> where C: SingleBlockDecrypt {
     fn bs(&self) -> usize { C::bs() }
     fn decrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
-        debug_assert_eq!(self.iv.len(), self.bs());
-        let out = data.chunks(self.bs())
-            .map(|b| {
-                let iv = self.iv.clone();
-                self.set_iv(b);
-                xor(
-                    &self.cipher.decrypt(b),
-                    &iv
-                )
-            })
-            .fold(Vec::new(), |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            });
-        P::unpadding(&out, self.bs()).map_err(|err| err.into())
+        let mut out = data.to_vec();
+        InPlaceBlockDecrypt::decrypt_in_place(self, &mut out)?;
+        let text = P::unpadding(&out, self.bs());
+        out.zeroize();
+        text.map_err(|err| err.into())
+    }
+}
+
+impl<C, V> InPlaceBlockEncrypt for Cbc<C, V> where C: SingleBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % C::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for block in data.chunks_mut(C::bs()) {
+            xor_in_place(block, &self.iv);
+            self.cipher.encrypt_slice(block)?;
+            self.iv.copy_from_slice(block);
+        }
+        Ok(())
+    }
+}
+
+/// The ciphertext block is kept in `next_iv` while it is decrypted over.
+impl<C, V> InPlaceBlockDecrypt for Cbc<C, V> where C: SingleBlockDecrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % C::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for block in data.chunks_mut(C::bs()) {
+            self.next_iv.copy_from_slice(block);
+            self.cipher.decrypt_slice(block)?;
+            xor_in_place(block, &self.iv);
+            mem::swap(&mut self.iv, &mut self.next_iv);
+        }
+        Ok(())
+    }
+}
+
+/// The last block is zero padded for CBC, then the padding's worth is cut from the block before it.
+impl<C, V> CtsBlockEncrypt for Cbc<C, V> where C: SingleBlockEncrypt, V: Stealing {
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let bs = C::bs();
+        if data.len() < bs { Err(DecryptFail::ShortInput)? };
+        let d = (data.len() - 1) % bs + 1;
+        let pos = data.len() - d;
+
+        let mut padded = data.to_vec();
+        padded.resize(pos + bs, 0);
+        let out = BlockEncrypt::encrypt::<NoPadding>(self, &padded)?;
+        padded.zeroize();
+        if pos == 0 { return Ok(out) };
+
+        let (head, last) = out.split_at(pos);
+        let (head, stolen) = head.split_at(pos - bs);
+        Ok(if V::swap(d != bs) {
+            [head, last, &stolen[..d]].concat()
+        } else {
+            [head, &stolen[..d], last].concat()
+        })
+    }
+}
+
+/// Decrypting the last block gives back the tail that was cut from the one before it.
+impl<C, V> CtsBlockDecrypt for Cbc<C, V> where C: SingleBlockDecrypt, V: Stealing {
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let bs = C::bs();
+        if data.len() < bs { Err(DecryptFail::ShortInput)? };
+        let d = (data.len() - 1) % bs + 1;
+        let pos = data.len() - d;
+        if pos == 0 { return BlockDecrypt::decrypt::<NoPadding>(self, data) };
+
+        let (head, tail) = data.split_at(pos - bs);
+        let (stolen, last) = if V::swap(d != bs) {
+            let (last, stolen) = tail.split_at(bs);
+            (stolen, last)
+        } else {
+            tail.split_at(d)
+        };
+
+        let mut z = self.cipher.decrypt(last)?;
+        let prev = [stolen, &z[d..]].concat();
+        let text = xor(&z[..d], stolen);
+        z.zeroize();
+
+        let mut out = BlockDecrypt::decrypt::<NoPadding>(self, &[head, &prev].concat())?;
+        out.extend_from_slice(&text);
+        self.iv = last.into();
+        Ok(out)
     }
 }
@@ -0,0 +1,161 @@
+use std::fmt;
+use ::utils::{ xor, xor_in_place, eq };
+use ::utils::zeroize::Zeroize;
+use ::mode::Ctr;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    StreamEncrypt, StreamDecrypt,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// Counter with CBC-MAC, NIST SP 800-38C and RFC 3610.
+///
+/// The MAC covers the message length up front, so every call is a whole message.
+/// The nonce is fixed in `new` and seals one message, a second `encrypt` fails with `NonceReuse`.
+#[derive(Clone)]
+pub struct Ccm<C> {
+    cipher: Ctr<C>,
+    mac: C,
+    nonce: Vec<u8>,
+    aad: Vec<u8>,
+    tag_len: usize,
+    used: bool
+}
+
+impl<C> Ccm<C> where C: KeyInit + SingleBlockEncrypt {
+    /// `nonce` is 7 to 13 bytes, the rest of the counter block holds the message length.
+    /// `tag_len` is an even number of bytes from 4 to 16.
+    pub fn new(key: &C::Key, nonce: &[u8], aad: &[u8], tag_len: usize) -> Result<Self, DecryptFail> {
+        if nonce.len() < 7 || nonce.len() > 13 { Err(DecryptFail::InvalidNonceLength)? };
+        if tag_len < 4 || tag_len > 16 || tag_len % 2 != 0 { Err(DecryptFail::InvalidTagLength)? };
+        let cipher = Ctr::<C>::new(key, &counter_block(nonce, 0))?;
+
+        Ok(Ccm {
+            cipher: cipher,
+            mac: C::init(key)?,
+            nonce: nonce.into(),
+            aad: aad.into(),
+            tag_len: tag_len,
+            used: false
+        })
+    }
+}
+
+impl<C> Ccm<C> where C: SingleBlockEncrypt {
+    /// The first `tag_len` bytes of the CBC-MAC over `B_0`, the associated data and `data`.
+    fn cbc_mac(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let l = 15 - self.nonce.len();
+        if l < 8 && (data.len() as u64) >> (8 * l) != 0 { Err(DecryptFail::LongInput)? };
+
+        let flags = if self.aad.is_empty() { 0 } else { 0x40 }
+            | ((self.tag_len as u8 - 2) / 2) << 3
+            | (l as u8 - 1);
+        let mut b = vec![flags];
+        b.extend_from_slice(&self.nonce);
+        b.extend_from_slice(&be_bytes(data.len() as u64, l));
+
+        if !self.aad.is_empty() {
+            let len = self.aad.len() as u64;
+            if len < 0xff00 {
+                b.extend_from_slice(&be_bytes(len, 2));
+            } else if len >> 32 == 0 {
+                b.extend_from_slice(&[0xff, 0xfe]);
+                b.extend_from_slice(&be_bytes(len, 4));
+            } else {
+                b.extend_from_slice(&[0xff, 0xff]);
+                b.extend_from_slice(&be_bytes(len, 8));
+            }
+            b.extend_from_slice(&self.aad);
+            b.resize((b.len() + 15) / 16 * 16, 0);
+        }
+        b.extend_from_slice(data);
+        b.resize((b.len() + 15) / 16 * 16, 0);
+
+        let mut x = vec![0; 16];
+        for block in b.chunks(16) {
+            xor_in_place(&mut x, block);
+            let next = self.mac.encrypt(&x);
+            x.zeroize();
+            x = next?;
+        }
+        b.zeroize();
+
+        x.truncate(self.tag_len);
+        Ok(x)
+    }
+
+    /// Rewind the counter, return the first `tag_len` bytes of `S_0`.
+    fn reset(&mut self) -> Result<Vec<u8>, DecryptFail> {
+        self.cipher.set_ctr(&counter_block(&self.nonce, 0))?;
+        let mut s0 = self.cipher.encrypt(&[0; 16])?;
+        s0.truncate(self.tag_len);
+        Ok(s0)
+    }
+}
+
+impl<C> Zeroize for Ccm<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.mac.zeroize();
+    }
+}
+
+impl<C> fmt::Debug for Ccm<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ccm")
+            .field("cipher", &self.cipher)
+            .field("nonce", &self.nonce)
+            .field("tag_len", &self.tag_len)
+            .field("used", &self.used)
+            .finish()
+    }
+}
+
+impl<C> AeadStreamEncrypt for Ccm<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        if self.used { Err(DecryptFail::NonceReuse)? };
+        let mut mac = self.cbc_mac(data)?;
+        self.used = true;
+        let mut s0 = self.reset()?;
+        let tag = xor(&mac, &s0);
+        mac.zeroize();
+        s0.zeroize();
+
+        Ok((self.cipher.encrypt(data)?, tag))
+    }
+}
+
+impl<C> AeadStreamDecrypt for Ccm<C> where C: SingleBlockEncrypt {
+    fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut s0 = self.reset()?;
+        let mut out = self.cipher.decrypt(data)?;
+        let calc_tag = self.cbc_mac(&out).map(|mut mac| {
+            let tag = xor(&mac, &s0);
+            mac.zeroize();
+            tag
+        });
+        s0.zeroize();
+
+        if eq(&calc_tag?, tag) {
+            Ok(out)
+        } else {
+            out.zeroize();
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+/// `A_i`, the flags byte only holds the size of the counter field.
+fn counter_block(nonce: &[u8], i: u64) -> Vec<u8> {
+    let l = 15 - nonce.len();
+    let mut a = vec![l as u8 - 1];
+    a.extend_from_slice(nonce);
+    a.extend_from_slice(&be_bytes(i, l));
+    a
+}
+
+fn be_bytes(x: u64, len: usize) -> Vec<u8> {
+    (0..len).rev().map(|i| (x >> (8 * i)) as u8).collect()
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::marker::PhantomData;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    StreamEncrypt, StreamDecrypt
+};
+
+
+/// How much feedback a `Cfb` step takes, in bits.
+pub trait Segment {
+    fn bits(bs: usize) -> usize;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bits1;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bits8;
+
+/// The whole block, 128 bits for AES.
+#[derive(Clone, Copy, Debug)]
+pub struct FullBlock;
+
+impl Segment for Bits1 {
+    fn bits(_: usize) -> usize { 1 }
+}
+
+impl Segment for Bits8 {
+    fn bits(_: usize) -> usize { 8 }
+}
+
+impl Segment for FullBlock {
+    fn bits(bs: usize) -> usize { bs * 8 }
+}
+
+pub type Cfb1<C> = Cfb<C, Bits1>;
+pub type Cfb8<C> = Cfb<C, Bits8>;
+pub type Cfb128<C> = Cfb<C, FullBlock>;
+
+
+/// Cipher feedback with `S`-sized segments.
+///
+/// Byte-sized segments can be split across calls. The keystream block is used up from `pos`,
+/// each used byte is overwritten by the ciphertext to feed back once the segment is complete.
+#[derive(Clone)]
+pub struct Cfb<C, S> {
+    cipher: C,
+    register: Vec<u8>,
+    keystream: Vec<u8>,
+    pos: usize,
+    segment: PhantomData<S>
+}
+
+impl<C, S> Cfb<C, S> where C: SingleBlockEncrypt {
+    pub fn set_iv(&mut self, iv: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        self.register.zeroize();
+        self.register.extend_from_slice(iv);
+        self.keystream[..].zeroize();
+        self.pos = 0;
+        Ok(self)
+    }
+}
+
+impl<C, S> Cfb<C, S> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Cfb<C, S>, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        Ok(Cfb {
+            cipher: C::init(key)?,
+            register: iv.into(),
+            keystream: vec![0; C::bs()],
+            pos: 0,
+            segment: PhantomData
+        })
+    }
+}
+
+impl<C, S> Cfb<C, S> where C: SingleBlockEncrypt, S: Segment {
+    fn process(&mut self, data: &[u8], decrypt: bool) -> Result<Vec<u8>, DecryptFail> {
+        match S::bits(C::bs()) {
+            1 => self.process_bits(data, decrypt),
+            n => self.process_bytes(data, decrypt, n / 8)
+        }
+    }
+
+    /// Once `n` bytes of a segment are done, the register shifts left by `n` in place
+    /// and takes in the ciphertext held in the keystream block.
+    fn process_bytes(&mut self, data: &[u8], decrypt: bool, n: usize) -> Result<Vec<u8>, DecryptFail> {
+        let bs = C::bs();
+        let mut out = Vec::with_capacity(data.len());
+
+        for &b in data {
+            if self.pos == 0 {
+                let mut keystream = self.cipher.encrypt(&self.register)?;
+                self.keystream.copy_from_slice(&keystream);
+                keystream.zeroize();
+            }
+
+            let o = b ^ self.keystream[self.pos];
+            self.keystream[self.pos] = if decrypt { b } else { o };
+            self.pos += 1;
+            out.push(o);
+
+            if self.pos == n {
+                for i in 0..bs - n {
+                    self.register[i] = self.register[i + n];
+                }
+                self.register[bs - n..].copy_from_slice(&self.keystream[..n]);
+                self.keystream[..].zeroize();
+                self.pos = 0;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Bits go through most significant first, like OpenSSL's CFB1.
+    fn process_bits(&mut self, data: &[u8], decrypt: bool) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = Vec::with_capacity(data.len());
+
+        for &b in data {
+            let mut o = 0;
+            for i in (0..8).rev() {
+                let mut keystream = self.cipher.encrypt(&self.register)?;
+                let bit = (b >> i) & 1;
+                let obit = bit ^ (keystream[0] >> 7);
+                keystream.zeroize();
+
+                o |= obit << i;
+                shift_in(&mut self.register, if decrypt { bit } else { obit });
+            }
+            out.push(o);
+        }
+
+        Ok(out)
+    }
+}
+
+impl<C, S> Zeroize for Cfb<C, S> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.keystream[..].zeroize();
+        self.pos = 0;
+    }
+}
+
+impl<C, S> Drop for Cfb<C, S> {
+    fn drop(&mut self) {
+        self.keystream.zeroize();
+    }
+}
+
+/// Leaves out the leftover keystream.
+impl<C, S> fmt::Debug for Cfb<C, S> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cfb")
+            .field("cipher", &self.cipher)
+            .field("register", &self.register)
+            .finish()
+    }
+}
+
+impl<C, S> StreamEncrypt for Cfb<C, S> where C: SingleBlockEncrypt, S: Segment {
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        self.process(data, false)
+    }
+}
+
+impl<C, S> StreamDecrypt for Cfb<C, S> where C: SingleBlockEncrypt, S: Segment {
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        self.process(data, true)
+    }
+}
+
+/// Shift the whole register left by one bit, `bit` comes in at the end.
+fn shift_in(register: &mut [u8], bit: u8) {
+    let len = register.len();
+    for i in 0..len {
+        let next = if i + 1 < len { register[i + 1] >> 7 } else { bit };
+        register[i] = (register[i] << 1) | next;
+    }
+}
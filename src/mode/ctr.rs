@@ -1,73 +1,116 @@
-use ::AES;
-use ::utils::xor;
+use std::fmt;
+use ::utils::{ xor_in_place, take_keystream };
+use ::utils::zeroize::Zeroize;
 use ::cipher::{
+    DecryptFail, KeyInit,
     SingleBlockEncrypt,
-    StreamEncrypt, StreamDecrypt
+    StreamEncrypt, StreamDecrypt,
+    InPlaceStreamEncrypt, InPlaceStreamDecrypt
 };
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Ctr<C> {
     cipher: C,
     counter: Vec<u8>,
+    keystream: Vec<u8>,
     buffer: Vec<u8>
 }
 
-impl<C> Ctr<C> {
-    pub fn set_ctr(&mut self, ctr: &[u8]) -> &mut Self {
+impl<C> Ctr<C> where C: SingleBlockEncrypt {
+    pub fn set_ctr(&mut self, ctr: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if ctr.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
         self.counter = ctr.into();
-        self.buffer.clear();
-        self
+        self.buffer.zeroize();
+        Ok(self)
     }
 }
 
-impl Ctr<AES> {
-    pub fn new(key: &[u8], ctr: &[u8]) -> Ctr<AES> {
-        Ctr {
-            cipher: AES::new(key),
+impl<C> Ctr<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, ctr: &[u8]) -> Result<Ctr<C>, DecryptFail> {
+        if ctr.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        Ok(Ctr {
+            cipher: C::init(key)?,
             counter: ctr.into(),
-            buffer: Vec::with_capacity(15)
-        }
+            keystream: vec![0; C::bs()],
+            buffer: Vec::with_capacity(C::bs() - 1)
+        })
     }
 }
 
-impl<C> StreamEncrypt for Ctr<C> where C: SingleBlockEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> Vec<u8> {
-        let buff_len = self.buffer.len();
-        let buff = xor(&self.buffer, &data[..buff_len]);
+impl<C> Zeroize for Ctr<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.keystream[..].zeroize();
+        self.buffer.zeroize();
+    }
+}
 
-        data[buff_len..].chunks(C::bs())
-            .map(|b| {
-                let counter = self.counter.clone();
-                self.counter = add_ctr(&counter, 1);
-                let keystream = self.cipher.encrypt(&counter);
+impl<C> Drop for Ctr<C> {
+    fn drop(&mut self) {
+        self.keystream.zeroize();
+        self.buffer.zeroize();
+    }
+}
 
-                if b.len() < keystream.len() {
-                    self.buffer = keystream[b.len()..].into();
-                }
+/// Leaves out the leftover keystream.
+impl<C> fmt::Debug for Ctr<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ctr")
+            .field("cipher", &self.cipher)
+            .field("counter", &self.counter)
+            .finish()
+    }
+}
+
+impl<C> Ctr<C> where C: SingleBlockEncrypt {
+    /// XOR the keystream into `data`, each block is made in `keystream` so nothing is allocated.
+    fn apply_keystream(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        let n = take_keystream(&mut self.buffer, data);
+
+        for b in data[n..].chunks_mut(C::bs()) {
+            self.keystream.copy_from_slice(&self.counter);
+            increment(&mut self.counter);
+            self.cipher.encrypt_slice(&mut self.keystream)?;
+            xor_in_place(b, &self.keystream[..b.len()]);
+            self.buffer.extend_from_slice(&self.keystream[b.len()..]);
+        }
+        self.keystream[..].zeroize();
 
-                xor(b, &keystream[..b.len()])
-            })
-            .fold(buff, |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            })
+        Ok(())
+    }
+}
+
+impl<C> StreamEncrypt for Ctr<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = data.to_vec();
+        self.apply_keystream(&mut out)?;
+        Ok(out)
     }
 }
 
 impl<C> StreamDecrypt for Ctr<C> where C: SingleBlockEncrypt {
-    fn decrypt(&mut self, data: &[u8]) -> Vec<u8> {
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
         StreamEncrypt::encrypt(self, data)
     }
 }
 
-// from rust-crypto
-fn add_ctr(ctr: &[u8], mut ammount: u8) -> Vec<u8> {
-    let mut ctr: Vec<u8> = ctr.into();
-    for (i, &b) in ctr.clone().iter().enumerate().rev() {
-        ctr[i] = b.wrapping_add(ammount);
-        if ctr[i] >= b { break };
-        ammount = 1;
+impl<C> InPlaceStreamEncrypt for Ctr<C> where C: SingleBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) {
+        self.apply_keystream(data).expect("the counter is one block long")
+    }
+}
+
+impl<C> InPlaceStreamDecrypt for Ctr<C> where C: SingleBlockEncrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) {
+        InPlaceStreamEncrypt::encrypt_in_place(self, data)
+    }
+}
+
+/// Big-endian increment by one, wrapping.
+fn increment(ctr: &mut [u8]) {
+    for b in ctr.iter_mut().rev() {
+        *b = b.wrapping_add(1);
+        if *b != 0 { break };
     }
-    ctr
 }
@@ -0,0 +1,106 @@
+use std::fmt;
+use ::utils::{ xor, xor_in_place, eq };
+use ::utils::zeroize::Zeroize;
+use ::mode::Ctr;
+use ::mac::Cmac;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    StreamEncrypt, StreamDecrypt,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// EAX, Bellare, Rogaway and Wagner: `Ctr` then OMAC over the ciphertext.
+///
+/// The nonce and header may have any length. An `Eax` seals a single message under its nonce,
+/// any later `encrypt` returns `NonceReuse`.
+#[derive(Clone)]
+pub struct Eax<C> {
+    cipher: Ctr<C>,
+    mac: Cmac<C>,
+    nonce: Vec<u8>,
+    tag_mask: Vec<u8>,
+    used: bool
+}
+
+impl<C> Eax<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, nonce: &[u8], header: &[u8]) -> Result<Self, DecryptFail> {
+        let mut mac = Cmac::with_cipher(C::init(key)?)?;
+        let nonce = omac(&mut mac, 0, nonce)?;
+        let mut header = omac(&mut mac, 1, header)?;
+        let tag_mask = xor(&nonce, &header);
+        header.zeroize();
+
+        Ok(Eax {
+            cipher: Ctr::<C>::new(key, &nonce)?,
+            mac: mac,
+            nonce: nonce,
+            tag_mask: tag_mask,
+            used: false
+        })
+    }
+}
+
+impl<C> Eax<C> where C: SingleBlockEncrypt {
+    fn tag(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut mac = omac(&mut self.mac, 2, ciphertext)?;
+        xor_in_place(&mut mac, &self.tag_mask);
+        Ok(mac)
+    }
+}
+
+impl<C> Zeroize for Eax<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.mac.zeroize();
+        self.tag_mask.zeroize();
+    }
+}
+
+impl<C> Drop for Eax<C> {
+    fn drop(&mut self) {
+        self.tag_mask.zeroize();
+    }
+}
+
+/// Leaves out the tag mask.
+impl<C> fmt::Debug for Eax<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Eax")
+            .field("cipher", &self.cipher)
+            .field("nonce", &self.nonce)
+            .field("used", &self.used)
+            .finish()
+    }
+}
+
+impl<C> AeadStreamEncrypt for Eax<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        if self.used { Err(DecryptFail::NonceReuse)? };
+        self.used = true;
+        self.cipher.set_ctr(&self.nonce)?;
+        let out = self.cipher.encrypt(data)?;
+        let tag = self.tag(&out)?;
+
+        Ok((out, tag))
+    }
+}
+
+impl<C> AeadStreamDecrypt for Eax<C> where C: SingleBlockEncrypt {
+    fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        if eq(&self.tag(data)?, tag) {
+            self.cipher.set_ctr(&self.nonce)?;
+            self.cipher.decrypt(data)
+        } else {
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+/// OMAC1 of `[t]_n || data`, the leading block keeps EAX's three MACs apart.
+fn omac<C>(mac: &mut Cmac<C>, t: u8, data: &[u8]) -> Result<Vec<u8>, DecryptFail> where C: SingleBlockEncrypt {
+    let mut block = [0; 16];
+    block[15] = t;
+    mac.reset().input(&block)?.input(data)?.result()
+}
@@ -1,9 +1,10 @@
-use ::AES;
 use ::utils::padding::Padding;
+use ::utils::zeroize::Zeroize;
 use ::cipher::{
-    DecryptFail,
+    DecryptFail, KeyInit,
     SingleBlockEncrypt, SingleBlockDecrypt,
-    BlockEncrypt, BlockDecrypt
+    BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt
 };
 
 
@@ -12,35 +13,40 @@ pub struct Ecb<C> {
     cipher: C
 }
 
-impl Ecb<AES> {
-    pub fn new(key: &[u8]) -> Ecb<AES> {
-        Ecb { cipher: AES::new(key) }
+impl<C> Ecb<C> where C: KeyInit {
+    pub fn new(key: &C::Key) -> Result<Ecb<C>, DecryptFail> {
+        Ok(Ecb { cipher: C::init(key)? })
     }
 }
 
 impl<C> BlockEncrypt for Ecb<C> where C: SingleBlockEncrypt {
     fn bs(&self) -> usize { C::bs() }
-    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Vec<u8> {
-        P::padding(data, self.bs()).chunks(self.bs())
-            .map(|b| self.cipher.encrypt(b))
-            .fold(Vec::new(), |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            })
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = P::padding(data, self.bs())?;
+        InPlaceBlockEncrypt::encrypt_in_place(self, &mut out)?;
+        Ok(out)
     }
 }
 
 impl<C> BlockDecrypt for Ecb<C> where C: SingleBlockDecrypt {
     fn bs(&self) -> usize { C::bs() }
     fn decrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
-        P::unpadding(
-            &data.chunks(self.bs())
-                .map(|b| self.cipher.decrypt(b))
-                .fold(Vec::new(), |mut sum, mut next| {
-                    sum.append(&mut next);
-                    sum
-                }),
-            self.bs()
-        ).map_err(|err| err.into())
+        let mut out = data.to_vec();
+        InPlaceBlockDecrypt::decrypt_in_place(self, &mut out)?;
+        let text = P::unpadding(&out, self.bs());
+        out.zeroize();
+        text.map_err(|err| err.into())
+    }
+}
+
+impl<C> InPlaceBlockEncrypt for Ecb<C> where C: SingleBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        self.cipher.encrypt_slice(data)
+    }
+}
+
+impl<C> InPlaceBlockDecrypt for Ecb<C> where C: SingleBlockDecrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        self.cipher.decrypt_slice(data)
     }
 }
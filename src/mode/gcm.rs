@@ -1,60 +1,86 @@
-use ::AES;
+use std::fmt;
 use ::utils::{ xor, eq };
 use ::utils::ghash::Ghash;
+use ::utils::zeroize::Zeroize;
 use ::mode::Ctr;
 use ::cipher::{
-    DecryptFail,
+    DecryptFail, KeyInit,
     SingleBlockEncrypt,
     StreamEncrypt, StreamDecrypt,
     AeadStreamEncrypt, AeadStreamDecrypt
 };
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Gcm<C> {
-    cipher: C,
+    cipher: Ctr<C>,
     mac: Ghash,
     end_tag: Vec<u8>
 }
 
-impl Gcm<Ctr<AES>> {
-    pub fn new(key: &[u8], nonce: &[u8], aad: &[u8]) -> Self {
-        debug_assert_eq!(nonce.len(), 12);
+impl<C> Gcm<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, nonce: &[u8], aad: &[u8]) -> Result<Self, DecryptFail> {
+        if nonce.len() != 12 { Err(DecryptFail::InvalidNonceLength)? };
         let x: &[u8] = &[0x00, 0x00, 0x00, 0x01];
-        let mut cipher = Ctr::new(key, &[nonce, x].concat());
-        let hash_key = AES::new(key).encrypt(&[0; 16]);
-        let end_tag = cipher.encrypt(&[0; 16]);
-        let ghash = Ghash::new(&hash_key, aad);
+        let mut cipher = Ctr::<C>::new(key, &[nonce, x].concat())?;
+        let mut hash_key = C::init(key)?.encrypt(&[0; 16])?;
+        let end_tag = cipher.encrypt(&[0; 16])?;
+        let ghash = Ghash::new(&hash_key);
+        hash_key.zeroize();
+        let mut ghash = ghash?;
+        ghash.update_aad(aad)?;
 
-        Gcm {
+        Ok(Gcm {
             cipher: cipher,
             mac: ghash,
             end_tag: end_tag
-        }
+        })
+    }
+}
+
+impl<C> Zeroize for Gcm<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.mac.zeroize();
+        self.end_tag.zeroize();
+    }
+}
+
+impl<C> Drop for Gcm<C> {
+    fn drop(&mut self) {
+        self.end_tag.zeroize();
+    }
+}
+
+/// Leaves out the encrypted initial counter block.
+impl<C> fmt::Debug for Gcm<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Gcm")
+            .field("cipher", &self.cipher)
+            .field("mac", &self.mac)
+            .finish()
     }
 }
 
-impl<C> AeadStreamEncrypt for Gcm<C> where C: StreamEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> (Vec<u8>, Vec<u8>) {
-        let out = self.cipher.encrypt(data);
-        let tag = xor(
-            &self.end_tag,
-            &self.mac.input(&out).result()
-        );
+impl<C> AeadStreamEncrypt for Gcm<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        let out = self.cipher.encrypt(data)?;
+        let mut hash = self.mac.update_ciphertext(&out).finalize();
+        let tag = xor(&self.end_tag, &hash);
+        hash.zeroize();
 
-        (out, tag)
+        Ok((out, tag))
     }
 }
 
-impl<C> AeadStreamDecrypt for Gcm<C> where C: StreamDecrypt {
+impl<C> AeadStreamDecrypt for Gcm<C> where C: SingleBlockEncrypt {
     fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
-        let calc_tag = xor(
-            &self.end_tag,
-            &self.mac.input(data).result()
-        );
+        let mut hash = self.mac.update_ciphertext(data).finalize();
+        let calc_tag = xor(&self.end_tag, &hash);
+        hash.zeroize();
 
         if eq(&calc_tag, tag) {
-            Ok(self.cipher.decrypt(data))
+            self.cipher.decrypt(data)
         } else {
             Err(DecryptFail::Auth)
         }
@@ -0,0 +1,140 @@
+use std::fmt;
+use ::utils::{ xor_in_place, eq };
+use ::utils::polyval::Polyval;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// AES-GCM-SIV, RFC 8452: POLYVAL and a little-endian 32-bit counter, under keys derived from the nonce.
+///
+/// Only 128 and 256-bit keys are defined. Like `Ccm`, every call is a whole message.
+#[derive(Clone)]
+pub struct GcmSiv<C> {
+    cipher: C,
+    mac: Polyval,
+    nonce: Vec<u8>,
+    aad_len: usize
+}
+
+impl<C> GcmSiv<C>
+    where
+        C: KeyInit + SingleBlockEncrypt,
+        C::Key: AsRef<[u8]> + AsMut<[u8]> + Default
+{
+    pub fn new(key: &C::Key, nonce: &[u8], aad: &[u8]) -> Result<Self, DecryptFail> {
+        let key_len = key.as_ref().len();
+        if key_len != 16 && key_len != 32 { Err(DecryptFail::InvalidKeyLength)? };
+        if nonce.len() != 12 { Err(DecryptFail::InvalidNonceLength)? };
+        if aad.len() as u64 > 1 << 36 { Err(DecryptFail::LongInput)? };
+
+        let key_generating = C::init(key)?;
+        let mut derived = Vec::with_capacity(16 + key_len);
+        for i in 0..(2 + key_len / 8) as u32 {
+            let mut block = le_bytes(i as u64, 4);
+            block.extend_from_slice(nonce);
+            let mut out = key_generating.encrypt(&block)?;
+            derived.extend_from_slice(&out[..8]);
+            out.zeroize();
+        }
+
+        let mac = Polyval::new(&derived[..16]);
+        let mut enc_key = <C::Key as Default>::default();
+        enc_key.as_mut().copy_from_slice(&derived[16..]);
+        derived.zeroize();
+        let cipher = C::init(&enc_key);
+        enc_key.as_mut().zeroize();
+
+        let mut mac = mac?;
+        mac.update(aad);
+        Ok(GcmSiv {
+            cipher: cipher?,
+            mac: mac,
+            nonce: nonce.into(),
+            aad_len: aad.len()
+        })
+    }
+}
+
+impl<C> GcmSiv<C> where C: SingleBlockEncrypt {
+    /// Encrypt POLYVAL over the associated data, `data` and their bit lengths, mixed with the nonce.
+    fn tag(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut mac = self.mac.clone();
+        let mut lengths = le_bytes(self.aad_len as u64 * 8, 8);
+        lengths.extend_from_slice(&le_bytes(data.len() as u64 * 8, 8));
+        let mut s = mac.update(data).update(&lengths).result();
+
+        xor_in_place(&mut s[..12], &self.nonce);
+        s[15] &= 0x7f;
+        let tag = self.cipher.encrypt(&s);
+        s.zeroize();
+        tag
+    }
+
+    /// The counter starts at the tag with its top bit set, only the first 32 bits count, little-endian.
+    fn ctr(&self, tag: &[u8], data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut counter = tag.to_vec();
+        counter[15] |= 0x80;
+
+        let mut out = data.to_vec();
+        for b in out.chunks_mut(16) {
+            let mut keystream = self.cipher.encrypt(&counter)?;
+            xor_in_place(b, &keystream[..b.len()]);
+            keystream.zeroize();
+
+            for c in &mut counter[..4] {
+                *c = c.wrapping_add(1);
+                if *c != 0 { break };
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<C> Zeroize for GcmSiv<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.mac.zeroize();
+    }
+}
+
+impl<C> fmt::Debug for GcmSiv<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GcmSiv")
+            .field("cipher", &self.cipher)
+            .field("mac", &self.mac)
+            .field("nonce", &self.nonce)
+            .finish()
+    }
+}
+
+impl<C> AeadStreamEncrypt for GcmSiv<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        if data.len() as u64 > 1 << 36 { Err(DecryptFail::LongInput)? };
+        let tag = self.tag(data)?;
+
+        Ok((self.ctr(&tag, data)?, tag))
+    }
+}
+
+impl<C> AeadStreamDecrypt for GcmSiv<C> where C: SingleBlockEncrypt {
+    fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        if data.len() as u64 > 1 << 36 { Err(DecryptFail::LongInput)? };
+        if tag.len() != 16 { Err(DecryptFail::Auth)? };
+        let mut out = self.ctr(tag, data)?;
+
+        if eq(&self.tag(&out)?, tag) {
+            Ok(out)
+        } else {
+            out.zeroize();
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+fn le_bytes(x: u64, len: usize) -> Vec<u8> {
+    (0..len).map(|i| (x >> (8 * i)) as u8).collect()
+}
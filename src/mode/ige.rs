@@ -0,0 +1,103 @@
+use std::fmt;
+use ::utils::xor;
+use ::utils::padding::Padding;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt, SingleBlockDecrypt,
+    BlockEncrypt, BlockDecrypt
+};
+
+
+/// Infinite Garble Extension, as OpenSSL's `AES_ige_encrypt`.
+///
+/// The IV is two blocks, the ciphertext block before the first one and then the plaintext block.
+#[derive(Clone)]
+pub struct Ige<C> {
+    cipher: C,
+    prev_ciphertext: Vec<u8>,
+    prev_plaintext: Vec<u8>
+}
+
+impl<C> Ige<C> where C: SingleBlockEncrypt {
+    pub fn set_iv(&mut self, iv: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if iv.len() != 2 * C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        let (c, p) = iv.split_at(C::bs());
+        self.prev_ciphertext = c.into();
+        self.prev_plaintext.zeroize();
+        self.prev_plaintext.extend_from_slice(p);
+        Ok(self)
+    }
+}
+
+impl<C> Ige<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Ige<C>, DecryptFail> {
+        if iv.len() != 2 * C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        let (c, p) = iv.split_at(C::bs());
+        Ok(Ige { cipher: C::init(key)?, prev_ciphertext: c.into(), prev_plaintext: p.into() })
+    }
+}
+
+impl<C> Zeroize for Ige<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.prev_plaintext.zeroize();
+    }
+}
+
+impl<C> Drop for Ige<C> {
+    fn drop(&mut self) {
+        self.prev_plaintext.zeroize();
+    }
+}
+
+/// Leaves out the previous plaintext block.
+impl<C> fmt::Debug for Ige<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ige")
+            .field("cipher", &self.cipher)
+            .field("prev_ciphertext", &self.prev_ciphertext)
+            .finish()
+    }
+}
+
+impl<C> BlockEncrypt for Ige<C> where C: SingleBlockEncrypt {
+    fn bs(&self) -> usize { C::bs() }
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut data = P::padding(data, self.bs())?;
+        if data.len() % self.bs() != 0 {
+            data.zeroize();
+            Err(DecryptFail::MisalignedInput)?
+        };
+
+        let mut out = Vec::with_capacity(data.len());
+        for b in data.chunks(self.bs()) {
+            let text = xor(&self.cipher.encrypt(&xor(b, &self.prev_ciphertext))?, &self.prev_plaintext);
+            out.extend_from_slice(&text);
+            self.prev_ciphertext = text;
+            self.prev_plaintext.zeroize();
+            self.prev_plaintext.extend_from_slice(b);
+        }
+        data.zeroize();
+        Ok(out)
+    }
+}
+
+impl<C> BlockDecrypt for Ige<C> where C: SingleBlockDecrypt {
+    fn bs(&self) -> usize { C::bs() }
+    fn decrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        if data.len() % self.bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+
+        let mut out = Vec::with_capacity(data.len());
+        for b in data.chunks(self.bs()) {
+            let text = xor(&self.cipher.decrypt(&xor(b, &self.prev_plaintext))?, &self.prev_ciphertext);
+            out.extend_from_slice(&text);
+            self.prev_ciphertext = b.into();
+            self.prev_plaintext.zeroize();
+            self.prev_plaintext.extend_from_slice(&text);
+        }
+        let text = P::unpadding(&out, self.bs());
+        out.zeroize();
+        text.map_err(|err| err.into())
+    }
+}
@@ -1,11 +1,29 @@
 mod ecb;
 mod cbc;
+mod pcbc;
 mod ctr;
+mod cfb;
+mod ofb;
+mod ige;
 mod xex;
 mod gcm;
+mod gcm_siv;
+mod ccm;
+mod eax;
+mod ocb;
+mod siv;
 
 pub use self::ecb::Ecb;
-pub use self::cbc::Cbc;
+pub use self::cbc::{ Cbc, Stealing, Cs1, Cs2, Cs3 };
+pub use self::pcbc::Pcbc;
 pub use self::ctr::Ctr;
+pub use self::cfb::{ Cfb, Cfb1, Cfb8, Cfb128, Segment, Bits1, Bits8, FullBlock };
+pub use self::ofb::Ofb;
+pub use self::ige::Ige;
 pub use self::xex::{ Xex, Xts };
 pub use self::gcm::Gcm;
+pub use self::gcm_siv::GcmSiv;
+pub use self::ccm::Ccm;
+pub use self::eax::Eax;
+pub use self::ocb::Ocb3;
+pub use self::siv::Siv;
@@ -0,0 +1,248 @@
+use std::fmt;
+use ::utils::{ xor, xor_in_place, eq };
+use ::utils::zeroize::Zeroize;
+use ::mode::Xex;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt, SingleBlockDecrypt,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// OCB3, RFC 7253. Needs a 128-bit block cipher.
+///
+/// The nonce and associated data are fixed by `new`, so every call is a whole message.
+/// Only the first `encrypt` may use the nonce, the ones after it return `NonceReuse`.
+#[derive(Clone)]
+pub struct Ocb3<C> {
+    cipher: C,
+    l_star: Vec<u8>,
+    l_dollar: Vec<u8>,
+    l: Vec<Vec<u8>>,
+    offset: Vec<u8>,
+    aad_hash: Vec<u8>,
+    nonce: Vec<u8>,
+    tag_len: usize,
+    used: bool
+}
+
+impl<C> Ocb3<C> where C: KeyInit + SingleBlockEncrypt {
+    /// `nonce` is 1 to 15 bytes, `tag_len` is 8, 12 or 16 bytes.
+    pub fn new(key: &C::Key, nonce: &[u8], aad: &[u8], tag_len: usize) -> Result<Self, DecryptFail> {
+        if nonce.is_empty() || nonce.len() > 15 { Err(DecryptFail::InvalidNonceLength)? };
+        if tag_len != 8 && tag_len != 12 && tag_len != 16 { Err(DecryptFail::InvalidTagLength)? };
+
+        let cipher = C::init(key)?;
+        let l_star = cipher.encrypt(&[0; 16])?;
+        let l_dollar = double::<C>(&l_star);
+        let mut l = vec![double::<C>(&l_dollar)];
+        for i in 1..8 {
+            let next = double::<C>(&l[i - 1]);
+            l.push(next);
+        }
+
+        let mut ocb = Ocb3 {
+            offset: offset0(&cipher, nonce, tag_len)?,
+            cipher: cipher,
+            l_star: l_star,
+            l_dollar: l_dollar,
+            l: l,
+            aad_hash: Vec::new(),
+            nonce: nonce.into(),
+            tag_len: tag_len,
+            used: false
+        };
+        ocb.aad_hash = ocb.hash(aad)?;
+        Ok(ocb)
+    }
+}
+
+impl<C> Ocb3<C> {
+    /// `L_{ntz(i)}`, the table is precomputed for 255 blocks and extended past that.
+    fn l(&mut self, i: usize) -> &[u8] {
+        let ntz = i.trailing_zeros() as usize;
+        while self.l.len() <= ntz {
+            let next = double::<C>(&self.l[self.l.len() - 1]);
+            self.l.push(next);
+        }
+        &self.l[ntz]
+    }
+}
+
+impl<C> Ocb3<C> where C: SingleBlockEncrypt {
+    /// `HASH(K, A)`.
+    fn hash(&mut self, aad: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut sum = vec![0; 16];
+        let mut offset = vec![0; 16];
+        let full = aad.len() / 16 * 16;
+
+        for (i, b) in aad[..full].chunks(16).enumerate() {
+            xor_in_place(&mut offset, self.l(i + 1));
+            xor_in_place(&mut sum, &self.cipher.encrypt(&xor(b, &offset))?);
+        }
+        if full != aad.len() {
+            xor_in_place(&mut offset, &self.l_star);
+            let mut last = pad(&aad[full..]);
+            xor_in_place(&mut last, &offset);
+            xor_in_place(&mut sum, &self.cipher.encrypt(&last)?);
+            last.zeroize();
+        }
+        offset.zeroize();
+        Ok(sum)
+    }
+
+    /// `E(Checksum ^ Offset ^ L_$) ^ HASH(K, A)`, truncated to `tag_len`.
+    fn tag(&self, mut checksum: Vec<u8>, mut offset: Vec<u8>) -> Result<Vec<u8>, DecryptFail> {
+        xor_in_place(&mut checksum, &offset);
+        xor_in_place(&mut checksum, &self.l_dollar);
+        let tag = self.cipher.encrypt(&checksum);
+        checksum.zeroize();
+        offset.zeroize();
+
+        let mut tag = tag?;
+        xor_in_place(&mut tag, &self.aad_hash);
+        tag.truncate(self.tag_len);
+        Ok(tag)
+    }
+}
+
+impl<C> Zeroize for Ocb3<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.l_star.zeroize();
+        self.l_dollar.zeroize();
+        for l in &mut self.l {
+            l.zeroize();
+        }
+        self.offset.zeroize();
+        self.aad_hash.zeroize();
+    }
+}
+
+impl<C> Drop for Ocb3<C> {
+    fn drop(&mut self) {
+        self.l_star.zeroize();
+        self.l_dollar.zeroize();
+        for l in &mut self.l {
+            l.zeroize();
+        }
+        self.offset.zeroize();
+        self.aad_hash.zeroize();
+    }
+}
+
+/// Leaves out the L table, the offset and the associated data hash.
+impl<C> fmt::Debug for Ocb3<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ocb3")
+            .field("cipher", &self.cipher)
+            .field("nonce", &self.nonce)
+            .field("tag_len", &self.tag_len)
+            .field("used", &self.used)
+            .finish()
+    }
+}
+
+impl<C> AeadStreamEncrypt for Ocb3<C> where C: SingleBlockEncrypt + SingleBlockDecrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        if self.used { Err(DecryptFail::NonceReuse)? };
+        self.used = true;
+        let mut offset = self.offset.clone();
+        let mut checksum = vec![0; 16];
+        let mut out = Vec::with_capacity(data.len());
+        let full = data.len() / 16 * 16;
+
+        for (i, b) in data[..full].chunks(16).enumerate() {
+            xor_in_place(&mut offset, self.l(i + 1));
+            out.extend_from_slice(&xor(&self.cipher.encrypt(&xor(b, &offset))?, &offset));
+            xor_in_place(&mut checksum, b);
+        }
+        if full != data.len() {
+            let tail = &data[full..];
+            xor_in_place(&mut offset, &self.l_star);
+            let mut keystream = self.cipher.encrypt(&offset)?;
+            out.extend_from_slice(&xor(tail, &keystream[..tail.len()]));
+            keystream.zeroize();
+            let mut last = pad(tail);
+            xor_in_place(&mut checksum, &last);
+            last.zeroize();
+        }
+
+        let tag = self.tag(checksum, offset)?;
+        Ok((out, tag))
+    }
+}
+
+impl<C> AeadStreamDecrypt for Ocb3<C> where C: SingleBlockEncrypt + SingleBlockDecrypt {
+    fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut offset = self.offset.clone();
+        let mut checksum = vec![0; 16];
+        let mut out = Vec::with_capacity(data.len());
+        let full = data.len() / 16 * 16;
+
+        for (i, b) in data[..full].chunks(16).enumerate() {
+            xor_in_place(&mut offset, self.l(i + 1));
+            let text = xor(&self.cipher.decrypt(&xor(b, &offset))?, &offset);
+            xor_in_place(&mut checksum, &text);
+            out.extend_from_slice(&text);
+        }
+        if full != data.len() {
+            let tail = &data[full..];
+            xor_in_place(&mut offset, &self.l_star);
+            let mut keystream = self.cipher.encrypt(&offset)?;
+            let text = xor(tail, &keystream[..tail.len()]);
+            keystream.zeroize();
+            let mut last = pad(&text);
+            xor_in_place(&mut checksum, &last);
+            last.zeroize();
+            out.extend_from_slice(&text);
+        }
+
+        if eq(&self.tag(checksum, offset)?, tag) {
+            Ok(out)
+        } else {
+            out.zeroize();
+            Err(DecryptFail::Auth)
+        }
+    }
+}
+
+/// `Offset_0`, bits `bottom..bottom + 128` of `Stretch`.
+fn offset0<C>(cipher: &C, nonce: &[u8], tag_len: usize) -> Result<Vec<u8>, DecryptFail> where C: SingleBlockEncrypt {
+    let mut block = vec![0; 16];
+    block[0] = ((tag_len * 8 % 128) as u8) << 1;
+    block[15 - nonce.len()] |= 1;
+    block[16 - nonce.len()..].copy_from_slice(nonce);
+    let bottom = (block[15] & 0x3f) as usize;
+    block[15] &= 0xc0;
+
+    let mut stretch = cipher.encrypt(&block)?;
+    let mut tail = xor(&stretch[..8], &stretch[1..9]);
+    stretch.extend_from_slice(&tail);
+    tail.zeroize();
+
+    let (byte, bit) = (bottom / 8, bottom % 8);
+    let offset = (byte..byte + 16)
+        .map(|i| if bit == 0 { stretch[i] } else { (stretch[i] << bit) | (stretch[i + 1] >> (8 - bit)) })
+        .collect();
+    stretch.zeroize();
+    Ok(offset)
+}
+
+/// `double` of RFC 7253. It is `Xex::next_tweak` with the bytes in the other order.
+fn double<C>(block: &[u8]) -> Vec<u8> {
+    let mut reversed = block.to_vec();
+    reversed.reverse();
+    let mut out = Xex::<C>::next_tweak(&reversed);
+    reversed.zeroize();
+    out.reverse();
+    out
+}
+
+/// `data || 1 || 0*`, a partial block padded to 16 bytes.
+fn pad(data: &[u8]) -> Vec<u8> {
+    let mut out = data.to_vec();
+    out.push(0x80);
+    out.resize(16, 0);
+    out
+}
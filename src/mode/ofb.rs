@@ -0,0 +1,104 @@
+use std::fmt;
+use ::utils::{ xor_in_place, take_keystream };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    StreamEncrypt, StreamDecrypt,
+    InPlaceStreamEncrypt, InPlaceStreamDecrypt
+};
+
+
+/// Output feedback, the register is the last keystream block.
+#[derive(Clone)]
+pub struct Ofb<C> {
+    cipher: C,
+    register: Vec<u8>,
+    buffer: Vec<u8>
+}
+
+impl<C> Ofb<C> where C: SingleBlockEncrypt {
+    pub fn set_iv(&mut self, iv: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        self.register.zeroize();
+        self.register.extend_from_slice(iv);
+        self.buffer.zeroize();
+        Ok(self)
+    }
+}
+
+impl<C> Ofb<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Ofb<C>, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        Ok(Ofb {
+            cipher: C::init(key)?,
+            register: iv.into(),
+            buffer: Vec::with_capacity(C::bs() - 1)
+        })
+    }
+}
+
+impl<C> Zeroize for Ofb<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.register.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl<C> Drop for Ofb<C> {
+    fn drop(&mut self) {
+        self.register.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+/// Leaves out the register and the leftover keystream.
+impl<C> fmt::Debug for Ofb<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ofb")
+            .field("cipher", &self.cipher)
+            .finish()
+    }
+}
+
+impl<C> Ofb<C> where C: SingleBlockEncrypt {
+    /// XOR the keystream into `data`, the register is encrypted over in place.
+    fn apply_keystream(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        let n = take_keystream(&mut self.buffer, data);
+
+        for b in data[n..].chunks_mut(C::bs()) {
+            self.cipher.encrypt_slice(&mut self.register)?;
+            xor_in_place(b, &self.register[..b.len()]);
+            self.buffer.extend_from_slice(&self.register[b.len()..]);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> StreamEncrypt for Ofb<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = data.to_vec();
+        self.apply_keystream(&mut out)?;
+        Ok(out)
+    }
+}
+
+impl<C> StreamDecrypt for Ofb<C> where C: SingleBlockEncrypt {
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        StreamEncrypt::encrypt(self, data)
+    }
+}
+
+impl<C> InPlaceStreamEncrypt for Ofb<C> where C: SingleBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) {
+        self.apply_keystream(data).expect("the register is one block long")
+    }
+}
+
+impl<C> InPlaceStreamDecrypt for Ofb<C> where C: SingleBlockEncrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) {
+        InPlaceStreamEncrypt::encrypt_in_place(self, data)
+    }
+}
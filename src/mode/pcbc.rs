@@ -0,0 +1,88 @@
+use std::fmt;
+use ::utils::{ xor, xor_in_place };
+use ::utils::padding::Padding;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt, SingleBlockDecrypt,
+    BlockEncrypt, BlockDecrypt
+};
+
+
+/// Propagating CBC, each block is chained with the xor of the previous plaintext and ciphertext.
+#[derive(Clone)]
+pub struct Pcbc<C> {
+    cipher: C,
+    iv: Vec<u8>
+}
+
+impl<C> Pcbc<C> where C: SingleBlockEncrypt {
+    pub fn set_iv(&mut self, iv: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        self.iv.zeroize();
+        self.iv.extend_from_slice(iv);
+        Ok(self)
+    }
+}
+
+impl<C> Pcbc<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Pcbc<C>, DecryptFail> {
+        if iv.len() != C::bs() { Err(DecryptFail::InvalidIvLength)? };
+        Ok(Pcbc { cipher: C::init(key)?, iv: iv.into() })
+    }
+}
+
+impl<C> Zeroize for Pcbc<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.iv.zeroize();
+    }
+}
+
+impl<C> Drop for Pcbc<C> {
+    fn drop(&mut self) {
+        self.iv.zeroize();
+    }
+}
+
+/// Leaves out the chaining value, it is one xor away from the plaintext.
+impl<C> fmt::Debug for Pcbc<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pcbc")
+            .field("cipher", &self.cipher)
+            .finish()
+    }
+}
+
+impl<C> BlockEncrypt for Pcbc<C> where C: SingleBlockEncrypt {
+    fn bs(&self) -> usize { C::bs() }
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let data = P::padding(data, self.bs())?;
+        if data.len() % self.bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+
+        let mut out = Vec::with_capacity(data.len());
+        for b in data.chunks(self.bs()) {
+            let text = self.cipher.encrypt(&xor(b, &self.iv))?;
+            out.extend_from_slice(&text);
+            self.iv.copy_from_slice(b);
+            xor_in_place(&mut self.iv, &text);
+        }
+        Ok(out)
+    }
+}
+
+impl<C> BlockDecrypt for Pcbc<C> where C: SingleBlockDecrypt {
+    fn bs(&self) -> usize { C::bs() }
+    fn decrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        if data.len() % self.bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+
+        let mut out = Vec::with_capacity(data.len());
+        for b in data.chunks(self.bs()) {
+            let text = xor(&self.cipher.decrypt(b)?, &self.iv);
+            out.extend_from_slice(&text);
+            self.iv.copy_from_slice(b);
+            xor_in_place(&mut self.iv, &text);
+        }
+        P::unpadding(&out, self.bs()).map_err(|err| err.into())
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+use ::utils::{ xor_in_place, eq, dbl };
+use ::utils::zeroize::Zeroize;
+use ::mode::Ctr;
+use ::mac::Cmac;
+use ::cipher::{
+    DecryptFail, KeyInit,
+    SingleBlockEncrypt,
+    StreamEncrypt, StreamDecrypt,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// Synthetic IV, RFC 5297: S2V over CMAC then `Ctr`.
+///
+/// `key1` is the CMAC key and `key2` the CTR key, so AES-SIV-CMAC-256 is `Siv<Aes128>`
+/// and AES-SIV-CMAC-512 is `Siv<Aes256>`. The tag is the synthetic IV.
+/// A nonce, if any, goes in as the last associated data component.
+#[derive(Clone)]
+pub struct Siv<C> {
+    cipher: Ctr<C>,
+    mac: Cmac<C>,
+    aad: Vec<Vec<u8>>
+}
+
+impl<C> Siv<C> where C: KeyInit + SingleBlockEncrypt {
+    /// At most 126 associated data components, S2V takes 127 strings including the plaintext.
+    pub fn new(key1: &C::Key, key2: &C::Key, aad: &[&[u8]]) -> Result<Self, DecryptFail> {
+        if aad.len() > 126 { Err(DecryptFail::LongInput)? };
+
+        Ok(Siv {
+            cipher: Ctr::<C>::new(key2, &[0; 16])?,
+            mac: Cmac::new(key1)?,
+            aad: aad.iter().map(|&a| a.into()).collect()
+        })
+    }
+}
+
+impl<C> Siv<C> where C: SingleBlockEncrypt {
+    /// `S2V(K1, AD_1, .., AD_n, data)`.
+    fn s2v(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut d = self.mac.reset().input(&[0; 16])?.result()?;
+        for a in &self.aad {
+            dbl(&mut d)?;
+            xor_in_place(&mut d, &self.mac.reset().input(a)?.result()?);
+        }
+
+        let mut t = data.to_vec();
+        if t.len() >= 16 {
+            let n = t.len() - 16;
+            xor_in_place(&mut t[n..], &d);
+        } else {
+            dbl(&mut d)?;
+            t.push(0x80);
+            t.resize(16, 0);
+            xor_in_place(&mut t, &d);
+        }
+        d.zeroize();
+
+        let v = self.mac.reset().input(&t).and_then(|mac| mac.result());
+        t.zeroize();
+        v
+    }
+
+    /// Start the counter at `Q`, the synthetic IV with bits 63 and 31 cleared.
+    fn reset(&mut self, v: &[u8]) -> Result<(), DecryptFail> {
+        let mut q = v.to_vec();
+        q[8] &= 0x7f;
+        q[12] &= 0x7f;
+        self.cipher.set_ctr(&q)?;
+        Ok(())
+    }
+}
+
+impl<C> Zeroize for Siv<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.mac.zeroize();
+    }
+}
+
+impl<C> fmt::Debug for Siv<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Siv")
+            .field("cipher", &self.cipher)
+            .field("aad", &self.aad)
+            .finish()
+    }
+}
+
+impl<C> AeadStreamEncrypt for Siv<C> where C: SingleBlockEncrypt {
+    fn encrypt(&mut self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DecryptFail> {
+        let v = self.s2v(data)?;
+        self.reset(&v)?;
+
+        Ok((self.cipher.encrypt(data)?, v))
+    }
+}
+
+impl<C> AeadStreamDecrypt for Siv<C> where C: SingleBlockEncrypt {
+    fn decrypt(&mut self, data: &[u8], tag: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        if tag.len() != 16 { Err(DecryptFail::Auth)? };
+        self.reset(tag)?;
+        let mut out = self.cipher.decrypt(data)?;
+
+        if eq(&self.s2v(&out)?, tag) {
+            Ok(out)
+        } else {
+            out.zeroize();
+            Err(DecryptFail::Auth)
+        }
+    }
+}
@@ -1,166 +1,208 @@
-use ::AES;
-use ::utils::xor;
+use std::fmt;
+use ::utils::{ xor, xor_in_place };
+use ::utils::zeroize::Zeroize;
 use ::utils::padding::{ Padding, NoPadding };
 use ::cipher::{
-    DecryptFail,
+    DecryptFail, KeyInit,
     SingleBlockEncrypt, SingleBlockDecrypt,
     BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt,
     CtsBlockEncrypt, CtsBlockDecrypt
 };
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Xex<C> {
     cipher: C,
     tweak: Vec<u8>,
 }
 pub type Xts<C> = Xex<C>;
 
-impl Xex<AES> {
-    pub fn new(key1: &[u8], key2: &[u8], i: &[u8]) -> Self {
-        Xex {
-            cipher: AES::new(key1),
-            tweak: AES::new(key2).encrypt(i),
-        }
+impl<C> Xex<C> where C: KeyInit + SingleBlockEncrypt {
+    pub fn new(key1: &C::Key, key2: &C::Key, i: &[u8]) -> Result<Self, DecryptFail> {
+        if i.len() != C::bs() { Err(DecryptFail::InvalidTweakLength)? };
+        Ok(Xex {
+            cipher: C::init(key1)?,
+            tweak: C::init(key2)?.encrypt(i)?,
+        })
     }
 }
 
-impl<C> Xex<C> {
-    pub fn set_tweak(&mut self, tweak: &[u8]) -> &mut Self {
-        self.tweak = tweak.into();
-        self
+impl<C> Xex<C> where C: SingleBlockEncrypt {
+    pub fn set_tweak(&mut self, tweak: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if tweak.len() != C::bs() { Err(DecryptFail::InvalidTweakLength)? };
+        self.tweak.zeroize();
+        self.tweak.extend_from_slice(tweak);
+        Ok(self)
     }
+}
 
+impl<C> Xex<C> {
     pub fn next_tweak(tweak: &[u8]) -> Vec<u8> {
-        let mut out = Vec::with_capacity(tweak.len());
-        let (mut x, mut y) = (0, 0);
+        let mut out = tweak.to_vec();
+        mul_alpha(&mut out);
+        out
+    }
+}
 
-        for &b in tweak {
-            y = (b >> 7) & 1;
-            out.push(((b << 1) + x) & 0xff);
-            x = y
-        }
+impl<C> Zeroize for Xex<C> where C: Zeroize {
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.tweak.zeroize();
+    }
+}
 
-        if y != 0 {
-            out[0] ^= 0x87;
-        }
+impl<C> Drop for Xex<C> {
+    fn drop(&mut self) {
+        self.tweak.zeroize();
+    }
+}
 
-        out
+/// Leaves out the tweak.
+impl<C> fmt::Debug for Xex<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Xex {{ cipher: {:?}, .. }}", self.cipher)
     }
 }
 
 impl<C> BlockEncrypt for Xex<C> where C: SingleBlockEncrypt {
     fn bs(&self) -> usize { C::bs() }
-    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Vec<u8> {
-        debug_assert_eq!(self.tweak.len(), self.bs());
-        P::padding(data, self.bs()).chunks(self.bs())
-            .map(|b| {
-                let tweak = self.tweak.clone();
-                self.set_tweak(&Self::next_tweak(&tweak));
-                xex_encrypt(&self.cipher, b, &tweak)
-            })
-            .fold(Vec::new(), |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            })
+    fn encrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let mut out = P::padding(data, self.bs())?;
+        InPlaceBlockEncrypt::encrypt_in_place(self, &mut out)?;
+        Ok(out)
     }
 }
 
 impl<C> BlockDecrypt for Xex<C> where C: SingleBlockDecrypt {
     fn bs(&self) -> usize { C::bs() }
     fn decrypt<P: Padding>(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
-        debug_assert_eq!(self.tweak.len(), self.bs());
-        let out = data.chunks(self.bs())
-            .map(|b| {
-                let tweak = self.tweak.clone();
-                self.set_tweak(&Self::next_tweak(&tweak));
-                xex_decrypt(&self.cipher, b, &tweak)
-            })
-            .fold(Vec::new(), |mut sum, mut next| {
-                sum.append(&mut next);
-                sum
-            });
-        P::unpadding(&out, self.bs()).map_err(|err| err.into())
+        let mut out = data.to_vec();
+        InPlaceBlockDecrypt::decrypt_in_place(self, &mut out)?;
+        let text = P::unpadding(&out, self.bs());
+        out.zeroize();
+        text.map_err(|err| err.into())
+    }
+}
+
+impl<C> InPlaceBlockEncrypt for Xex<C> where C: SingleBlockEncrypt {
+    fn encrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % C::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for block in data.chunks_mut(C::bs()) {
+            xor_in_place(block, &self.tweak);
+            self.cipher.encrypt_slice(block)?;
+            xor_in_place(block, &self.tweak);
+            mul_alpha(&mut self.tweak);
+        }
+        Ok(())
+    }
+}
+
+impl<C> InPlaceBlockDecrypt for Xex<C> where C: SingleBlockDecrypt {
+    fn decrypt_in_place(&mut self, data: &mut [u8]) -> Result<(), DecryptFail> {
+        if data.len() % C::bs() != 0 { Err(DecryptFail::MisalignedInput)? };
+        for block in data.chunks_mut(C::bs()) {
+            xor_in_place(block, &self.tweak);
+            self.cipher.decrypt_slice(block)?;
+            xor_in_place(block, &self.tweak);
+            mul_alpha(&mut self.tweak);
+        }
+        Ok(())
     }
 }
 
 impl<C> CtsBlockEncrypt for Xts<C> where C: SingleBlockEncrypt {
-    fn encrypt(&mut self, data: &[u8]) -> Vec<u8> {
-        debug_assert!(data.len() >= self.bs());
+    fn encrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
         let bs = self.bs();
+        if data.len() < bs { Err(DecryptFail::ShortInput)? };
         let pos = data.len() / bs * bs;
         let (head, stealer) = data.split_at(pos);
-        let head = BlockEncrypt::encrypt::<NoPadding>(self, head);
+        let head = BlockEncrypt::encrypt::<NoPadding>(self, head)?;
         let (head, tail) = head.split_at(pos - bs);
 
         if stealer.is_empty() {
-            [
-                &head[..pos-bs],
-                tail,
-                &head[pos-bs..]
-            ].concat()
+            Ok([head, tail].concat())
         } else {
-            let tweak = self.tweak.clone();
-            self.set_tweak(&Self::next_tweak(&tweak));
+            let mut tweak = self.tweak.clone();
+            mul_alpha(&mut self.tweak);
+
+            let last = xex_encrypt(&self.cipher, &[
+                stealer,
+                &tail[stealer.len()..]
+            ].concat(), &tweak);
+            tweak.zeroize();
 
-            [
+            Ok([
                 head,
-                &xex_encrypt(&self.cipher, &[
-                    stealer,
-                    &tail[stealer.len()..]
-                ].concat(), &tweak),
+                &last?,
                 &tail[..stealer.len()]
-            ].concat()
+            ].concat())
         }
     }
 }
 
 impl<C> CtsBlockDecrypt for Xts<C> where C: SingleBlockDecrypt {
-    fn decrypt(&mut self, data: &[u8]) -> Vec<u8> {
-        debug_assert!(data.len() >= self.bs());
+    fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
         let bs = self.bs();
+        if data.len() < bs { Err(DecryptFail::ShortInput)? };
         let pos = data.len() / bs * bs;
         let (head, tail) = data.split_at(pos);
         let (head, stealer) = head.split_at(pos - bs);
 
         if tail.is_empty() {
-            BlockDecrypt::decrypt::<NoPadding>(self, &[
-                &head[..pos-bs],
-                stealer,
-                &head[pos-bs..]
-            ].concat()).unwrap()
+            BlockDecrypt::decrypt::<NoPadding>(self, &data[..pos])
         } else {
-            let head = BlockDecrypt::decrypt::<NoPadding>(self, head).unwrap();
-            let tweak_tail = self.tweak.clone();
-            self.set_tweak(&Self::next_tweak(&tweak_tail));
-            let tweak_stealer = self.tweak.clone();
-            self.set_tweak(&Self::next_tweak(&tweak_stealer));
-
-            let stealer = xex_decrypt(&self.cipher, stealer, &tweak_stealer);
-
-            [
-                &head,
-                &xex_decrypt(
-                    &self.cipher,
-                    &[tail, &stealer[tail.len()..]].concat(),
-                    &tweak_tail
-                ),
-                &stealer[..tail.len()]
-            ].concat()
+            let head = BlockDecrypt::decrypt::<NoPadding>(self, head)?;
+            let mut tweak_tail = self.tweak.clone();
+            mul_alpha(&mut self.tweak);
+            let mut tweak_stealer = self.tweak.clone();
+            mul_alpha(&mut self.tweak);
+
+            let out = xex_decrypt(&self.cipher, stealer, &tweak_stealer)
+                .and_then(|stealer| Ok([
+                    &head,
+                    &xex_decrypt(
+                        &self.cipher,
+                        &[tail, &stealer[tail.len()..]].concat(),
+                        &tweak_tail
+                    )?,
+                    &stealer[..tail.len()]
+                ].concat()));
+            tweak_tail.zeroize();
+            tweak_stealer.zeroize();
+            out
         }
     }
 }
 
-fn xex_encrypt<C: SingleBlockEncrypt>(cipher: &C, data: &[u8], tweak: &[u8]) -> Vec<u8> {
-    xor(
-        &cipher.encrypt(&xor(data, tweak)),
-        tweak
-    )
+/// Multiply the tweak by `x` in GF(2^128), little-endian.
+fn mul_alpha(tweak: &mut [u8]) {
+    let mut carry = 0;
+
+    for b in tweak.iter_mut() {
+        let next = *b >> 7;
+        *b = (*b << 1) | carry;
+        carry = next;
+    }
+
+    if carry != 0 {
+        tweak[0] ^= 0x87;
+    }
+}
+
+/// Intermediates are one xor away from the tweak, so they are wiped too.
+fn xex_encrypt<C: SingleBlockEncrypt>(cipher: &C, data: &[u8], tweak: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+    let mut block = xor(data, tweak);
+    let mut out = cipher.encrypt(&block);
+    block.zeroize();
+    if let Ok(ref mut out) = out { xor_in_place(out, tweak) };
+    out
 }
 
-fn xex_decrypt<C: SingleBlockDecrypt>(cipher: &C, data: &[u8], tweak: &[u8]) -> Vec<u8> {
-    xor(
-        &cipher.decrypt(&xor(data, tweak)),
-        tweak
-    )
+fn xex_decrypt<C: SingleBlockDecrypt>(cipher: &C, data: &[u8], tweak: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+    let mut block = xor(data, tweak);
+    let mut out = cipher.decrypt(&block);
+    block.zeroize();
+    if let Ok(ref mut out) = out { xor_in_place(out, tweak) };
+    out
 }
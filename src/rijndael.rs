@@ -0,0 +1,221 @@
+//! Rijndael with every block and key size of the original submission, 128 to 256 bits in 32-bit steps.
+//!
+//! `Rijndael<N4, Nk>` is AES. Blocks wider than 128 bits are not a `Block`,
+//! so this only implements the `Vec` based traits and works through `bs()`.
+
+use std::{ cmp, fmt };
+use std::marker::PhantomData;
+use ::aes::{ SBOX, RSBOX, gmul };
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, KeyInit, SingleBlockEncrypt, SingleBlockDecrypt };
+
+
+/// A length in 32-bit words, `Nb` for the block or `Nk` for the key.
+pub trait Words {
+    type Bytes: AsRef<[u8]>;
+    fn words() -> usize;
+}
+
+macro_rules! words {
+    ( $( $name:ident = $n:expr ),* ) => {
+        $(
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl Words for $name {
+                type Bytes = [u8; $n * 4];
+                fn words() -> usize { $n }
+            }
+        )*
+    }
+}
+
+words!(N4 = 4, N5 = 5, N6 = 6, N7 = 7, N8 = 8);
+
+
+#[derive(Clone)]
+pub struct Rijndael<B, K> {
+    round_keys: Vec<[u8; 4]>,
+    size: PhantomData<(B, K)>
+}
+
+impl<B, K> Rijndael<B, K> where B: Words, K: Words {
+    pub fn new(key: &K::Bytes) -> Rijndael<B, K> {
+        let (nb, nk) = (B::words(), K::words());
+        let rounds = cmp::max(nb, nk) + 6;
+        let mut round_keys = vec![[0; 4]; nb * (rounds + 1)];
+
+        for (w, k) in round_keys.iter_mut().zip(key.as_ref().chunks(4)) {
+            w.copy_from_slice(k);
+        }
+
+        let mut rcon = 1;
+        for i in nk..round_keys.len() {
+            let mut tmp = round_keys[i - 1];
+            if i % nk == 0 {
+                tmp = sub_word(&[tmp[1], tmp[2], tmp[3], tmp[0]]);
+                tmp[0] ^= rcon;
+                rcon = gmul(rcon, 2);
+            } else if nk > 6 && i % nk == 4 {
+                tmp = sub_word(&tmp);
+            }
+            for j in 0..4 {
+                round_keys[i][j] = round_keys[i - nk][j] ^ tmp[j];
+            }
+        }
+
+        Rijndael { round_keys: round_keys, size: PhantomData }
+    }
+
+    fn rounds(&self) -> usize {
+        self.round_keys.len() / B::words() - 1
+    }
+
+    fn round_key(&self, round: usize) -> &[[u8; 4]] {
+        let nb = B::words();
+        &self.round_keys[round * nb..(round + 1) * nb]
+    }
+}
+
+impl<B, K> KeyInit for Rijndael<B, K> where B: Words, K: Words {
+    type Key = K::Bytes;
+    fn init(key: &K::Bytes) -> Result<Rijndael<B, K>, DecryptFail> {
+        Ok(Rijndael::new(key))
+    }
+}
+
+impl<B, K> Zeroize for Rijndael<B, K> {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+impl<B, K> Drop for Rijndael<B, K> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<B, K> fmt::Debug for Rijndael<B, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Rijndael { .. }")
+    }
+}
+
+impl<B, K> SingleBlockEncrypt for Rijndael<B, K> where B: Words, K: Words {
+    fn bs() -> usize { B::words() * 4 }
+
+    /// ```
+    /// use aes::rijndael::{ Rijndael, N4 };
+    /// use aes::cipher::SingleBlockEncrypt;
+    /// assert_eq!(
+    ///     Rijndael::<N4, N4>::new(b"0123456789123456").encrypt(b"0987654321123456").unwrap(),
+    ///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+    /// );
+    /// ```
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let nb = B::words();
+        if data.len() != nb * 4 { Err(DecryptFail::MisalignedInput)? };
+        let rounds = self.rounds();
+        let mut state = load(data);
+
+        {
+            let state = &mut state[..nb];
+            add_round_key(state, self.round_key(0));
+            for round in 1..rounds + 1 {
+                sub_bytes(state, &*SBOX);
+                shift_rows(state, false);
+                if round != rounds { mix_columns(state, &[0x02, 0x03, 0x01, 0x01]) };
+                add_round_key(state, self.round_key(round));
+            }
+        }
+
+        let out = state[..nb].concat();
+        state[..].zeroize();
+        Ok(out)
+    }
+}
+
+impl<B, K> SingleBlockDecrypt for Rijndael<B, K> where B: Words, K: Words {
+    fn bs() -> usize { B::words() * 4 }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, DecryptFail> {
+        let nb = B::words();
+        if data.len() != nb * 4 { Err(DecryptFail::MisalignedInput)? };
+        let rounds = self.rounds();
+        let mut state = load(data);
+
+        {
+            let state = &mut state[..nb];
+            add_round_key(state, self.round_key(rounds));
+            for round in (0..rounds).rev() {
+                shift_rows(state, true);
+                sub_bytes(state, &*RSBOX);
+                add_round_key(state, self.round_key(round));
+                if round != 0 { mix_columns(state, &[0x0e, 0x0b, 0x0d, 0x09]) };
+            }
+        }
+
+        let out = state[..nb].concat();
+        state[..].zeroize();
+        Ok(out)
+    }
+}
+
+
+fn load(data: &[u8]) -> [[u8; 4]; 8] {
+    let mut state = [[0; 4]; 8];
+    for (column, b) in state.iter_mut().zip(data.chunks(4)) {
+        column.copy_from_slice(b);
+    }
+    state
+}
+
+fn sub_word(w: &[u8; 4]) -> [u8; 4] {
+    [SBOX[w[0] as usize], SBOX[w[1] as usize], SBOX[w[2] as usize], SBOX[w[3] as usize]]
+}
+
+fn add_round_key(state: &mut [[u8; 4]], round_key: &[[u8; 4]]) {
+    for (column, k) in state.iter_mut().zip(round_key) {
+        for j in 0..4 {
+            column[j] ^= k[j];
+        }
+    }
+}
+
+fn sub_bytes(state: &mut [[u8; 4]], sbox: &[u8; 256]) {
+    for b in state.iter_mut().flat_map(|column| column.iter_mut()) {
+        *b = sbox[*b as usize];
+    }
+}
+
+/// Row `r` moves left by `C_r` columns, the offsets grow for 224 and 256-bit blocks.
+fn shift_rows(state: &mut [[u8; 4]], inverse: bool) {
+    let nb = state.len();
+    let offsets = match nb {
+        7 => [0, 1, 2, 4],
+        8 => [0, 1, 3, 4],
+        _ => [0, 1, 2, 3]
+    };
+
+    for row in 1..4 {
+        let mut tmp = [0; 8];
+        for c in 0..nb {
+            tmp[c] = state[c][row];
+        }
+        for c in 0..nb {
+            let shift = if inverse { nb - offsets[row] } else { offsets[row] };
+            state[c][row] = tmp[(c + shift) % nb];
+        }
+    }
+}
+
+/// Multiply every column by the circulant matrix whose first row is `m`.
+fn mix_columns(state: &mut [[u8; 4]], m: &[u8; 4]) {
+    for column in state.iter_mut() {
+        let c = *column;
+        for j in 0..4 {
+            column[j] = (0..4).fold(0, |sum, k| sum ^ gmul(m[(k + 4 - j) % 4], c[k]));
+        }
+    }
+}
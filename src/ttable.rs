@@ -0,0 +1,193 @@
+//! Table-driven AES (32-bit T-tables).
+//!
+//! Much faster than `AES::portable`, but every round indexes the tables with secret bytes,
+//! so it leaks through cache timing. Only use it where that is not part of the threat model.
+
+use std::fmt;
+use ::aes::{ SBOX, RSBOX, gmul, key_expansion, inv_mix_columns };
+use ::utils::state::State;
+use ::utils::zeroize::Zeroize;
+use ::cipher::{ DecryptFail, Block, KeyInit, FixedBlockEncrypt, FixedBlockDecrypt };
+
+
+lazy_static!{
+    /// `TE[0][x]` is the column `[2, 1, 1, 3] * SBOX[x]`, `TE[n]` is `TE[0]` rotated right by `n` bytes.
+    pub static ref TE: [[u32; 256]; 4] = {
+        let mut te = [[0; 256]; 4];
+        for x in 0..256 {
+            let s = SBOX[x];
+            let w = word(&[gmul(s, 2), s, s, gmul(s, 3)]);
+            for n in 0..4 {
+                te[n][x] = w.rotate_right(8 * n as u32);
+            }
+        }
+        te
+    };
+
+    /// `TD[0][x]` is the column `[14, 9, 13, 11] * RSBOX[x]`, `TD[n]` is `TD[0]` rotated right by `n` bytes.
+    pub static ref TD: [[u32; 256]; 4] = {
+        let mut td = [[0; 256]; 4];
+        for x in 0..256 {
+            let s = RSBOX[x];
+            let w = word(&[gmul(s, 14), gmul(s, 9), gmul(s, 13), gmul(s, 11)]);
+            for n in 0..4 {
+                td[n][x] = w.rotate_right(8 * n as u32);
+            }
+        }
+        td
+    };
+}
+
+
+#[derive(Clone)]
+pub struct AesTable {
+    enc_keys: Vec<[u32; 4]>,
+    dec_keys: Vec<[u32; 4]>
+}
+
+impl AesTable {
+    pub fn new(key: &[u8]) -> Result<AesTable, DecryptFail> {
+        let rounds = 10 + (key.len() / 4) - 4;
+        let mut round_keys = vec![[[0; 4]; 4]; rounds + 1];
+        key_expansion(key, &mut round_keys)?;
+
+        let enc_keys = round_keys.iter()
+            .map(words)
+            .collect::<Vec<_>>();
+
+        // Equivalent inverse cipher: reversed schedule, `InvMixColumns` on the inner round keys.
+        let dec_keys = round_keys.iter()
+            .rev()
+            .enumerate()
+            .map(|(i, rk)| if i == 0 || i == rounds {
+                words(rk)
+            } else {
+                words(&inv_mix_columns(rk))
+            })
+            .collect::<Vec<_>>();
+        round_keys.zeroize();
+
+        Ok(AesTable { enc_keys: enc_keys, dec_keys: dec_keys })
+    }
+}
+
+impl Zeroize for AesTable {
+    fn zeroize(&mut self) {
+        self.enc_keys.zeroize();
+        self.dec_keys.zeroize();
+    }
+}
+
+impl Drop for AesTable {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for AesTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AesTable { .. }")
+    }
+}
+
+impl KeyInit for AesTable {
+    type Key = [u8];
+    fn init(key: &[u8]) -> Result<AesTable, DecryptFail> {
+        AesTable::new(key)
+    }
+}
+
+impl FixedBlockEncrypt for AesTable {
+    /// ```
+    /// use aes::ttable::AesTable;
+    /// use aes::cipher::SingleBlockEncrypt;
+    /// assert_eq!(
+    ///     AesTable::new(b"0123456789123456").unwrap().encrypt(b"0987654321123456").unwrap(),
+    ///     [215, 88, 51, 56, 75, 78, 81, 214, 230, 55, 134, 27, 39, 58, 179, 70]
+    /// );
+    /// ```
+    fn encrypt_block(&self, block: &mut Block) {
+        let rounds = self.enc_keys.len() - 1;
+        let mut s = xor(&load(block), &self.enc_keys[0]);
+
+        for rk in &self.enc_keys[1..rounds] {
+            s = xor(&[
+                TE[0][byte(s[0], 0)] ^ TE[1][byte(s[1], 1)] ^ TE[2][byte(s[2], 2)] ^ TE[3][byte(s[3], 3)],
+                TE[0][byte(s[1], 0)] ^ TE[1][byte(s[2], 1)] ^ TE[2][byte(s[3], 2)] ^ TE[3][byte(s[0], 3)],
+                TE[0][byte(s[2], 0)] ^ TE[1][byte(s[3], 1)] ^ TE[2][byte(s[0], 2)] ^ TE[3][byte(s[1], 3)],
+                TE[0][byte(s[3], 0)] ^ TE[1][byte(s[0], 1)] ^ TE[2][byte(s[1], 2)] ^ TE[3][byte(s[2], 3)]
+            ], rk);
+        }
+
+        let sub = |a: u32, b: u32, c: u32, d: u32| word(&[
+            SBOX[byte(a, 0)], SBOX[byte(b, 1)], SBOX[byte(c, 2)], SBOX[byte(d, 3)]
+        ]);
+        store(&xor(&[
+            sub(s[0], s[1], s[2], s[3]),
+            sub(s[1], s[2], s[3], s[0]),
+            sub(s[2], s[3], s[0], s[1]),
+            sub(s[3], s[0], s[1], s[2])
+        ], &self.enc_keys[rounds]), block);
+    }
+}
+
+impl FixedBlockDecrypt for AesTable {
+    fn decrypt_block(&self, block: &mut Block) {
+        let rounds = self.dec_keys.len() - 1;
+        let mut s = xor(&load(block), &self.dec_keys[0]);
+
+        for rk in &self.dec_keys[1..rounds] {
+            s = xor(&[
+                TD[0][byte(s[0], 0)] ^ TD[1][byte(s[3], 1)] ^ TD[2][byte(s[2], 2)] ^ TD[3][byte(s[1], 3)],
+                TD[0][byte(s[1], 0)] ^ TD[1][byte(s[0], 1)] ^ TD[2][byte(s[3], 2)] ^ TD[3][byte(s[2], 3)],
+                TD[0][byte(s[2], 0)] ^ TD[1][byte(s[1], 1)] ^ TD[2][byte(s[0], 2)] ^ TD[3][byte(s[3], 3)],
+                TD[0][byte(s[3], 0)] ^ TD[1][byte(s[2], 1)] ^ TD[2][byte(s[1], 2)] ^ TD[3][byte(s[0], 3)]
+            ], rk);
+        }
+
+        let sub = |a: u32, b: u32, c: u32, d: u32| word(&[
+            RSBOX[byte(a, 0)], RSBOX[byte(b, 1)], RSBOX[byte(c, 2)], RSBOX[byte(d, 3)]
+        ]);
+        store(&xor(&[
+            sub(s[0], s[3], s[2], s[1]),
+            sub(s[1], s[0], s[3], s[2]),
+            sub(s[2], s[1], s[0], s[3]),
+            sub(s[3], s[2], s[1], s[0])
+        ], &self.dec_keys[rounds]), block);
+    }
+}
+
+
+/// Big-endian word of a column, so row `0` is the most significant byte.
+fn word(column: &[u8; 4]) -> u32 {
+    (column[0] as u32) << 24 | (column[1] as u32) << 16 | (column[2] as u32) << 8 | column[3] as u32
+}
+
+fn byte(w: u32, row: usize) -> usize {
+    (w >> (24 - 8 * row) & 0xff) as usize
+}
+
+fn words(state: &State) -> [u32; 4] {
+    [word(&state[0]), word(&state[1]), word(&state[2]), word(&state[3])]
+}
+
+fn load(data: &Block) -> [u32; 4] {
+    [
+        word(&[data[0], data[1], data[2], data[3]]),
+        word(&[data[4], data[5], data[6], data[7]]),
+        word(&[data[8], data[9], data[10], data[11]]),
+        word(&[data[12], data[13], data[14], data[15]])
+    ]
+}
+
+fn store(s: &[u32; 4], out: &mut Block) {
+    for (i, &w) in s.iter().enumerate() {
+        for row in 0..4 {
+            out[i * 4 + row] = byte(w, row) as u8;
+        }
+    }
+}
+
+fn xor(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    [a[0] ^ b[0], a[1] ^ b[1], a[2] ^ b[2], a[3] ^ b[3]]
+}
@@ -1,76 +1,144 @@
+use std::{ cmp, fmt };
 use num::{ BigUint, Zero, One };
+use ::cipher::DecryptFail;
+use super::zeroize::Zeroize;
 
 
-#[derive(Clone, Debug)]
+/// Field elements are kept as two big-endian `u64` halves, so they can be wiped.
+///
+/// Associated data goes in first, then ciphertext; either may arrive in chunks of any size.
+#[derive(Clone)]
 pub struct Ghash {
-    key: BigUint,
-    state: BigUint,
+    key: [u64; 2],
+    state: [u64; 2],
     buffer: Vec<u8>,
-    aad_len: BigUint,
-    txt_len: usize
+    aad_len: usize,
+    txt_len: usize,
+    in_text: bool
 }
 
 impl Ghash {
-    pub fn new(key: &[u8], aad: &[u8]) -> Ghash {
-        let mut ghash = Ghash {
-            key: BigUint::from_bytes_be(key),
-            state: BigUint::zero(),
-            buffer: Vec::with_capacity(15),
-            aad_len: BigUint::from(aad.len() * 8) << 64,
-            txt_len: 0
-        };
-        ghash.update(&[
-            aad,
-            &vec![0; (16 - aad.len() % 16) % 16]
-        ].concat());
-        ghash
+    pub fn new(key: &[u8]) -> Result<Ghash, DecryptFail> {
+        if key.len() != 16 { Err(DecryptFail::InvalidKeyLength)? };
+        Ok(Ghash {
+            key: load(key),
+            state: [0; 2],
+            buffer: Vec::with_capacity(16),
+            aad_len: 0,
+            txt_len: 0,
+            in_text: false
+        })
     }
 
-    // TODO Pre tablet
-    fn xor_mult(&self, p: &BigUint, q: &BigUint) -> BigUint {
-        gmult(&self.key, &(p ^ q))
+    fn xor_mult(&self, p: &[u64; 2], q: &[u64; 2]) -> [u64; 2] {
+        gf_mult(&self.key, &[p[0] ^ q[0], p[1] ^ q[1]])
     }
 
+    /// Hash whole blocks as they fill up, keep the rest in `buffer`.
     fn update(&mut self, data: &[u8]) {
-        let mut buffer = self.buffer.clone();
-        buffer.extend_from_slice(data);
-        let pos = buffer.len() / 16 * 16;
-        let (head, tail) = buffer.split_at(pos);
-        for b in head.chunks(16).map(BigUint::from_bytes_be) {
-            self.state = self.xor_mult(&self.state, &b);
+        let mut data = data;
+        if !self.buffer.is_empty() {
+            let n = cmp::min(16 - self.buffer.len(), data.len());
+            self.buffer.extend_from_slice(&data[..n]);
+            data = &data[n..];
+            if self.buffer.len() < 16 { return };
+            self.state = self.xor_mult(&self.state, &load(&self.buffer));
+            self.buffer.zeroize();
         }
-        self.buffer = tail.into();
+
+        let pos = data.len() / 16 * 16;
+        for b in data[..pos].chunks(16) {
+            self.state = self.xor_mult(&self.state, &load(b));
+        }
+        self.buffer.extend_from_slice(&data[pos..]);
+    }
+
+    /// The state with the partial block in `buffer` zero-padded and hashed.
+    fn padded_state(&self) -> [u64; 2] {
+        if self.buffer.is_empty() {
+            self.state
+        } else {
+            self.xor_mult(&self.state, &load(&self.buffer))
+        }
+    }
+
+    pub fn update_aad(&mut self, aad: &[u8]) -> Result<&mut Self, DecryptFail> {
+        if self.in_text { Err(DecryptFail::AadAfterText)? };
+        self.update(aad);
+        self.aad_len += aad.len();
+        Ok(self)
     }
 
-    pub fn input(&mut self, data: &[u8]) -> Ghash {
+    /// The first call closes the associated data, padding it to a whole block.
+    pub fn update_ciphertext(&mut self, data: &[u8]) -> &mut Self {
+        if !self.in_text {
+            self.state = self.padded_state();
+            self.buffer.zeroize();
+            self.in_text = true;
+        }
         self.update(data);
         self.txt_len += data.len();
-        self.clone()
+        self
     }
 
-    pub fn result(&self) -> Vec<u8> {
-        let state = if self.buffer.is_empty() {
-            self.state.clone()
-        } else {
-            self.xor_mult(&self.state, &BigUint::from_bytes_be(&[
-                self.buffer.clone(),
-                vec![0; (16 - self.buffer.len() % 16) % 16]
-            ].concat()))
-        };
-
-        let out = self.xor_mult(
-            &state,
-            &(&self.aad_len | BigUint::from(self.txt_len * 8))
-        ).to_bytes_be();
-
-        [
-            vec![0; 16 - out.len()],
-            out
-        ].concat()
+    /// The hash of everything so far; more input may still follow.
+    pub fn finalize(&self) -> Vec<u8> {
+        store(&self.xor_mult(
+            &self.padded_state(),
+            &[(self.aad_len as u64) * 8, (self.txt_len as u64) * 8]
+        ))
+    }
+}
+
+impl Zeroize for Ghash {
+    fn zeroize(&mut self) {
+        self.key[..].zeroize();
+        self.state[..].zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl Drop for Ghash {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for Ghash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Ghash { .. }")
     }
 }
 
 
+fn load(b: &[u8]) -> [u64; 2] {
+    let mut out = [0; 2];
+    for (i, &byte) in b.iter().enumerate() {
+        out[i / 8] |= (byte as u64) << (56 - 8 * (i % 8));
+    }
+    out
+}
+
+fn store(x: &[u64; 2]) -> Vec<u8> {
+    (0..16).map(|i| (x[i / 8] >> (56 - 8 * (i % 8))) as u8).collect()
+}
+
+/// Multiplication in GF(2^128), without secret-dependent branches.
+pub fn gf_mult(x: &[u64; 2], y: &[u64; 2]) -> [u64; 2] {
+    let mut out = [0; 2];
+    let mut v = *y;
+    for i in 0..128 {
+        let mask = 0u64.wrapping_sub((x[i / 64] >> (63 - i % 64)) & 1);
+        out[0] ^= v[0] & mask;
+        out[1] ^= v[1] & mask;
+
+        let carry = 0u64.wrapping_sub(v[1] & 1);
+        v[1] = (v[1] >> 1) | (v[0] << 63);
+        v[0] = (v[0] >> 1) ^ (0xe1 << 56 & carry);
+    }
+    out
+}
+
 pub fn gmult(x: &BigUint, y: &BigUint) -> BigUint {
     let one = BigUint::one();
     let e1 = BigUint::from(0xe1u32) << 120;
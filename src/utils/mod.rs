@@ -1,6 +1,11 @@
 pub mod state;
 pub mod padding;
 pub mod ghash;
+pub mod polyval;
+pub mod zeroize;
+
+use std::{ cmp, slice };
+use ::cipher::{ Block, DecryptFail };
 
 
 pub fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
@@ -11,6 +16,38 @@ pub fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
         .collect()
 }
 
+pub fn xor_in_place(a: &mut [u8], b: &[u8]) {
+    debug_assert_eq!(a.len(), b.len());
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        *x ^= y;
+    }
+}
+
+/// Use up the leftover keystream in `buffer` on the front of `data`, return how many bytes were consumed.
+pub fn take_keystream(buffer: &mut Vec<u8>, data: &mut [u8]) -> usize {
+    let n = cmp::min(buffer.len(), data.len());
+    xor_in_place(&mut data[..n], &buffer[..n]);
+    buffer.drain(..n);
+    n
+}
+
+/// Multiply a 128-bit block by `x` in GF(2^128), big-endian, as CMAC's subkeys are derived.
+pub fn dbl(b: &mut [u8]) -> Result<(), DecryptFail> {
+    if b.len() != 16 { Err(DecryptFail::MisalignedInput)? };
+    let carry = b[0] >> 7;
+    for i in 0..15 {
+        b[i] = (b[i] << 1) | (b[i + 1] >> 7);
+    }
+    b[15] = (b[15] << 1) ^ (0x87 * carry);
+    Ok(())
+}
+
+/// View a whole number of blocks as `Block`s, without copying.
+pub fn as_blocks_mut(data: &mut [u8]) -> Result<&mut [Block], DecryptFail> {
+    if data.len() % 16 != 0 { Err(DecryptFail::MisalignedInput)? };
+    Ok(unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut Block, data.len() / 16) })
+}
+
 pub fn eq(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() { return false };
 
@@ -6,32 +6,36 @@ pub enum PaddingError {
 }
 
 pub trait Padding {
-    fn padding(&[u8], usize) -> Vec<u8>;
+    fn padding(&[u8], usize) -> Result<Vec<u8>, PaddingError>;
     fn unpadding(&[u8], usize) -> Result<Vec<u8>, PaddingError>;
 }
 
 
+#[derive(Clone, Copy, Debug)]
 pub struct NoPadding;
 
 impl Padding for NoPadding {
-    fn padding(data: &[u8], _: usize) -> Vec<u8> { data.into() }
+    fn padding(data: &[u8], _: usize) -> Result<Vec<u8>, PaddingError> { Ok(data.into()) }
     fn unpadding(data: &[u8], _: usize) -> Result<Vec<u8>, PaddingError> { Ok(data.into()) }
 }
 
 
+#[derive(Clone, Copy, Debug)]
 pub struct Pkcs7Padding;
 
 impl Padding for Pkcs7Padding {
-    fn padding(data: &[u8], len: usize) -> Vec<u8> {
+    fn padding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len == 0 || len > 255 { Err(PaddingError::Other)? };
         let pad = len - data.len() % len;
         let mut out: Vec<u8> = data.into();
         out.append(&mut vec![pad as u8; pad]);
-        out
+        Ok(out)
     }
     fn unpadding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
-        if data.len() % len != 0 { Err(PaddingError::BadData)? };
+        if len == 0 || len > 255 { Err(PaddingError::Other)? };
+        if data.is_empty() || data.len() % len != 0 { Err(PaddingError::BadData)? };
         let &pad = data.last().unwrap();
-        if len < pad as usize { Err(PaddingError::BadPadding)? };
+        if pad == 0 || len < pad as usize { Err(PaddingError::BadPadding)? };
         let data_len = data.len() - pad as usize;
 
         if !data[data_len..].iter().any(|&r| r != pad) {
@@ -41,3 +45,83 @@ impl Padding for Pkcs7Padding {
         }
     }
 }
+
+
+/// ISO/IEC 9797-1 padding method 1: zero bytes up to a whole block, an empty input becomes one zero block.
+///
+/// Only for MACs, the original length is lost.
+#[derive(Clone, Copy, Debug)]
+pub struct ZeroPadding;
+
+impl Padding for ZeroPadding {
+    fn padding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len == 0 { Err(PaddingError::Other)? };
+        let mut out: Vec<u8> = data.into();
+        let padded = if data.is_empty() { len } else { (data.len() + len - 1) / len * len };
+        out.resize(padded, 0);
+        Ok(out)
+    }
+    fn unpadding(_: &[u8], _: usize) -> Result<Vec<u8>, PaddingError> {
+        Err(PaddingError::Other)
+    }
+}
+
+
+/// ISO/IEC 9797-1 padding method 2, also ISO/IEC 7816-4: a `0x80` byte, then zero bytes up to a whole block.
+#[derive(Clone, Copy, Debug)]
+pub struct BitPadding;
+
+impl Padding for BitPadding {
+    fn padding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len == 0 { Err(PaddingError::Other)? };
+        let mut out: Vec<u8> = data.into();
+        out.push(0x80);
+        out.resize((data.len() / len + 1) * len, 0);
+        Ok(out)
+    }
+    fn unpadding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len == 0 { Err(PaddingError::Other)? };
+        if data.is_empty() || data.len() % len != 0 { Err(PaddingError::BadData)? };
+        let last = data.len() - len;
+        match data[last..].iter().rposition(|&b| b != 0) {
+            Some(i) if data[last + i] == 0x80 => Ok(data[..last + i].into()),
+            _ => Err(PaddingError::BadPadding)
+        }
+    }
+}
+
+
+/// ISO/IEC 9797-1 padding method 3: a block holding the input length in bits, big-endian,
+/// in front of the input zero-padded to a whole block.
+#[derive(Clone, Copy, Debug)]
+pub struct LengthPadding;
+
+impl Padding for LengthPadding {
+    fn padding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len < 8 { Err(PaddingError::Other)? };
+        let bits = data.len() as u64 * 8;
+        let mut out = vec![0; len - 8];
+        out.extend((0..8).rev().map(|i| (bits >> (8 * i)) as u8));
+        out.extend_from_slice(data);
+        out.resize((data.len() + len - 1) / len * len + len, 0);
+        Ok(out)
+    }
+    fn unpadding(data: &[u8], len: usize) -> Result<Vec<u8>, PaddingError> {
+        if len < 8 { Err(PaddingError::Other)? };
+        if data.is_empty() || data.len() % len != 0 { Err(PaddingError::BadData)? };
+        let (head, rest) = data.split_at(len);
+        let bits = head[len - 8..].iter().fold(0, |acc, &b| acc << 8 | b as u64);
+        let data_len = (bits / 8) as usize;
+
+        if head[..len - 8].iter().any(|&b| b != 0)
+            || bits % 8 != 0
+            || data_len > rest.len()
+            || (data_len + len - 1) / len * len != rest.len()
+            || rest[data_len..].iter().any(|&b| b != 0)
+        {
+            Err(PaddingError::BadPadding)
+        } else {
+            Ok(rest[..data_len].into())
+        }
+    }
+}
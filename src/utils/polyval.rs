@@ -0,0 +1,67 @@
+use std::fmt;
+use ::cipher::DecryptFail;
+use super::ghash::gf_mult;
+use super::zeroize::Zeroize;
+
+
+/// POLYVAL from RFC 8452, through `Ghash`'s field multiplication:
+/// `POLYVAL(H, X) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)), ByteReverse(X)))`.
+#[derive(Clone)]
+pub struct Polyval {
+    key: [u64; 2],
+    state: [u64; 2]
+}
+
+impl Polyval {
+    pub fn new(key: &[u8]) -> Result<Polyval, DecryptFail> {
+        if key.len() != 16 { Err(DecryptFail::InvalidKeyLength)? };
+        let mut key = load(key);
+        let carry = 0u64.wrapping_sub(key[1] & 1);
+        key[1] = (key[1] >> 1) | (key[0] << 63);
+        key[0] = (key[0] >> 1) ^ (0xe1 << 56 & carry);
+
+        Ok(Polyval { key: key, state: [0; 2] })
+    }
+
+    /// Absorb `data`, zero-padded to a whole number of blocks.
+    pub fn update(&mut self, data: &[u8]) -> &mut Self {
+        for b in data.chunks(16) {
+            let b = load(b);
+            self.state = gf_mult(&self.key, &[self.state[0] ^ b[0], self.state[1] ^ b[1]]);
+        }
+        self
+    }
+
+    pub fn result(&self) -> Vec<u8> {
+        (0..16).map(|i| (self.state[1 - i / 8] >> (8 * (i % 8))) as u8).collect()
+    }
+}
+
+impl Zeroize for Polyval {
+    fn zeroize(&mut self) {
+        self.key[..].zeroize();
+        self.state[..].zeroize();
+    }
+}
+
+impl Drop for Polyval {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for Polyval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Polyval { .. }")
+    }
+}
+
+
+/// The byte-reversed block, as `Ghash` loads it. A short block is zero-padded.
+fn load(b: &[u8]) -> [u64; 2] {
+    let mut out = [0; 2];
+    for (i, &byte) in b.iter().enumerate() {
+        out[1 - i / 8] |= (byte as u64) << (8 * (i % 8));
+    }
+    out
+}
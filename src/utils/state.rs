@@ -1,4 +1,5 @@
 use ::aes::{ SBOX, RSBOX };
+use ::cipher::DecryptFail;
 
 
 #[allow(non_camel_case_types)]
@@ -8,18 +9,18 @@ pub type State = [u8x4; 4];
 /// ```
 /// use aes::utils::state::create_state;
 /// assert_eq!(
-///     create_state(&[[1, 2, 3, 4]; 4].concat()),
+///     create_state(&[[1, 2, 3, 4]; 4].concat()).unwrap(),
 ///     [[1, 2, 3, 4]; 4]
 /// );
+/// assert!(create_state(&[0; 15]).is_err());
 /// ```
-pub fn create_state(input: &[u8]) -> State {
-    assert_eq!(input.len(), 16);
+pub fn create_state(input: &[u8]) -> Result<State, DecryptFail> {
+    if input.len() != 16 { Err(DecryptFail::MisalignedInput)? };
     let mut state = [[0; 4]; 4];
     for (i, &j) in input.iter().enumerate() {
         state[i / 4][i % 4] = j;
     }
-    state
-
+    Ok(state)
 }
 
 pub trait Ops {
@@ -0,0 +1,51 @@
+//! Wiping secrets before their memory is released.
+//!
+//! Writes are volatile and followed by a compiler fence, so they are not optimized away
+//! even though the memory is about to be freed.
+
+use std::{ mem, ptr };
+use std::sync::atomic::{ compiler_fence, Ordering };
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::__m128i;
+use super::state::State;
+
+
+pub trait Zeroize {
+    fn zeroize(&mut self);
+}
+
+/// Overwrite `len` values starting at `ptr` with zero bytes.
+///
+/// Only sound for plain integer data, where the all-zero bit pattern is a valid value.
+unsafe fn wipe<T: Copy>(ptr: *mut T, len: usize) {
+    let bytes = ptr as *mut u8;
+    for i in 0..len * mem::size_of::<T>() {
+        ptr::write_volatile(bytes.offset(i as isize), 0);
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+macro_rules! zeroize_plain {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Zeroize for [$t] {
+                fn zeroize(&mut self) {
+                    unsafe { wipe(self.as_mut_ptr(), self.len()) };
+                }
+            }
+
+            /// Wipes the spare capacity too, it may still hold data left behind by `drain` or `truncate`.
+            impl Zeroize for Vec<$t> {
+                fn zeroize(&mut self) {
+                    unsafe { wipe(self.as_mut_ptr(), self.capacity()) };
+                    self.clear();
+                }
+            }
+        )*
+    }
+}
+
+zeroize_plain!(u8, u32, u64, State, [u8; 4], [u32; 4], [u64; 8]);
+
+#[cfg(target_arch = "x86_64")]
+zeroize_plain!(__m128i);
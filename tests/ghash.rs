@@ -9,6 +9,7 @@ extern crate aes;
 use num::BigUint;
 use crypto::ghash::Ghash as CryptoGhash;
 use aes::utils::ghash::{ Ghash, gmult };
+use aes::utils::polyval::Polyval;
 
 
 #[test]
@@ -31,7 +32,59 @@ fn test_ghash() {
     let text = rand!(rand!(choose 15..55));
 
     assert_eq!(
-        Ghash::new(&key, &aad).input(&text).result(),
+        Ghash::new(&key).unwrap().update_aad(&aad).unwrap().update_ciphertext(&text).finalize(),
         CryptoGhash::new(&key).input_a(&aad).input_c(&text).result()
     );
 }
+
+#[test]
+fn test_ghash_chunks() {
+    use aes::cipher::DecryptFail;
+
+    let key = rand!(16);
+    let aad = rand!(rand!(choose 15..55));
+    let text = rand!(rand!(choose 15..55));
+    let (aad_head, aad_tail) = aad.split_at(rand!(choose 0..15));
+    let (text_head, text_tail) = text.split_at(rand!(choose 0..15));
+
+    let mut ghash = Ghash::new(&key).unwrap();
+    ghash.update_aad(aad_head).unwrap().update_aad(aad_tail).unwrap();
+    ghash.update_ciphertext(text_head);
+    let head = ghash.finalize();
+    ghash.update_ciphertext(text_tail);
+
+    assert_eq!(head, CryptoGhash::new(&key).input_a(&aad).input_c(text_head).result());
+    assert_eq!(ghash.finalize(), CryptoGhash::new(&key).input_a(&aad).input_c(&text).result());
+    assert_eq!(ghash.update_aad(&aad).err(), Some(DecryptFail::AadAfterText));
+
+    let mut ghash = Ghash::new(&key).unwrap();
+    ghash.update_ciphertext(&[]);
+    assert_eq!(ghash.update_aad(&[]).err(), Some(DecryptFail::AadAfterText));
+}
+
+/// RFC 8452 Appendix A, `POLYVAL(H, X_1, X_2)`.
+#[test]
+fn test_polyval() {
+    let h = [
+        0x25, 0x62, 0x93, 0x47, 0x58, 0x92, 0x42, 0x76, 0x1d, 0x31, 0xf8, 0x26, 0xba, 0x4b, 0x75, 0x7b
+    ];
+    let x = [
+        0x4f, 0x4f, 0x95, 0x66, 0x8c, 0x83, 0xdf, 0xb6, 0x40, 0x17, 0x62, 0xbb, 0x2d, 0x01, 0xa2, 0x62,
+        0xd1, 0xa2, 0x4d, 0xdd, 0x27, 0x21, 0xd0, 0x06, 0xbb, 0xe4, 0x5f, 0x20, 0xd3, 0xc9, 0xf3, 0x62
+    ];
+
+    assert_eq!(
+        Polyval::new(&h).unwrap().update(&x).result(),
+        vec![
+            0xf7, 0xa3, 0xb4, 0x7b, 0x84, 0x61, 0x19, 0xfa, 0xe5, 0xb7, 0x86, 0x6c, 0xf5, 0xe5, 0xb7, 0x7e
+        ]
+    );
+    assert_eq!(
+        Polyval::new(&h).unwrap().update(&x[..16]).update(&x[16..]).result(),
+        Polyval::new(&h).unwrap().update(&x).result()
+    );
+    assert_eq!(
+        Polyval::new(&h).unwrap().update(&x[..20]).result(),
+        Polyval::new(&h).unwrap().update(&[&x[..20], &[0; 12][..]].concat()).result()
+    );
+}
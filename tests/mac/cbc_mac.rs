@@ -0,0 +1,116 @@
+use aes::Aes128;
+use aes::mac::{ CbcMac, RetailMac };
+use aes::utils::padding::{ Padding, NoPadding, ZeroPadding, BitPadding, LengthPadding };
+use aes::cipher::DecryptFail;
+
+
+const KEY1: [u8; 16] = [
+    0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
+];
+const KEY2: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f
+];
+
+/// The ISO/IEC 9797-1 Annex B messages. The Annex only has DES examples, these tags are
+/// for AES-128 under `KEY1` and `KEY2` and were computed independently with PyCA cryptography.
+const MESSAGES: [&'static [u8]; 2] = [b"Now is the time for all ", b"Now is the time for it"];
+
+fn check_cbc_mac<P: Padding>(tags: [[u8; 16]; 2]) {
+    for (message, tag) in MESSAGES.iter().zip(&tags) {
+        let mut mac = CbcMac::<Aes128, P>::new(&KEY1).unwrap();
+        assert_eq!(mac.compute(message).unwrap(), tag);
+    }
+}
+
+fn check_retail_mac<P: Padding>(tags: [[u8; 16]; 2]) {
+    for (message, tag) in MESSAGES.iter().zip(&tags) {
+        let mut mac = RetailMac::<Aes128, P>::new(&KEY1, &KEY2).unwrap();
+        assert_eq!(mac.compute(message).unwrap(), tag);
+    }
+}
+
+/// With the IV folded into the first block, the CBC-MAC is the last block of NIST SP 800-38A F.2.1.
+#[test]
+fn test_cbc_mac_sp800_38a() {
+    let mut message = vec![
+        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10
+    ];
+    for (m, k) in message.iter_mut().zip(&KEY2) {
+        *m ^= *k;
+    }
+
+    let mut mac = CbcMac::<Aes128, NoPadding>::new(&KEY1).unwrap();
+    assert_eq!(
+        mac.compute(&message).unwrap(),
+        [0x3f, 0xf1, 0xca, 0xa1, 0x68, 0x1f, 0xac, 0x09, 0x12, 0x0e, 0xca, 0x30, 0x75, 0x86, 0xe1, 0xa7]
+    );
+    assert_eq!(mac.compute(&message[..20]), Err(DecryptFail::MisalignedInput));
+    assert_eq!(mac.compute(b""), Err(DecryptFail::ShortInput));
+}
+
+#[test]
+fn test_cbc_mac_padding() {
+    check_cbc_mac::<ZeroPadding>([
+        [0xc4, 0x52, 0xcb, 0xd9, 0xa2, 0x07, 0x58, 0x26, 0x2a, 0x77, 0x97, 0x0f, 0xfd, 0xc2, 0x31, 0xdf],
+        [0x29, 0xc5, 0xa0, 0xb6, 0xd6, 0x55, 0x87, 0xf0, 0x50, 0x8d, 0x0a, 0x84, 0x30, 0x0d, 0xac, 0xb3]
+    ]);
+    check_cbc_mac::<BitPadding>([
+        [0x00, 0xfa, 0xc2, 0x11, 0xe9, 0xdb, 0x57, 0x4b, 0xee, 0x19, 0xc3, 0xca, 0x9e, 0xdf, 0x48, 0x08],
+        [0x7c, 0xf0, 0xaf, 0xe4, 0x7c, 0x19, 0x38, 0x33, 0x20, 0x49, 0x7d, 0x91, 0x25, 0xe2, 0x72, 0x1b]
+    ]);
+    check_cbc_mac::<LengthPadding>([
+        [0x7f, 0x9f, 0xa3, 0x0e, 0xa2, 0xfa, 0x38, 0xdf, 0x4b, 0x89, 0x69, 0xb7, 0xa2, 0xad, 0x95, 0x89],
+        [0xba, 0x23, 0x1f, 0x8e, 0x8d, 0x91, 0xc5, 0xee, 0x82, 0xe4, 0xbc, 0x3c, 0xe9, 0x02, 0xc2, 0x76]
+    ]);
+
+    let mut mac = CbcMac::<Aes128, ZeroPadding>::new(&KEY1).unwrap();
+    assert_eq!(
+        mac.compute(b"").unwrap(),
+        [0x7d, 0xf7, 0x6b, 0x0c, 0x1a, 0xb8, 0x99, 0xb3, 0x3e, 0x42, 0xf0, 0x47, 0xb9, 0x1b, 0x54, 0x6f]
+    );
+}
+
+#[test]
+fn test_retail_mac() {
+    check_retail_mac::<ZeroPadding>([
+        [0x06, 0xba, 0x8a, 0x8b, 0x88, 0x53, 0xc2, 0x06, 0x17, 0x8a, 0x96, 0xf0, 0xb8, 0x66, 0x24, 0xba],
+        [0x8b, 0xea, 0x36, 0x46, 0x7b, 0xcc, 0xcd, 0xac, 0xe8, 0x91, 0x70, 0x93, 0xa8, 0x31, 0xeb, 0x1b]
+    ]);
+    check_retail_mac::<BitPadding>([
+        [0xc6, 0xcf, 0x23, 0x37, 0xe3, 0x22, 0x37, 0xae, 0xa1, 0xf6, 0xf1, 0x5e, 0x5c, 0x22, 0xc9, 0xc4],
+        [0xa7, 0x37, 0x8e, 0x4f, 0x4e, 0x8c, 0xeb, 0xa9, 0xdf, 0xf1, 0xfa, 0x3a, 0xad, 0x4f, 0x45, 0x3d]
+    ]);
+    check_retail_mac::<LengthPadding>([
+        [0x66, 0x0a, 0x73, 0x0a, 0x2d, 0xda, 0x2f, 0xdc, 0x9d, 0x05, 0xa3, 0xfd, 0xf4, 0x3b, 0x38, 0x38],
+        [0xa7, 0x1e, 0x6a, 0xd3, 0xec, 0x3e, 0x99, 0xc4, 0x4d, 0x19, 0xfc, 0x10, 0xb5, 0x3e, 0x7e, 0xba]
+    ]);
+
+    // With both keys equal the last two steps cancel out.
+    let mut retail = RetailMac::<Aes128, BitPadding>::new(&KEY1, &KEY1).unwrap();
+    let mut mac = CbcMac::<Aes128, BitPadding>::new(&KEY1).unwrap();
+    assert_eq!(retail.compute(MESSAGES[1]).unwrap(), mac.compute(MESSAGES[1]).unwrap());
+}
+
+#[test]
+fn test_cbc_mac_truncate() {
+    let mut mac = CbcMac::<Aes128>::new(&KEY1).unwrap();
+    mac.set_tag_len(8).unwrap();
+    let tag = [0x00, 0xfa, 0xc2, 0x11, 0xe9, 0xdb, 0x57, 0x4b];
+    assert_eq!(mac.compute(MESSAGES[0]).unwrap(), tag);
+    assert_eq!(mac.verify(MESSAGES[0], &tag), Ok(()));
+    assert_eq!(mac.verify(MESSAGES[1], &tag), Err(DecryptFail::Auth));
+    assert_eq!(mac.verify(MESSAGES[0], &tag[..4]), Err(DecryptFail::Auth));
+
+    let mut retail = RetailMac::<Aes128>::new(&KEY1, &KEY2).unwrap();
+    retail.set_tag_len(4).unwrap();
+    let tag = [0xc6, 0xcf, 0x23, 0x37];
+    assert_eq!(retail.compute(MESSAGES[0]).unwrap(), tag);
+    assert_eq!(retail.verify(MESSAGES[0], &tag), Ok(()));
+    assert_eq!(retail.verify(MESSAGES[0], &[0xc6, 0xcf, 0x23, 0x36]), Err(DecryptFail::Auth));
+
+    assert_eq!(mac.set_tag_len(0).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(retail.set_tag_len(17).err(), Some(DecryptFail::InvalidTagLength));
+}
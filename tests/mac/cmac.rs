@@ -0,0 +1,182 @@
+use aes::{ AES, Aes128, Aes192, Aes256 };
+use aes::mac::Cmac;
+use aes::cipher::DecryptFail;
+
+
+/// The SP 800-38A plaintext, the examples MAC its first 0, 16, 40 and 64 bytes.
+const MESSAGE: [u8; 64] = [
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+    0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+    0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10
+];
+const LENGTHS: [usize; 4] = [0, 16, 40, 64];
+
+/// RFC 4493 section 4, and NIST SP 800-38B D.1 which shares its key.
+#[test]
+fn test_cmac_aes128() {
+    let key = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
+    ];
+    let tags: [[u8; 16]; 4] = [
+        [
+            0xbb, 0x1d, 0x69, 0x29, 0xe9, 0x59, 0x37, 0x28, 0x7f, 0xa3, 0x7d, 0x12, 0x9b, 0x75, 0x67, 0x46
+        ],
+        [
+            0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a, 0x28, 0x7c
+        ],
+        [
+            0xdf, 0xa6, 0x67, 0x47, 0xde, 0x9a, 0xe6, 0x30, 0x30, 0xca, 0x32, 0x61, 0x14, 0x97, 0xc8, 0x27
+        ],
+        [
+            0x51, 0xf0, 0xbe, 0xbf, 0x7e, 0x3b, 0x9d, 0x92, 0xfc, 0x49, 0x74, 0x17, 0x79, 0x36, 0x3c, 0xfe
+        ]
+    ];
+
+    for (&len, tag) in LENGTHS.iter().zip(tags.iter()) {
+        let mut mac = Cmac::<Aes128>::new(&key).unwrap();
+        assert_eq!(mac.input(&MESSAGE[..len]).unwrap().result().unwrap(), tag);
+        assert_eq!(mac.verify(tag), Ok(()));
+    }
+}
+
+/// NIST SP 800-38B D.2.
+#[test]
+fn test_cmac_aes192() {
+    let key = [
+        0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90, 0x79, 0xe5,
+        0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b
+    ];
+    let tags: [[u8; 16]; 4] = [
+        [
+            0xd1, 0x7d, 0xdf, 0x46, 0xad, 0xaa, 0xcd, 0xe5, 0x31, 0xca, 0xc4, 0x83, 0xde, 0x7a, 0x93, 0x67
+        ],
+        [
+            0x9e, 0x99, 0xa7, 0xbf, 0x31, 0xe7, 0x10, 0x90, 0x06, 0x62, 0xf6, 0x5e, 0x61, 0x7c, 0x51, 0x84
+        ],
+        [
+            0x8a, 0x1d, 0xe5, 0xbe, 0x2e, 0xb3, 0x1a, 0xad, 0x08, 0x9a, 0x82, 0xe6, 0xee, 0x90, 0x8b, 0x0e
+        ],
+        [
+            0xa1, 0xd5, 0xdf, 0x0e, 0xed, 0x79, 0x0f, 0x79, 0x4d, 0x77, 0x58, 0x96, 0x59, 0xf3, 0x9a, 0x11
+        ]
+    ];
+
+    for (&len, tag) in LENGTHS.iter().zip(tags.iter()) {
+        let mut mac = Cmac::<Aes192>::new(&key).unwrap();
+        assert_eq!(mac.input(&MESSAGE[..len]).unwrap().result().unwrap(), tag);
+    }
+}
+
+/// NIST SP 800-38B D.3.
+#[test]
+fn test_cmac_aes256() {
+    let key = [
+        0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77, 0x81,
+        0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14, 0xdf, 0xf4
+    ];
+    let tags: [[u8; 16]; 4] = [
+        [
+            0x02, 0x89, 0x62, 0xf6, 0x1b, 0x7b, 0xf8, 0x9e, 0xfc, 0x6b, 0x55, 0x1f, 0x46, 0x67, 0xd9, 0x83
+        ],
+        [
+            0x28, 0xa7, 0x02, 0x3f, 0x45, 0x2e, 0x8f, 0x82, 0xbd, 0x4b, 0xf2, 0x8d, 0x8c, 0x37, 0xc3, 0x5c
+        ],
+        [
+            0xaa, 0xf3, 0xd8, 0xf1, 0xde, 0x56, 0x40, 0xc2, 0x32, 0xf5, 0xb1, 0x69, 0xb9, 0xc9, 0x11, 0xe6
+        ],
+        [
+            0xe1, 0x99, 0x21, 0x90, 0x54, 0x9f, 0x6e, 0xd5, 0x69, 0x6a, 0x2c, 0x05, 0x6c, 0x31, 0x54, 0x10
+        ]
+    ];
+
+    for (&len, tag) in LENGTHS.iter().zip(tags.iter()) {
+        let mut mac = Cmac::<Aes256>::new(&key).unwrap();
+        assert_eq!(mac.input(&MESSAGE[..len]).unwrap().result().unwrap(), tag);
+    }
+}
+
+/// RFC 4493 section 4, `L = AES(K, 0)` doubles into `K1` and `K2`.
+#[test]
+fn test_cmac_subkeys() {
+    use aes::utils::dbl;
+
+    let mut k = [
+        0x7d, 0xf7, 0x6b, 0x0c, 0x1a, 0xb8, 0x99, 0xb3, 0x3e, 0x42, 0xf0, 0x47, 0xb9, 0x1b, 0x54, 0x6f
+    ];
+    dbl(&mut k).unwrap();
+    assert_eq!(k, [
+        0xfb, 0xee, 0xd6, 0x18, 0x35, 0x71, 0x33, 0x66, 0x7c, 0x85, 0xe0, 0x8f, 0x72, 0x36, 0xa8, 0xde
+    ]);
+    dbl(&mut k).unwrap();
+    assert_eq!(k, [
+        0xf7, 0xdd, 0xac, 0x30, 0x6a, 0xe2, 0x66, 0xcc, 0xf9, 0x0b, 0xc1, 0x1e, 0xe4, 0x6d, 0x51, 0x3b
+    ]);
+    assert_eq!(dbl(&mut k[..15]), Err(DecryptFail::MisalignedInput));
+}
+
+#[test]
+fn test_cmac_chunks() {
+    let key: [u8; 16] = rand!(_);
+    let data = rand!(rand!(choose 0..100));
+    let (head, tail) = data.split_at(rand!(choose 0..data.len() + 1));
+
+    let mut mac = Cmac::<Aes128>::new(&key).unwrap();
+    let tag = mac.input(&data).unwrap().result().unwrap();
+
+    let mut chunks = Cmac::<Aes128>::new(&key).unwrap();
+    chunks.input(head).unwrap().input(tail).unwrap();
+    assert_eq!(chunks.result().unwrap(), tag);
+    assert_eq!(chunks.result().unwrap(), tag);
+
+    assert_eq!(mac.reset().input(&data).unwrap().result().unwrap(), tag);
+
+    for i in 0..49 {
+        for j in i..49 {
+            chunks.reset().input(&MESSAGE[..i]).unwrap().input(&MESSAGE[i..j]).unwrap().input(&MESSAGE[j..48]).unwrap();
+            assert_eq!(chunks.result(), mac.reset().input(&MESSAGE[..48]).unwrap().result());
+        }
+    }
+}
+
+#[test]
+fn test_cmac_truncate() {
+    let key: [u8; 16] = rand!(_);
+    let data = rand!(rand!(choose 0..100));
+
+    let tag = Cmac::<Aes128>::new(&key).unwrap().input(&data).unwrap().result().unwrap();
+    let mut mac = Cmac::<Aes128>::new(&key).unwrap();
+    mac.set_tag_len(12).unwrap().input(&data).unwrap();
+    assert_eq!(mac.result().unwrap(), &tag[..12]);
+    assert_eq!(mac.verify(&tag[..12]), Ok(()));
+    assert_eq!(mac.verify(&tag), Err(DecryptFail::Auth));
+    assert_eq!(mac.verify(&tag[..8]), Err(DecryptFail::Auth));
+
+    let mut bad_tag = tag[..12].to_vec();
+    bad_tag[rand!(choose 0..12)] ^= 1;
+    assert_eq!(mac.verify(&bad_tag), Err(DecryptFail::Auth));
+
+    assert_eq!(mac.set_tag_len(0).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(mac.set_tag_len(17).err(), Some(DecryptFail::InvalidTagLength));
+}
+
+/// RFC 4615 section 4: a 128-bit key is used as is, any other is first hashed under the zero key.
+#[test]
+fn test_cmac_prf() {
+    let message: Vec<u8> = (0..20).collect();
+    let keys: [Vec<u8>; 3] = [
+        (0..16).chain(vec![0xed, 0xcb]).collect(),
+        (0..16).collect(),
+        (0..10).collect()
+    ];
+    let outputs: [[u8; 16]; 3] = [
+        [0x84, 0xa3, 0x48, 0xa4, 0xa4, 0x5d, 0x23, 0x5b, 0xab, 0xff, 0xfc, 0x0d, 0x2b, 0x4d, 0xa0, 0x9a],
+        [0x98, 0x0a, 0xe8, 0x7b, 0x5f, 0x4c, 0x9c, 0x52, 0x14, 0xf5, 0xb6, 0xa8, 0x45, 0x5e, 0x4c, 0x2d],
+        [0x29, 0x0d, 0x9e, 0x11, 0x2e, 0xdb, 0x09, 0xee, 0x14, 0x1f, 0xcf, 0x64, 0xc0, 0xb7, 0x2f, 0x3d]
+    ];
+
+    for (key, output) in keys.iter().zip(&outputs) {
+        let mut prf = Cmac::<AES>::prf(key).unwrap();
+        assert_eq!(prf.input(&message).unwrap().result().unwrap(), output);
+    }
+}
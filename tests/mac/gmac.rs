@@ -0,0 +1,52 @@
+use aes::Aes128;
+use aes::mac::Gmac;
+use aes::mode::Gcm;
+use aes::cipher::{ DecryptFail, AeadStreamEncrypt };
+
+
+/// GCM spec test case 1, and the first 128-bit AAD, empty plaintext case of NIST's gcmEncryptExtIV128.
+#[test]
+fn test_gmac_test() {
+    assert_eq!(
+        Gmac::<Aes128>::new(&[0; 16], &[0; 12]).unwrap().result(),
+        [0x58, 0xe2, 0xfc, 0xce, 0xfa, 0x7e, 0x30, 0x61, 0x36, 0x7f, 0x1d, 0x57, 0xa4, 0xe7, 0x45, 0x5a]
+    );
+
+    let key = [
+        0x77, 0xbe, 0x63, 0x70, 0x89, 0x71, 0xc4, 0xe2, 0x40, 0xd1, 0xcb, 0x79, 0xe8, 0xd7, 0x7f, 0xeb
+    ];
+    let nonce = [0xe0, 0xe0, 0x0f, 0x19, 0xfe, 0xd7, 0xba, 0x01, 0x36, 0xa7, 0x97, 0xf3];
+    let aad = [
+        0x7a, 0x43, 0xec, 0x1d, 0x9c, 0x0a, 0x5a, 0x78, 0xa0, 0xb1, 0x65, 0x33, 0xa6, 0x21, 0x3c, 0xab
+    ];
+    let tag = [
+        0x20, 0x9f, 0xcc, 0x8d, 0x36, 0x75, 0xed, 0x93, 0x8e, 0x9c, 0x71, 0x66, 0x70, 0x9d, 0xd9, 0x46
+    ];
+
+    let mut mac = Gmac::<Aes128>::new(&key, &nonce).unwrap();
+    assert_eq!(mac.input(&aad).unwrap().result(), tag);
+    assert_eq!(mac.verify(&tag), Ok(()));
+}
+
+#[test]
+fn test_gmac_chunks() {
+    let key: [u8; 16] = rand!(_);
+    let nonce = rand!(12);
+    let data = rand!(rand!(choose 0..200));
+
+    let (_, tag) = Gcm::<Aes128>::new(&key, &nonce, &data).unwrap().encrypt(&[]).unwrap();
+
+    let mut mac = Gmac::<Aes128>::new(&key, &nonce).unwrap();
+    for chunk in data.chunks(rand!(choose 1..40)) {
+        mac.input(chunk).unwrap();
+    }
+    assert_eq!(mac.result(), tag);
+    assert_eq!(mac.verify(&tag), Ok(()));
+
+    let mut bad_tag = tag.clone();
+    bad_tag[rand!(choose 0..16)] ^= 1;
+    assert_eq!(mac.verify(&bad_tag), Err(DecryptFail::Auth));
+    assert_eq!(mac.verify(&tag[..12]), Err(DecryptFail::Auth));
+
+    assert_eq!(Gmac::<Aes128>::new(&key, &rand!(16)).err(), Some(DecryptFail::InvalidNonceLength));
+}
@@ -0,0 +1,5 @@
+mod cmac;
+mod gmac;
+mod pmac;
+mod cbc_mac;
+mod xcbc;
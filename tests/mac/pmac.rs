@@ -0,0 +1,124 @@
+use std::thread;
+use aes::Aes128;
+use aes::mac::Pmac;
+use aes::cipher::DecryptFail;
+
+
+/// The published PMAC-AES-128 vectors: key `00 01 .. 0f`, messages `00 01 ..` of each length,
+/// and 1000 zero bytes.
+#[test]
+fn test_pmac_test() {
+    let mut key = [0; 16];
+    for (i, k) in key.iter_mut().enumerate() {
+        *k = i as u8;
+    }
+    let vectors: [(usize, [u8; 16]); 6] = [
+        (0, [0x43, 0x99, 0x57, 0x2c, 0xd6, 0xea, 0x53, 0x41, 0xb8, 0xd3, 0x58, 0x76, 0xa7, 0x09, 0x8a, 0xf7]),
+        (3, [0x25, 0x6b, 0xa5, 0x19, 0x3c, 0x1b, 0x99, 0x1b, 0x4d, 0xf0, 0xc5, 0x1f, 0x38, 0x8a, 0x9e, 0x27]),
+        (16, [0xeb, 0xbd, 0x82, 0x2f, 0xa4, 0x58, 0xda, 0xf6, 0xdf, 0xda, 0xd7, 0xc2, 0x7d, 0xa7, 0x63, 0x38]),
+        (20, [0x04, 0x12, 0xca, 0x15, 0x0b, 0xbf, 0x79, 0x05, 0x8d, 0x8c, 0x75, 0xa5, 0x8c, 0x99, 0x3f, 0x55]),
+        (32, [0xe9, 0x7a, 0xc0, 0x4e, 0x9e, 0x5e, 0x33, 0x99, 0xce, 0x53, 0x55, 0xcd, 0x74, 0x07, 0xbc, 0x75]),
+        (34, [0x5c, 0xba, 0x7d, 0x5e, 0xb2, 0x4f, 0x7c, 0x86, 0xcc, 0xc5, 0x46, 0x04, 0xe5, 0x3d, 0x55, 0x12])
+    ];
+
+    for &(len, tag) in &vectors {
+        let msg = (0..len as u8).collect::<Vec<u8>>();
+        let mut mac = Pmac::<Aes128>::new(&key).unwrap();
+        assert_eq!(mac.input(&msg).unwrap().result().unwrap(), tag);
+        assert_eq!(mac.verify(&tag), Ok(()));
+    }
+
+    assert_eq!(
+        Pmac::<Aes128>::new(&key).unwrap().input(&[0; 1000]).unwrap().result().unwrap(),
+        [0xc2, 0xc9, 0xfa, 0x1d, 0x99, 0x85, 0xf6, 0xf0, 0xd2, 0xaf, 0xf9, 0x15, 0xa0, 0xe8, 0xd9, 0x10]
+    );
+}
+
+#[test]
+fn test_pmac_chunks() {
+    let key: [u8; 16] = rand!(_);
+    let data = rand!(rand!(choose 0..200));
+
+    let tag = Pmac::<Aes128>::new(&key).unwrap().input(&data).unwrap().result().unwrap();
+    let mut mac = Pmac::<Aes128>::new(&key).unwrap();
+    for chunk in data.chunks(rand!(choose 1..40)) {
+        mac.input(chunk).unwrap();
+    }
+    assert_eq!(mac.result().unwrap(), tag);
+
+    let mut bad_tag = tag.clone();
+    bad_tag[rand!(choose 0..16)] ^= 1;
+    assert_eq!(mac.verify(&bad_tag), Err(DecryptFail::Auth));
+}
+
+#[test]
+fn test_pmac_merge() {
+    let key: [u8; 16] = rand!(_);
+    let data = rand!(rand!(choose 100..2000));
+    let tag = Pmac::<Aes128>::new(&key).unwrap().input(&data).unwrap().result().unwrap();
+
+    let mac = Pmac::<Aes128>::new(&key).unwrap();
+    let cuts = [0, rand!(choose 0..3), rand!(choose 3..6), data.len() / 16];
+    let handles = cuts.windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            let end = if i == 2 { data.len() } else { w[1] * 16 };
+            let part = data[w[0] * 16..end].to_vec();
+            let mut mac = mac.part(w[0]);
+            thread::spawn(move || {
+                mac.input(&part).unwrap();
+                mac
+            })
+        })
+        .collect::<Vec<_>>();
+    let parts = handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>();
+
+    let mut merged = parts[2].clone();
+    merged.merge(&parts[1]).unwrap().merge(&parts[0]).unwrap();
+    assert_eq!(merged.result().unwrap(), tag);
+
+    let mut merged = parts[0].clone();
+    merged.merge(&parts[1]).unwrap().merge(&parts[2]).unwrap();
+    assert_eq!(merged.result().unwrap(), tag);
+
+    assert_eq!(parts[0].clone().merge(&parts[2]).err(), Some(DecryptFail::NotAdjacent));
+    assert_eq!(parts[2].clone().merge(&parts[0]).err(), Some(DecryptFail::NotAdjacent));
+    assert_eq!(parts[1].clone().merge(&parts[1]).err(), Some(DecryptFail::NotAdjacent));
+}
+
+#[test]
+fn test_pmac_merge_empty() {
+    let key: [u8; 16] = rand!(_);
+    let data = rand!(32);
+    let mac = Pmac::<Aes128>::new(&key).unwrap();
+    let tag = mac.clone().input(&data).unwrap().result().unwrap();
+
+    let mut head = mac.part(0);
+    head.input(&data).unwrap();
+    let tail = mac.part(2);
+    assert_eq!(head.clone().merge(&tail).unwrap().result().unwrap(), tag);
+    assert_eq!(tail.clone().merge(&head).unwrap().result().unwrap(), tag);
+    assert_eq!(mac.part(0).merge(&head).unwrap().result().unwrap(), tag);
+
+    let mut tail = mac.part(1);
+    tail.input(&data[16..]).unwrap();
+    head = mac.part(0);
+    head.input(&data[..16]).unwrap();
+    let mut merged = mac.part(2);
+    merged.merge(&tail).unwrap().merge(&head).unwrap();
+    assert_eq!(merged.result().unwrap(), tag);
+}
+
+#[test]
+fn test_pmac_merge_misaligned() {
+    let key: [u8; 16] = rand!(_);
+    let mac = Pmac::<Aes128>::new(&key).unwrap();
+
+    let mut head = mac.part(0);
+    head.input(&rand!(20)).unwrap();
+    let mut tail = mac.part(2);
+    tail.input(&rand!(20)).unwrap();
+
+    assert_eq!(tail.merge(&head).err(), Some(DecryptFail::MisalignedInput));
+    assert_eq!(head.merge(&tail).err(), Some(DecryptFail::MisalignedInput));
+}
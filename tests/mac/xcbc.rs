@@ -0,0 +1,79 @@
+use aes::mac::Xcbc;
+use aes::cipher::DecryptFail;
+
+
+/// RFC 3566 section 4 test cases 1 to 7: the key and the messages count up from 0, the last is zeros.
+#[test]
+fn test_xcbc_mac() {
+    let key: Vec<u8> = (0..16).collect();
+    let messages: [Vec<u8>; 7] = [
+        vec![],
+        (0..3).collect(),
+        (0..16).collect(),
+        (0..20).collect(),
+        (0..32).collect(),
+        (0..34).collect(),
+        vec![0; 1000]
+    ];
+    let tags: [[u8; 16]; 7] = [
+        [0x75, 0xf0, 0x25, 0x1d, 0x52, 0x8a, 0xc0, 0x1c, 0x45, 0x73, 0xdf, 0xd5, 0x84, 0xd7, 0x9f, 0x29],
+        [0x5b, 0x37, 0x65, 0x80, 0xae, 0x2f, 0x19, 0xaf, 0xe7, 0x21, 0x9c, 0xee, 0xf1, 0x72, 0x75, 0x6f],
+        [0xd2, 0xa2, 0x46, 0xfa, 0x34, 0x9b, 0x68, 0xa7, 0x99, 0x98, 0xa4, 0x39, 0x4f, 0xf7, 0xa2, 0x63],
+        [0x47, 0xf5, 0x1b, 0x45, 0x64, 0x96, 0x62, 0x15, 0xb8, 0x98, 0x5c, 0x63, 0x05, 0x5e, 0xd3, 0x08],
+        [0xf5, 0x4f, 0x0e, 0xc8, 0xd2, 0xb9, 0xf3, 0xd3, 0x68, 0x07, 0x73, 0x4b, 0xd5, 0x28, 0x3f, 0xd4],
+        [0xbe, 0xcb, 0xb3, 0xbc, 0xcd, 0xb5, 0x18, 0xa3, 0x06, 0x77, 0xd5, 0x48, 0x1f, 0xb6, 0xb4, 0xd8],
+        [0xf0, 0xda, 0xfe, 0xe8, 0x95, 0xdb, 0x30, 0x25, 0x37, 0x61, 0x10, 0x3b, 0x5d, 0x84, 0x52, 0x8f]
+    ];
+
+    for (message, tag) in messages.iter().zip(&tags) {
+        let mut mac = Xcbc::new(&key).unwrap();
+        assert_eq!(mac.input(message).unwrap().result().unwrap(), tag);
+        assert_eq!(mac.verify(tag), Ok(()));
+
+        let mut mac_96 = Xcbc::mac_96(&key).unwrap();
+        assert_eq!(mac_96.input(message).unwrap().result().unwrap(), &tag[..12]);
+        assert_eq!(mac_96.verify(&tag[..12]), Ok(()));
+        assert_eq!(mac_96.verify(tag), Err(DecryptFail::Auth));
+
+        mac.reset();
+        for chunk in message.chunks(7) {
+            mac.input(chunk).unwrap();
+        }
+        assert_eq!(mac.result().unwrap(), tag);
+    }
+}
+
+#[test]
+fn test_xcbc_key_length() {
+    assert_eq!(Xcbc::new(&[0; 15]).err(), Some(DecryptFail::InvalidKeyLength));
+    assert_eq!(Xcbc::new(&[0; 24]).err(), Some(DecryptFail::InvalidKeyLength));
+    assert_eq!(Xcbc::mac_96(&[0; 32]).err(), Some(DecryptFail::InvalidKeyLength));
+
+    let mut mac = Xcbc::new(&[0; 16]).unwrap();
+    assert_eq!(mac.set_tag_len(0).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(mac.set_tag_len(17).err(), Some(DecryptFail::InvalidTagLength));
+}
+
+/// RFC 4434 section 4: a 128-bit key, a short one that is zero padded and a long one that is hashed.
+#[test]
+fn test_xcbc_prf() {
+    let message: Vec<u8> = (0..20).collect();
+    let keys: [Vec<u8>; 3] = [
+        (0..16).collect(),
+        (0..10).collect(),
+        (0..16).chain(vec![0xed, 0xcb]).collect()
+    ];
+    let outputs: [[u8; 16]; 3] = [
+        [0x47, 0xf5, 0x1b, 0x45, 0x64, 0x96, 0x62, 0x15, 0xb8, 0x98, 0x5c, 0x63, 0x05, 0x5e, 0xd3, 0x08],
+        [0x0f, 0xa0, 0x87, 0xaf, 0x7d, 0x86, 0x6e, 0x76, 0x53, 0x43, 0x4e, 0x60, 0x2f, 0xdd, 0xe8, 0x35],
+        [0x8c, 0xd3, 0xc9, 0x3a, 0xe5, 0x98, 0xa9, 0x80, 0x30, 0x06, 0xff, 0xb6, 0x7c, 0x40, 0xe9, 0xe4]
+    ];
+
+    for (key, output) in keys.iter().zip(&outputs) {
+        let mut prf = Xcbc::prf(key).unwrap();
+        assert_eq!(prf.input(&message).unwrap().result().unwrap(), output);
+    }
+
+    let mut mac = Xcbc::new(&keys[1].iter().cloned().chain(vec![0; 6]).collect::<Vec<u8>>()).unwrap();
+    assert_eq!(mac.input(&message).unwrap().result().unwrap(), outputs[1]);
+}
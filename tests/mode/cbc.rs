@@ -1,38 +1,97 @@
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Cbc;
 use aes::utils::padding::Pkcs7Padding;
-use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+use aes::utils::padding::NoPadding;
+use aes::cipher::{
+    BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt
+};
 
 
 #[test]
 fn test_cbc_encrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let iv = rand!(16);
     let plaintext = rand!(rand!(choose 15..65));
 
     assert_eq!(
-        Cbc::new(&key, &iv).encrypt::<Pkcs7Padding>(&plaintext),
+        Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap(),
         encrypt(Type::AES_128_CBC, &key, &iv, &plaintext)
     );
 }
 
 #[test]
 fn test_cbc_decrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let iv = rand!(16);
     let plaintext = rand!(rand!(choose 15..65));
 
     assert_eq!(
-        Cbc::new(&key, &iv).decrypt::<Pkcs7Padding>(
-            &Cbc::new(&key, &iv).encrypt::<Pkcs7Padding>(&plaintext)
+        Cbc::<Aes128>::new(&key, &iv).unwrap().decrypt::<Pkcs7Padding>(
+            &Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap()
         ).unwrap(),
         plaintext
     );
 
     assert_eq!(
-        Cbc::new(&key, &iv).decrypt::<Pkcs7Padding>(
+        Cbc::<Aes128>::new(&key, &iv).unwrap().decrypt::<Pkcs7Padding>(
             &encrypt(Type::AES_128_CBC, &key, &iv, &plaintext)
         ).unwrap(),
         plaintext
     );
 }
+
+#[test]
+fn test_cbc_in_place() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(16 * rand!(choose 1..5));
+
+    let mut data = plaintext.clone();
+    Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<NoPadding>(&plaintext).unwrap());
+    Cbc::<Aes128>::new(&key, &iv).unwrap().decrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+/// A 192-bit block takes the per-block path, both APIs must still chain the same way.
+#[test]
+fn test_cbc_wide_block() {
+    use aes::rijndael::{ Rijndael, N4, N6 };
+    use aes::utils::xor;
+    use aes::cipher::SingleBlockEncrypt;
+
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(24);
+    let plaintext = rand!(24 * rand!(choose 1..5));
+
+    let cipher = Rijndael::<N6, N4>::new(&key);
+    let mut expected = Vec::new();
+    let mut prev = iv.clone();
+    for b in plaintext.chunks(24) {
+        prev = cipher.encrypt(&xor(b, &prev)).unwrap();
+        expected.extend_from_slice(&prev);
+    }
+
+    let mut data = plaintext.clone();
+    Cbc::<Rijndael<N6, N4>>::new(&key, &iv).unwrap().encrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, expected);
+    assert_eq!(Cbc::<Rijndael<N6, N4>>::new(&key, &iv).unwrap().encrypt::<NoPadding>(&plaintext).unwrap(), expected);
+    assert_eq!(Cbc::<Rijndael<N6, N4>>::new(&key, &iv).unwrap().decrypt::<NoPadding>(&data).unwrap(), plaintext);
+    Cbc::<Rijndael<N6, N4>>::new(&key, &iv).unwrap().decrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+#[test]
+fn test_cbc_bad_iv() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Cbc::<Aes128>::new(&key, &rand!(15)).err(), Some(DecryptFail::InvalidIvLength));
+    assert_eq!(
+        Cbc::<Aes128>::new(&key, &rand!(16)).unwrap().set_iv(&rand!(17)).err(),
+        Some(DecryptFail::InvalidIvLength)
+    );
+}
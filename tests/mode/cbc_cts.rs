@@ -0,0 +1,126 @@
+use aes::Aes128;
+use aes::mode::{ Cbc, Cs1, Cs2, Cs3 };
+use aes::cipher::{ CtsBlockEncrypt, CtsBlockDecrypt };
+
+
+const KEY: [u8; 16] = [
+    0x63, 0x68, 0x69, 0x63, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x65, 0x72, 0x69, 0x79, 0x61, 0x6b, 0x69
+];
+const PLAINTEXT: &'static [u8] = b"I would like the General Gau's Chicken, please, and wonton soup.";
+
+/// RFC 3962 Appendix B, plaintext lengths and CS3 ciphertexts.
+fn rfc3962() -> Vec<(usize, &'static [u8])> {
+    vec![
+        (17, &[
+            0xc6, 0x35, 0x35, 0x68, 0xf2, 0xbf, 0x8c, 0xb4, 0xd8, 0xa5, 0x80, 0x36, 0x2d, 0xa7, 0xff, 0x7f,
+            0x97
+        ]),
+        (31, &[
+            0xfc, 0x00, 0x78, 0x3e, 0x0e, 0xfd, 0xb2, 0xc1, 0xd4, 0x45, 0xd4, 0xc8, 0xef, 0xf7, 0xed, 0x22,
+            0x97, 0x68, 0x72, 0x68, 0xd6, 0xec, 0xcc, 0xc0, 0xc0, 0x7b, 0x25, 0xe2, 0x5e, 0xcf, 0xe5
+        ]),
+        (32, &[
+            0x39, 0x31, 0x25, 0x23, 0xa7, 0x86, 0x62, 0xd5, 0xbe, 0x7f, 0xcb, 0xcc, 0x98, 0xeb, 0xf5, 0xa8,
+            0x97, 0x68, 0x72, 0x68, 0xd6, 0xec, 0xcc, 0xc0, 0xc0, 0x7b, 0x25, 0xe2, 0x5e, 0xcf, 0xe5, 0x84
+        ]),
+        (47, &[
+            0x97, 0x68, 0x72, 0x68, 0xd6, 0xec, 0xcc, 0xc0, 0xc0, 0x7b, 0x25, 0xe2, 0x5e, 0xcf, 0xe5, 0x84,
+            0xb3, 0xff, 0xfd, 0x94, 0x0c, 0x16, 0xa1, 0x8c, 0x1b, 0x55, 0x49, 0xd2, 0xf8, 0x38, 0x02, 0x9e,
+            0x39, 0x31, 0x25, 0x23, 0xa7, 0x86, 0x62, 0xd5, 0xbe, 0x7f, 0xcb, 0xcc, 0x98, 0xeb, 0xf5
+        ]),
+        (48, &[
+            0x97, 0x68, 0x72, 0x68, 0xd6, 0xec, 0xcc, 0xc0, 0xc0, 0x7b, 0x25, 0xe2, 0x5e, 0xcf, 0xe5, 0x84,
+            0x9d, 0xad, 0x8b, 0xbb, 0x96, 0xc4, 0xcd, 0xc0, 0x3b, 0xc1, 0x03, 0xe1, 0xa1, 0x94, 0xbb, 0xd8,
+            0x39, 0x31, 0x25, 0x23, 0xa7, 0x86, 0x62, 0xd5, 0xbe, 0x7f, 0xcb, 0xcc, 0x98, 0xeb, 0xf5, 0xa8
+        ]),
+        (64, &[
+            0x97, 0x68, 0x72, 0x68, 0xd6, 0xec, 0xcc, 0xc0, 0xc0, 0x7b, 0x25, 0xe2, 0x5e, 0xcf, 0xe5, 0x84,
+            0x39, 0x31, 0x25, 0x23, 0xa7, 0x86, 0x62, 0xd5, 0xbe, 0x7f, 0xcb, 0xcc, 0x98, 0xeb, 0xf5, 0xa8,
+            0x48, 0x07, 0xef, 0xe8, 0x36, 0xee, 0x89, 0xa5, 0x26, 0x73, 0x0d, 0xbc, 0x2f, 0x7b, 0xc8, 0x40,
+            0x9d, 0xad, 0x8b, 0xbb, 0x96, 0xc4, 0xcd, 0xc0, 0x3b, 0xc1, 0x03, 0xe1, 0xa1, 0x94, 0xbb, 0xd8
+        ])
+    ]
+}
+
+/// Put the last two blocks back in CBC order, the last one may be short.
+fn unswap(data: &[u8]) -> Vec<u8> {
+    let d = (data.len() - 1) % 16 + 1;
+    let (head, tail) = data.split_at(data.len() - d - 16);
+    let (last, stolen) = tail.split_at(16);
+    [head, stolen, last].concat()
+}
+
+
+#[test]
+fn test_cbc_cts_rfc3962() {
+    for (len, ciphertext) in rfc3962() {
+        assert_eq!(Cbc::<Aes128>::new(&KEY, &[0; 16]).unwrap().encrypt(&PLAINTEXT[..len]).unwrap(), ciphertext);
+        assert_eq!(Cbc::<Aes128, Cs3>::new(&KEY, &[0; 16]).unwrap().decrypt(ciphertext).unwrap(), &PLAINTEXT[..len]);
+    }
+}
+
+/// The addendum defines CS1 and CS2 by where they put the blocks CS3 swaps,
+/// so the RFC 3962 vectors carry over.
+#[test]
+fn test_cbc_cts_variants() {
+    for (len, ciphertext) in rfc3962() {
+        let cs1 = unswap(ciphertext);
+        let cs2 = if len % 16 == 0 { cs1.clone() } else { ciphertext.to_vec() };
+
+        assert_eq!(Cbc::<Aes128, Cs1>::new(&KEY, &[0; 16]).unwrap().encrypt(&PLAINTEXT[..len]).unwrap(), cs1);
+        assert_eq!(Cbc::<Aes128, Cs1>::new(&KEY, &[0; 16]).unwrap().decrypt(&cs1).unwrap(), &PLAINTEXT[..len]);
+        assert_eq!(Cbc::<Aes128, Cs2>::new(&KEY, &[0; 16]).unwrap().encrypt(&PLAINTEXT[..len]).unwrap(), cs2);
+        assert_eq!(Cbc::<Aes128, Cs2>::new(&KEY, &[0; 16]).unwrap().decrypt(&cs2).unwrap(), &PLAINTEXT[..len]);
+    }
+}
+
+#[test]
+fn test_cbc_cts_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(rand!(choose 16..65));
+
+    let ciphertext = Cbc::<Aes128, Cs1>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap();
+    assert_eq!(ciphertext.len(), plaintext.len());
+    assert_eq!(Cbc::<Aes128, Cs1>::new(&key, &iv).unwrap().decrypt(&ciphertext).unwrap(), plaintext);
+
+    let ciphertext = Cbc::<Aes128, Cs2>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap();
+    assert_eq!(Cbc::<Aes128, Cs2>::new(&key, &iv).unwrap().decrypt(&ciphertext).unwrap(), plaintext);
+
+    let ciphertext = Cbc::<Aes128, Cs3>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap();
+    assert_eq!(Cbc::<Aes128, Cs3>::new(&key, &iv).unwrap().decrypt(&ciphertext).unwrap(), plaintext);
+}
+
+#[test]
+fn test_cbc_cts_aligned() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(16 * rand!(choose 1..5));
+
+    let ciphertext = {
+        use aes::utils::padding::NoPadding;
+        use aes::cipher::BlockEncrypt;
+        let mut cipher = Cbc::<Aes128>::new(&key, &iv).unwrap();
+        BlockEncrypt::encrypt::<NoPadding>(&mut cipher, &plaintext).unwrap()
+    };
+
+    assert_eq!(Cbc::<Aes128, Cs1>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap(), ciphertext);
+    assert_eq!(Cbc::<Aes128, Cs2>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap(), ciphertext);
+}
+
+#[test]
+fn test_cbc_cts_short_input() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+
+    assert_eq!(
+        Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt(&rand!(15)).err(),
+        Some(DecryptFail::ShortInput)
+    );
+    assert_eq!(
+        Cbc::<Aes128, Cs1>::new(&key, &iv).unwrap().decrypt(&[]).err(),
+        Some(DecryptFail::ShortInput)
+    );
+}
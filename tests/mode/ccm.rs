@@ -0,0 +1,372 @@
+use aes::Aes128;
+use aes::mode::Ccm;
+use aes::cipher::{
+    DecryptFail,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// RFC 3610 packet vectors #1 to #12: packet number, packet length, header length,
+/// tag length and the encrypted payload followed by the tag.
+#[test]
+fn test_ccm_rfc3610() {
+    let key = [
+        0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf
+    ];
+    let vectors: [(u8, u8, usize, usize, &[u8]); 12] = [
+        (1, 31, 8, 8, &[
+            0x58, 0x8c, 0x97, 0x9a, 0x61, 0xc6, 0x63, 0xd2, 0xf0, 0x66, 0xd0, 0xc2, 0xc0, 0xf9, 0x89, 0x80,
+            0x6d, 0x5f, 0x6b, 0x61, 0xda, 0xc3, 0x84, 0x17, 0xe8, 0xd1, 0x2c, 0xfd, 0xf9, 0x26, 0xe0
+        ]),
+        (2, 32, 8, 8, &[
+            0x72, 0xc9, 0x1a, 0x36, 0xe1, 0x35, 0xf8, 0xcf, 0x29, 0x1c, 0xa8, 0x94, 0x08, 0x5c, 0x87, 0xe3,
+            0xcc, 0x15, 0xc4, 0x39, 0xc9, 0xe4, 0x3a, 0x3b, 0xa0, 0x91, 0xd5, 0x6e, 0x10, 0x40, 0x09, 0x16
+        ]),
+        (3, 33, 8, 8, &[
+            0x51, 0xb1, 0xe5, 0xf4, 0x4a, 0x19, 0x7d, 0x1d, 0xa4, 0x6b, 0x0f, 0x8e, 0x2d, 0x28, 0x2a, 0xe8,
+            0x71, 0xe8, 0x38, 0xbb, 0x64, 0xda, 0x85, 0x96, 0x57, 0x4a, 0xda, 0xa7, 0x6f, 0xbd, 0x9f, 0xb0,
+            0xc5
+        ]),
+        (4, 31, 12, 8, &[
+            0xa2, 0x8c, 0x68, 0x65, 0x93, 0x9a, 0x9a, 0x79, 0xfa, 0xaa, 0x5c, 0x4c, 0x2a, 0x9d, 0x4a, 0x91,
+            0xcd, 0xac, 0x8c, 0x96, 0xc8, 0x61, 0xb9, 0xc9, 0xe6, 0x1e, 0xf1
+        ]),
+        (5, 32, 12, 8, &[
+            0xdc, 0xf1, 0xfb, 0x7b, 0x5d, 0x9e, 0x23, 0xfb, 0x9d, 0x4e, 0x13, 0x12, 0x53, 0x65, 0x8a, 0xd8,
+            0x6e, 0xbd, 0xca, 0x3e, 0x51, 0xe8, 0x3f, 0x07, 0x7d, 0x9c, 0x2d, 0x93
+        ]),
+        (6, 33, 12, 8, &[
+            0x6f, 0xc1, 0xb0, 0x11, 0xf0, 0x06, 0x56, 0x8b, 0x51, 0x71, 0xa4, 0x2d, 0x95, 0x3d, 0x46, 0x9b,
+            0x25, 0x70, 0xa4, 0xbd, 0x87, 0x40, 0x5a, 0x04, 0x43, 0xac, 0x91, 0xcb, 0x94
+        ]),
+        (7, 31, 8, 10, &[
+            0x01, 0x35, 0xd1, 0xb2, 0xc9, 0x5f, 0x41, 0xd5, 0xd1, 0xd4, 0xfe, 0xc1, 0x85, 0xd1, 0x66, 0xb8,
+            0x09, 0x4e, 0x99, 0x9d, 0xfe, 0xd9, 0x6c, 0x04, 0x8c, 0x56, 0x60, 0x2c, 0x97, 0xac, 0xbb, 0x74,
+            0x90
+        ]),
+        (8, 32, 8, 10, &[
+            0x7b, 0x75, 0x39, 0x9a, 0xc0, 0x83, 0x1d, 0xd2, 0xf0, 0xbb, 0xd7, 0x58, 0x79, 0xa2, 0xfd, 0x8f,
+            0x6c, 0xae, 0x6b, 0x6c, 0xd9, 0xb7, 0xdb, 0x24, 0xc1, 0x7b, 0x44, 0x33, 0xf4, 0x34, 0x96, 0x3f,
+            0x34, 0xb4
+        ]),
+        (9, 33, 8, 10, &[
+            0x82, 0x53, 0x1a, 0x60, 0xcc, 0x24, 0x94, 0x5a, 0x4b, 0x82, 0x79, 0x18, 0x1a, 0xb5, 0xc8, 0x4d,
+            0xf2, 0x1c, 0xe7, 0xf9, 0xb7, 0x3f, 0x42, 0xe1, 0x97, 0xea, 0x9c, 0x07, 0xe5, 0x6b, 0x5e, 0xb1,
+            0x7e, 0x5f, 0x4e
+        ]),
+        (10, 31, 12, 10, &[
+            0x07, 0x34, 0x25, 0x94, 0x15, 0x77, 0x85, 0x15, 0x2b, 0x07, 0x40, 0x98, 0x33, 0x0a, 0xbb, 0x14,
+            0x1b, 0x94, 0x7b, 0x56, 0x6a, 0xa9, 0x40, 0x6b, 0x4d, 0x99, 0x99, 0x88, 0xdd
+        ]),
+        (11, 32, 12, 10, &[
+            0x67, 0x6b, 0xb2, 0x03, 0x80, 0xb0, 0xe3, 0x01, 0xe8, 0xab, 0x79, 0x59, 0x0a, 0x39, 0x6d, 0xa7,
+            0x8b, 0x83, 0x49, 0x34, 0xf5, 0x3a, 0xa2, 0xe9, 0x10, 0x7a, 0x8b, 0x6c, 0x02, 0x2c
+        ]),
+        (12, 33, 12, 10, &[
+            0xc0, 0xff, 0xa0, 0xd6, 0xf0, 0x5b, 0xdb, 0x67, 0xf2, 0x4d, 0x43, 0xa4, 0x33, 0x8d, 0x2a, 0xa4,
+            0xbe, 0xd7, 0xb2, 0x0e, 0x43, 0xcd, 0x1a, 0xa3, 0x16, 0x62, 0xe7, 0xad, 0x65, 0xd6, 0xdb
+        ])
+    ];
+
+    for &(n, len, header_len, tag_len, expected) in &vectors {
+        let nonce = [0, 0, 0, n + 2, n + 1, n, n - 1, 0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5];
+        let packet: Vec<u8> = (0..len).collect();
+        let (header, payload) = packet.split_at(header_len);
+
+        let (ciphertext, tag) = Ccm::<Aes128>::new(&key, &nonce, header, tag_len).unwrap().encrypt(payload).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(payload.len());
+        assert_eq!(
+            Ccm::<Aes128>::new(&key, &nonce, header, tag_len).unwrap().decrypt(ciphertext, tag).unwrap(),
+            payload
+        );
+    }
+}
+
+/// RFC 3610 packet vectors #13 to #24, random nonces and packets: nonce, header length,
+/// tag length, the packet and the header followed by the encrypted payload and the tag.
+#[test]
+fn test_ccm_rfc3610_random() {
+    let key = [
+        0xd7, 0x82, 0x8d, 0x13, 0xb2, 0xb0, 0xbd, 0xc3, 0x25, 0xa7, 0x62, 0x36, 0xdf, 0x93, 0xcc, 0x6b
+    ];
+    let vectors: [(&[u8], usize, usize, &[u8], &[u8]); 12] = [
+        (
+            &[
+                0x00, 0x41, 0x2b, 0x4e, 0xa9, 0xcd, 0xbe, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 8,
+            &[
+                0x0b, 0xe1, 0xa8, 0x8b, 0xac, 0xe0, 0x18, 0xb1, 0x08, 0xe8, 0xcf, 0x97, 0xd8, 0x20, 0xea, 0x25,
+                0x84, 0x60, 0xe9, 0x6a, 0xd9, 0xcf, 0x52, 0x89, 0x05, 0x4d, 0x89, 0x5c, 0xea, 0xc4, 0x7c
+            ],
+            &[
+                0x0b, 0xe1, 0xa8, 0x8b, 0xac, 0xe0, 0x18, 0xb1, 0x4c, 0xb9, 0x7f, 0x86, 0xa2, 0xa4, 0x68, 0x9a,
+                0x87, 0x79, 0x47, 0xab, 0x80, 0x91, 0xef, 0x53, 0x86, 0xa6, 0xff, 0xbd, 0xd0, 0x80, 0xf8, 0xe7,
+                0x8c, 0xf7, 0xcb, 0x0c, 0xdd, 0xd7, 0xb3
+            ]
+        ),
+        (
+            &[
+                0x00, 0x33, 0x56, 0x8e, 0xf7, 0xb2, 0x63, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 8,
+            &[
+                0x63, 0x01, 0x8f, 0x76, 0xdc, 0x8a, 0x1b, 0xcb, 0x90, 0x20, 0xea, 0x6f, 0x91, 0xbd, 0xd8, 0x5a,
+                0xfa, 0x00, 0x39, 0xba, 0x4b, 0xaf, 0xf9, 0xbf, 0xb7, 0x9c, 0x70, 0x28, 0x94, 0x9c, 0xd0, 0xec
+            ],
+            &[
+                0x63, 0x01, 0x8f, 0x76, 0xdc, 0x8a, 0x1b, 0xcb, 0x4c, 0xcb, 0x1e, 0x7c, 0xa9, 0x81, 0xbe, 0xfa,
+                0xa0, 0x72, 0x6c, 0x55, 0xd3, 0x78, 0x06, 0x12, 0x98, 0xc8, 0x5c, 0x92, 0x81, 0x4a, 0xbc, 0x33,
+                0xc5, 0x2e, 0xe8, 0x1d, 0x7d, 0x77, 0xc0, 0x8a
+            ]
+        ),
+        (
+            &[
+                0x00, 0x10, 0x3f, 0xe4, 0x13, 0x36, 0x71, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 8,
+            &[
+                0xaa, 0x6c, 0xfa, 0x36, 0xca, 0xe8, 0x6b, 0x40, 0xb9, 0x16, 0xe0, 0xea, 0xcc, 0x1c, 0x00, 0xd7,
+                0xdc, 0xec, 0x68, 0xec, 0x0b, 0x3b, 0xbb, 0x1a, 0x02, 0xde, 0x8a, 0x2d, 0x1a, 0xa3, 0x46, 0x13,
+                0x2e
+            ],
+            &[
+                0xaa, 0x6c, 0xfa, 0x36, 0xca, 0xe8, 0x6b, 0x40, 0xb1, 0xd2, 0x3a, 0x22, 0x20, 0xdd, 0xc0, 0xac,
+                0x90, 0x0d, 0x9a, 0xa0, 0x3c, 0x61, 0xfc, 0xf4, 0xa5, 0x59, 0xa4, 0x41, 0x77, 0x67, 0x08, 0x97,
+                0x08, 0xa7, 0x76, 0x79, 0x6e, 0xdb, 0x72, 0x35, 0x06
+            ]
+        ),
+        (
+            &[
+                0x00, 0x76, 0x4c, 0x63, 0xb8, 0x05, 0x8e, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 8,
+            &[
+                0xd0, 0xd0, 0x73, 0x5c, 0x53, 0x1e, 0x1b, 0xec, 0xf0, 0x49, 0xc2, 0x44, 0x12, 0xda, 0xac, 0x56,
+                0x30, 0xef, 0xa5, 0x39, 0x6f, 0x77, 0x0c, 0xe1, 0xa6, 0x6b, 0x21, 0xf7, 0xb2, 0x10, 0x1c
+            ],
+            &[
+                0xd0, 0xd0, 0x73, 0x5c, 0x53, 0x1e, 0x1b, 0xec, 0xf0, 0x49, 0xc2, 0x44, 0x14, 0xd2, 0x53, 0xc3,
+                0x96, 0x7b, 0x70, 0x60, 0x9b, 0x7c, 0xbb, 0x7c, 0x49, 0x91, 0x60, 0x28, 0x32, 0x45, 0x26, 0x9a,
+                0x6f, 0x49, 0x97, 0x5b, 0xca, 0xde, 0xaf
+            ]
+        ),
+        (
+            &[
+                0x00, 0xf8, 0xb6, 0x78, 0x09, 0x4e, 0x3b, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 8,
+            &[
+                0x77, 0xb6, 0x0f, 0x01, 0x1c, 0x03, 0xe1, 0x52, 0x58, 0x99, 0xbc, 0xae, 0xe8, 0x8b, 0x6a, 0x46,
+                0xc7, 0x8d, 0x63, 0xe5, 0x2e, 0xb8, 0xc5, 0x46, 0xef, 0xb5, 0xde, 0x6f, 0x75, 0xe9, 0xcc, 0x0d
+            ],
+            &[
+                0x77, 0xb6, 0x0f, 0x01, 0x1c, 0x03, 0xe1, 0x52, 0x58, 0x99, 0xbc, 0xae, 0x55, 0x45, 0xff, 0x1a,
+                0x08, 0x5e, 0xe2, 0xef, 0xbf, 0x52, 0xb2, 0xe0, 0x4b, 0xee, 0x1e, 0x23, 0x36, 0xc7, 0x3e, 0x3f,
+                0x76, 0x2c, 0x0c, 0x77, 0x44, 0xfe, 0x7e, 0x3c
+            ]
+        ),
+        (
+            &[
+                0x00, 0xd5, 0x60, 0x91, 0x2d, 0x3f, 0x70, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 8,
+            &[
+                0xcd, 0x90, 0x44, 0xd2, 0xb7, 0x1f, 0xdb, 0x81, 0x20, 0xea, 0x60, 0xc0, 0x64, 0x35, 0xac, 0xba,
+                0xfb, 0x11, 0xa8, 0x2e, 0x2f, 0x07, 0x1d, 0x7c, 0xa4, 0xa5, 0xeb, 0xd9, 0x3a, 0x80, 0x3b, 0xa8,
+                0x7f
+            ],
+            &[
+                0xcd, 0x90, 0x44, 0xd2, 0xb7, 0x1f, 0xdb, 0x81, 0x20, 0xea, 0x60, 0xc0, 0x00, 0x97, 0x69, 0xec,
+                0xab, 0xdf, 0x48, 0x62, 0x55, 0x94, 0xc5, 0x92, 0x51, 0xe6, 0x03, 0x57, 0x22, 0x67, 0x5e, 0x04,
+                0xc8, 0x47, 0x09, 0x9e, 0x5a, 0xe0, 0x70, 0x45, 0x51
+            ]
+        ),
+        (
+            &[
+                0x00, 0x42, 0xff, 0xf8, 0xf1, 0x95, 0x1c, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 10,
+            &[
+                0xd8, 0x5b, 0xc7, 0xe6, 0x9f, 0x94, 0x4f, 0xb8, 0x8a, 0x19, 0xb9, 0x50, 0xbc, 0xf7, 0x1a, 0x01,
+                0x8e, 0x5e, 0x67, 0x01, 0xc9, 0x17, 0x87, 0x65, 0x98, 0x09, 0xd6, 0x7d, 0xbe, 0xdd, 0x18
+            ],
+            &[
+                0xd8, 0x5b, 0xc7, 0xe6, 0x9f, 0x94, 0x4f, 0xb8, 0xbc, 0x21, 0x8d, 0xaa, 0x94, 0x74, 0x27, 0xb6,
+                0xdb, 0x38, 0x6a, 0x99, 0xac, 0x1a, 0xef, 0x23, 0xad, 0xe0, 0xb5, 0x29, 0x39, 0xcb, 0x6a, 0x63,
+                0x7c, 0xf9, 0xbe, 0xc2, 0x40, 0x88, 0x97, 0xc6, 0xba
+            ]
+        ),
+        (
+            &[
+                0x00, 0x92, 0x0f, 0x40, 0xe5, 0x6c, 0xdc, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 10,
+            &[
+                0x74, 0xa0, 0xeb, 0xc9, 0x06, 0x9f, 0x5b, 0x37, 0x17, 0x61, 0x43, 0x3c, 0x37, 0xc5, 0xa3, 0x5f,
+                0xc1, 0xf3, 0x9f, 0x40, 0x63, 0x02, 0xeb, 0x90, 0x7c, 0x61, 0x63, 0xbe, 0x38, 0xc9, 0x84, 0x37
+            ],
+            &[
+                0x74, 0xa0, 0xeb, 0xc9, 0x06, 0x9f, 0x5b, 0x37, 0x58, 0x10, 0xe6, 0xfd, 0x25, 0x87, 0x40, 0x22,
+                0xe8, 0x03, 0x61, 0xa4, 0x78, 0xe3, 0xe9, 0xcf, 0x48, 0x4a, 0xb0, 0x4f, 0x44, 0x7e, 0xff, 0xf6,
+                0xf0, 0xa4, 0x77, 0xcc, 0x2f, 0xc9, 0xbf, 0x54, 0x89, 0x44
+            ]
+        ),
+        (
+            &[
+                0x00, 0x27, 0xca, 0x0c, 0x71, 0x20, 0xbc, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            8, 10,
+            &[
+                0x44, 0xa3, 0xaa, 0x3a, 0xae, 0x64, 0x75, 0xca, 0xa4, 0x34, 0xa8, 0xe5, 0x85, 0x00, 0xc6, 0xe4,
+                0x15, 0x30, 0x53, 0x88, 0x62, 0xd6, 0x86, 0xea, 0x9e, 0x81, 0x30, 0x1b, 0x5a, 0xe4, 0x22, 0x6b,
+                0xfa
+            ],
+            &[
+                0x44, 0xa3, 0xaa, 0x3a, 0xae, 0x64, 0x75, 0xca, 0xf2, 0xbe, 0xed, 0x7b, 0xc5, 0x09, 0x8e, 0x83,
+                0xfe, 0xb5, 0xb3, 0x16, 0x08, 0xf8, 0xe2, 0x9c, 0x38, 0x81, 0x9a, 0x89, 0xc8, 0xe7, 0x76, 0xf1,
+                0x54, 0x4d, 0x41, 0x51, 0xa4, 0xed, 0x3a, 0x8b, 0x87, 0xb9, 0xce
+            ]
+        ),
+        (
+            &[
+                0x00, 0x5b, 0x8c, 0xcb, 0xcd, 0x9a, 0xf8, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 10,
+            &[
+                0xec, 0x46, 0xbb, 0x63, 0xb0, 0x25, 0x20, 0xc3, 0x3c, 0x49, 0xfd, 0x70, 0xb9, 0x6b, 0x49, 0xe2,
+                0x1d, 0x62, 0x17, 0x41, 0x63, 0x28, 0x75, 0xdb, 0x7f, 0x6c, 0x92, 0x43, 0xd2, 0xd7, 0xc2
+            ],
+            &[
+                0xec, 0x46, 0xbb, 0x63, 0xb0, 0x25, 0x20, 0xc3, 0x3c, 0x49, 0xfd, 0x70, 0x31, 0xd7, 0x50, 0xa0,
+                0x9d, 0xa3, 0xed, 0x7f, 0xdd, 0xd4, 0x9a, 0x20, 0x32, 0xaa, 0xbf, 0x17, 0xec, 0x8e, 0xbf, 0x7d,
+                0x22, 0xc8, 0x08, 0x8c, 0x66, 0x6b, 0xe5, 0xc1, 0x97
+            ]
+        ),
+        (
+            &[
+                0x00, 0x3e, 0xbe, 0x94, 0x04, 0x4b, 0x9a, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 10,
+            &[
+                0x47, 0xa6, 0x5a, 0xc7, 0x8b, 0x3d, 0x59, 0x42, 0x27, 0xe8, 0x5e, 0x71, 0xe2, 0xfc, 0xfb, 0xb8,
+                0x80, 0x44, 0x2c, 0x73, 0x1b, 0xf9, 0x51, 0x67, 0xc8, 0xff, 0xd7, 0x89, 0x5e, 0x33, 0x70, 0x76
+            ],
+            &[
+                0x47, 0xa6, 0x5a, 0xc7, 0x8b, 0x3d, 0x59, 0x42, 0x27, 0xe8, 0x5e, 0x71, 0xe8, 0x82, 0xf1, 0xdb,
+                0xd3, 0x8c, 0xe3, 0xed, 0xa7, 0xc2, 0x3f, 0x04, 0xdd, 0x65, 0x07, 0x1e, 0xb4, 0x13, 0x42, 0xac,
+                0xdf, 0x7e, 0x00, 0xdc, 0xce, 0xc7, 0xae, 0x52, 0x98, 0x7d
+            ]
+        ),
+        (
+            &[
+                0x00, 0x8d, 0x49, 0x3b, 0x30, 0xae, 0x8b, 0x3c, 0x96, 0x96, 0x76, 0x6c, 0xfa
+            ],
+            12, 10,
+            &[
+                0x6e, 0x37, 0xa6, 0xef, 0x54, 0x6d, 0x95, 0x5d, 0x34, 0xab, 0x60, 0x59, 0xab, 0xf2, 0x1c, 0x0b,
+                0x02, 0xfe, 0xb8, 0x8f, 0x85, 0x6d, 0xf4, 0xa3, 0x73, 0x81, 0xbc, 0xe3, 0xcc, 0x12, 0x85, 0x17,
+                0xd4
+            ],
+            &[
+                0x6e, 0x37, 0xa6, 0xef, 0x54, 0x6d, 0x95, 0x5d, 0x34, 0xab, 0x60, 0x59, 0xf3, 0x29, 0x05, 0xb8,
+                0x8a, 0x64, 0x1b, 0x04, 0xb9, 0xc9, 0xff, 0xb5, 0x8c, 0xc3, 0x90, 0x90, 0x0f, 0x3d, 0xa1, 0x2a,
+                0xb1, 0x6d, 0xce, 0x9e, 0x82, 0xef, 0xa1, 0x6d, 0xa6, 0x20, 0x59
+            ]
+        )
+    ];
+
+    for &(nonce, header_len, tag_len, packet, expected) in &vectors {
+        let (header, payload) = packet.split_at(header_len);
+        let (ciphertext, tag) = Ccm::<Aes128>::new(&key, nonce, header, tag_len).unwrap().encrypt(payload).unwrap();
+        assert_eq!([header, &ciphertext, &tag].concat(), expected);
+
+        let (ciphertext, tag) = expected[header_len..].split_at(payload.len());
+        assert_eq!(
+            Ccm::<Aes128>::new(&key, nonce, header, tag_len).unwrap().decrypt(ciphertext, tag).unwrap(),
+            payload
+        );
+    }
+}
+
+/// NIST SP 800-38C Appendix C: nonce, associated data and payload lengths,
+/// tag length and the ciphertext. The data is counting bytes from 0x10, 0x00 and 0x20.
+#[test]
+fn test_ccm_sp800_38c() {
+    let key = [
+        0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f
+    ];
+    let vectors: [(u8, usize, u8, usize, &[u8]); 4] = [
+        (7, 8, 4, 4, &[
+            0x71, 0x62, 0x01, 0x5b, 0x4d, 0xac, 0x25, 0x5d
+        ]),
+        (8, 16, 16, 6, &[
+            0xd2, 0xa1, 0xf0, 0xe0, 0x51, 0xea, 0x5f, 0x62, 0x08, 0x1a, 0x77, 0x92, 0x07, 0x3d, 0x59, 0x3d,
+            0x1f, 0xc6, 0x4f, 0xbf, 0xac, 0xcd
+        ]),
+        (12, 20, 24, 8, &[
+            0xe3, 0xb2, 0x01, 0xa9, 0xf5, 0xb7, 0x1a, 0x7a, 0x9b, 0x1c, 0xea, 0xec, 0xcd, 0x97, 0xe7, 0x0b,
+            0x61, 0x76, 0xaa, 0xd9, 0xa4, 0x42, 0x8a, 0xa5, 0x48, 0x43, 0x92, 0xfb, 0xc1, 0xb0, 0x99, 0x51
+        ]),
+        (13, 65536, 32, 14, &[
+            0x69, 0x91, 0x5d, 0xad, 0x1e, 0x84, 0xc6, 0x37, 0x6a, 0x68, 0xc2, 0x96, 0x7e, 0x4d, 0xab, 0x61,
+            0x5a, 0xe0, 0xfd, 0x1f, 0xae, 0xc4, 0x4c, 0xc4, 0x84, 0x82, 0x85, 0x29, 0x46, 0x3c, 0xcf, 0x72,
+            0xb4, 0xac, 0x6b, 0xec, 0x93, 0xe8, 0x59, 0x8e, 0x7f, 0x0d, 0xad, 0xbc, 0xea, 0x5b
+        ])
+    ];
+
+    for &(nonce_len, aad_len, len, tag_len, expected) in &vectors {
+        let nonce: Vec<u8> = (0x10..0x10 + nonce_len).collect();
+        let aad: Vec<u8> = (0..aad_len).map(|i| i as u8).collect();
+        let plaintext: Vec<u8> = (0x20..0x20 + len).collect();
+
+        let (ciphertext, tag) = Ccm::<Aes128>::new(&key, &nonce, &aad, tag_len).unwrap().encrypt(&plaintext).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(plaintext.len());
+        assert_eq!(
+            Ccm::<Aes128>::new(&key, &nonce, &aad, tag_len).unwrap().decrypt(ciphertext, tag).unwrap(),
+            plaintext
+        );
+    }
+}
+
+#[test]
+fn test_ccm_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let nonce = rand!(rand!(choose 7..14));
+    let aad = rand!(rand!(choose 0..40));
+    let plaintext = rand!(rand!(choose 0..65));
+
+    let mut cipher = Ccm::<Aes128>::new(&key, &nonce, &aad, 12).unwrap();
+    let (ciphertext, tag) = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(tag.len(), 12);
+    assert_eq!(cipher.decrypt(&ciphertext, &tag), Ok(plaintext.clone()));
+    assert_eq!(cipher.encrypt(&plaintext).err(), Some(DecryptFail::NonceReuse));
+
+    let mut bad_tag = tag.clone();
+    bad_tag[0] ^= 1;
+    assert_eq!(cipher.decrypt(&ciphertext, &bad_tag), Err(DecryptFail::Auth));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag[..8]), Err(DecryptFail::Auth));
+    assert_eq!(
+        Ccm::<Aes128>::new(&key, &nonce, &[&aad[..], &[0]].concat(), 12).unwrap().decrypt(&ciphertext, &tag),
+        Err(DecryptFail::Auth)
+    );
+}
+
+#[test]
+fn test_ccm_bad_length() {
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Ccm::<Aes128>::new(&key, &rand!(6), &[], 8).err(), Some(DecryptFail::InvalidNonceLength));
+    assert_eq!(Ccm::<Aes128>::new(&key, &rand!(14), &[], 8).err(), Some(DecryptFail::InvalidNonceLength));
+    assert_eq!(Ccm::<Aes128>::new(&key, &rand!(12), &[], 2).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(Ccm::<Aes128>::new(&key, &rand!(12), &[], 7).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(Ccm::<Aes128>::new(&key, &rand!(12), &[], 18).err(), Some(DecryptFail::InvalidTagLength));
+
+    let mut cipher = Ccm::<Aes128>::new(&key, &rand!(13), &[], 16).unwrap();
+    assert_eq!(cipher.encrypt(&vec![0; 1 << 16]).err(), Some(DecryptFail::LongInput));
+    assert!(cipher.encrypt(&vec![0; (1 << 16) - 1]).is_ok());
+}
@@ -0,0 +1,128 @@
+use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
+use aes::mode::{ Cfb1, Cfb8, Cfb128 };
+use aes::cipher::{ StreamEncrypt, StreamDecrypt };
+
+
+const KEY: [u8; 16] = [
+    0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f
+];
+const PLAINTEXT: [u8; 32] = [
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51
+];
+
+/// NIST SP 800-38A F.3.1, F.3.7 and F.3.13.
+#[test]
+fn test_cfb_test() {
+    assert_eq!(
+        Cfb1::<Aes128>::new(&KEY, &IV).unwrap().encrypt(&PLAINTEXT[..2]).unwrap(),
+        [0x68, 0xb3]
+    );
+
+    assert_eq!(
+        Cfb8::<Aes128>::new(&KEY, &IV).unwrap().encrypt(&PLAINTEXT[..18]).unwrap(),
+        [
+            0x3b, 0x79, 0x42, 0x4c, 0x9c, 0x0d, 0xd4, 0x36, 0xba,
+            0xce, 0x9e, 0x0e, 0xd4, 0x58, 0x6a, 0x4f, 0x32, 0xb9
+        ]
+    );
+
+    assert_eq!(
+        Cfb128::<Aes128>::new(&KEY, &IV).unwrap().encrypt(&PLAINTEXT).unwrap(),
+        [
+            0x3b, 0x3f, 0xd9, 0x2e, 0xb7, 0x2d, 0xad, 0x20, 0x33, 0x34, 0x49, 0xf8, 0xe8, 0x3c, 0xfb, 0x4a,
+            0xc8, 0xa6, 0x45, 0x37, 0xa0, 0xb3, 0xa9, 0x3f, 0xcd, 0xe3, 0xcd, 0xad, 0x9f, 0x1c, 0xe5, 0x8b
+        ]
+    );
+}
+
+#[test]
+fn test_cfb_encrypt() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(rand!(choose 15..65));
+
+    assert_eq!(
+        Cfb1::<Aes128>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap(),
+        encrypt(Type::AES_128_CFB1, &key, &iv, &plaintext)
+    );
+    assert_eq!(
+        Cfb8::<Aes128>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap(),
+        encrypt(Type::AES_128_CFB8, &key, &iv, &plaintext)
+    );
+    assert_eq!(
+        Cfb128::<Aes128>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap(),
+        encrypt(Type::AES_128_CFB128, &key, &iv, &plaintext)
+    );
+}
+
+#[test]
+fn test_cfb_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(rand!(choose 15..65));
+
+    assert_eq!(
+        Cfb1::<Aes128>::new(&key, &iv).unwrap().decrypt(
+            &encrypt(Type::AES_128_CFB1, &key, &iv, &plaintext)
+        ),
+        Ok(plaintext.clone())
+    );
+    assert_eq!(
+        Cfb8::<Aes128>::new(&key, &iv).unwrap().decrypt(
+            &encrypt(Type::AES_128_CFB8, &key, &iv, &plaintext)
+        ),
+        Ok(plaintext.clone())
+    );
+    assert_eq!(
+        Cfb128::<Aes128>::new(&key, &iv).unwrap().decrypt(
+            &encrypt(Type::AES_128_CFB128, &key, &iv, &plaintext)
+        ),
+        Ok(plaintext)
+    );
+}
+
+#[test]
+fn test_cfb_chunks() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(rand!(choose 33..65));
+    let (head, rest) = plaintext.split_at(rand!(choose 1..16));
+    let (middle, tail) = rest.split_at(rand!(choose 1..16));
+
+    let ciphertext = Cfb128::<Aes128>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap();
+    let mut cipher = Cfb128::<Aes128>::new(&key, &iv).unwrap();
+    let chunks = [
+        cipher.encrypt(head).unwrap(),
+        cipher.encrypt(middle).unwrap(),
+        cipher.encrypt(tail).unwrap()
+    ].concat();
+    assert_eq!(chunks, ciphertext);
+
+    let mut cipher = Cfb128::<Aes128>::new(&key, &iv).unwrap();
+    let (head, tail) = ciphertext.split_at(rand!(choose 1..32));
+    let chunks = [cipher.decrypt(head).unwrap(), cipher.decrypt(tail).unwrap()].concat();
+    assert_eq!(chunks, plaintext);
+
+    let mut cipher = Cfb8::<Aes128>::new(&key, &iv).unwrap();
+    let (head, tail) = plaintext.split_at(rand!(choose 1..32));
+    let chunks = [cipher.encrypt(head).unwrap(), cipher.encrypt(tail).unwrap()].concat();
+    assert_eq!(chunks, Cfb8::<Aes128>::new(&key, &iv).unwrap().encrypt(&plaintext).unwrap());
+}
+
+#[test]
+fn test_cfb_bad_iv() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Cfb8::<Aes128>::new(&key, &rand!(8)).err(), Some(DecryptFail::InvalidIvLength));
+    assert_eq!(
+        Cfb128::<Aes128>::new(&key, &rand!(16)).unwrap().set_iv(&rand!(17)).err(),
+        Some(DecryptFail::InvalidIvLength)
+    );
+}
@@ -1,6 +1,10 @@
 use openssl::crypto::symm::{ Crypter, Type, Mode };
+use aes::Aes128;
 use aes::mode::Ctr;
-use aes::cipher::{ StreamEncrypt, StreamDecrypt };
+use aes::cipher::{
+    StreamEncrypt, StreamDecrypt,
+    InPlaceStreamEncrypt, InPlaceStreamDecrypt
+};
 
 
 #[test]
@@ -10,7 +14,7 @@ fn test_ctr_test() {
     let plaintext = [2; 33];
 
     assert_eq!(
-        Ctr::new(&key, &ctr).encrypt(&plaintext),
+        Ctr::<Aes128>::new(&key, &ctr).unwrap().encrypt(&plaintext).unwrap(),
         vec![
             0x64, 0x3e, 0x05, 0x19, 0x79, 0x78, 0xd7, 0x45, 0xa9, 0x10, 0x5f,
             0xd8, 0x4c, 0xd7, 0xe6, 0xb1, 0x5f, 0x66, 0xc6, 0x17, 0x4b, 0x25,
@@ -21,25 +25,25 @@ fn test_ctr_test() {
 
 #[test]
 fn test_ctr_encrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let ctr = rand!(16);
     let plaintext = rand!(rand!(choose 15..65));
 
-    let mut cipher = Ctr::new(&key, &ctr);
+    let mut cipher = Ctr::<Aes128>::new(&key, &ctr).unwrap();
     let os_cipher = Crypter::new(Type::AES_128_CTR);
     os_cipher.init(Mode::Encrypt, &key, &ctr);
 
     let ciphertext1 = os_cipher.update(&plaintext);
 
     assert_eq!(
-        cipher.encrypt(&plaintext),
+        cipher.encrypt(&plaintext).unwrap(),
         ciphertext1
     );
 
     let ciphertext2 = os_cipher.update(&plaintext);
 
     assert_eq!(
-        cipher.encrypt(&plaintext),
+        cipher.encrypt(&plaintext).unwrap(),
         ciphertext2
     );
 
@@ -48,14 +52,71 @@ fn test_ctr_encrypt() {
 
 #[test]
 fn test_ctr_decrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let ctr = rand!(16);
     let plaintext = rand!(rand!(choose 15..65));
 
     assert_eq!(
-        Ctr::new(&key, &ctr).decrypt(
-            &Ctr::new(&key, &ctr).encrypt(&plaintext)
+        Ctr::<Aes128>::new(&key, &ctr).unwrap().decrypt(
+            &Ctr::<Aes128>::new(&key, &ctr).unwrap().encrypt(&plaintext).unwrap()
         ),
-        plaintext
+        Ok(plaintext)
+    );
+}
+
+#[test]
+fn test_ctr_in_place() {
+    let key: [u8; 16] = rand!(_);
+    let ctr = rand!(16);
+    let plaintext = rand!(rand!(choose 15..65));
+
+    let mut cipher = Ctr::<Aes128>::new(&key, &ctr).unwrap();
+    let mut data = plaintext.clone();
+    let (head, tail) = data.split_at_mut(rand!(choose 0..15));
+    cipher.encrypt_in_place(head);
+    cipher.encrypt_in_place(tail);
+    assert_eq!(data, Ctr::<Aes128>::new(&key, &ctr).unwrap().encrypt(&plaintext).unwrap());
+
+    Ctr::<Aes128>::new(&key, &ctr).unwrap().decrypt_in_place(&mut data);
+    assert_eq!(data, plaintext);
+}
+
+/// A 192-bit block takes the per-block path, the leftover keystream carries over the same way.
+#[test]
+fn test_ctr_wide_block() {
+    use aes::rijndael::{ Rijndael, N4, N6 };
+    use aes::cipher::SingleBlockEncrypt;
+
+    let key: [u8; 16] = rand!(_);
+    let mut ctr = vec![0xffu8; 24];
+    ctr[0] = rand!(_);
+    let plaintext = rand!(rand!(choose 48..100));
+
+    let cipher = Rijndael::<N6, N4>::new(&key);
+    let mut next = vec![0; 24];
+    next[0] = ctr[0].wrapping_add(1);
+    let keystream = [cipher.encrypt(&ctr).unwrap(), cipher.encrypt(&next).unwrap()].concat();
+
+    let mut cipher = Ctr::<Rijndael<N6, N4>>::new(&key, &ctr).unwrap();
+    let mut data = plaintext.clone();
+    let (head, tail) = data.split_at_mut(rand!(choose 0..24));
+    cipher.encrypt_in_place(head);
+    cipher.encrypt_in_place(tail);
+    assert_eq!(data, Ctr::<Rijndael<N6, N4>>::new(&key, &ctr).unwrap().encrypt(&plaintext).unwrap());
+    for (i, b) in plaintext[..48].iter().enumerate() {
+        assert_eq!(data[i], b ^ keystream[i]);
+    }
+}
+
+#[test]
+fn test_ctr_bad_counter() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Ctr::<Aes128>::new(&key, &rand!(12)).err(), Some(DecryptFail::InvalidIvLength));
+    assert_eq!(
+        Ctr::<Aes128>::new(&key, &rand!(16)).unwrap().set_ctr(&[]).err(),
+        Some(DecryptFail::InvalidIvLength)
     );
 }
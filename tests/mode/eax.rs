@@ -0,0 +1,231 @@
+use aes::Aes128;
+use aes::mode::Eax;
+use aes::cipher::{
+    DecryptFail,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// The EAX paper's Appendix vectors: message, key, nonce, header, ciphertext followed by the tag.
+#[test]
+fn test_eax_test() {
+    let vectors: [(&[u8], &[u8], &[u8], &[u8], &[u8]); 10] = [
+        (
+            &[],
+            &[
+                0x23, 0x39, 0x52, 0xde, 0xe4, 0xd5, 0xed, 0x5f, 0x9b, 0x9c, 0x6d, 0x6f, 0xf8, 0x0f, 0xf4, 0x78
+            ],
+            &[
+                0x62, 0xec, 0x67, 0xf9, 0xc3, 0xa4, 0xa4, 0x07, 0xfc, 0xb2, 0xa8, 0xc4, 0x90, 0x31, 0xa8, 0xb3
+            ],
+            &[
+                0x6b, 0xfb, 0x91, 0x4f, 0xd0, 0x7e, 0xae, 0x6b
+            ],
+            &[
+                0xe0, 0x37, 0x83, 0x0e, 0x83, 0x89, 0xf2, 0x7b, 0x02, 0x5a, 0x2d, 0x65, 0x27, 0xe7, 0x9d, 0x01
+            ]
+        ),
+        (
+            &[
+                0xf7, 0xfb
+            ],
+            &[
+                0x91, 0x94, 0x5d, 0x3f, 0x4d, 0xcb, 0xee, 0x0b, 0xf4, 0x5e, 0xf5, 0x22, 0x55, 0xf0, 0x95, 0xa4
+            ],
+            &[
+                0xbe, 0xca, 0xf0, 0x43, 0xb0, 0xa2, 0x3d, 0x84, 0x31, 0x94, 0xba, 0x97, 0x2c, 0x66, 0xde, 0xbd
+            ],
+            &[
+                0xfa, 0x3b, 0xfd, 0x48, 0x06, 0xeb, 0x53, 0xfa
+            ],
+            &[
+                0x19, 0xdd, 0x5c, 0x4c, 0x93, 0x31, 0x04, 0x9d, 0x0b, 0xda, 0xb0, 0x27, 0x74, 0x08, 0xf6, 0x79,
+                0x67, 0xe5
+            ]
+        ),
+        (
+            &[
+                0x1a, 0x47, 0xcb, 0x49, 0x33
+            ],
+            &[
+                0x01, 0xf7, 0x4a, 0xd6, 0x40, 0x77, 0xf2, 0xe7, 0x04, 0xc0, 0xf6, 0x0a, 0xda, 0x3d, 0xd5, 0x23
+            ],
+            &[
+                0x70, 0xc3, 0xdb, 0x4f, 0x0d, 0x26, 0x36, 0x84, 0x00, 0xa1, 0x0e, 0xd0, 0x5d, 0x2b, 0xff, 0x5e
+            ],
+            &[
+                0x23, 0x4a, 0x34, 0x63, 0xc1, 0x26, 0x4a, 0xc6
+            ],
+            &[
+                0xd8, 0x51, 0xd5, 0xba, 0xe0, 0x3a, 0x59, 0xf2, 0x38, 0xa2, 0x3e, 0x39, 0x19, 0x9d, 0xc9, 0x26,
+                0x66, 0x26, 0xc4, 0x0f, 0x80
+            ]
+        ),
+        (
+            &[
+                0x48, 0x1c, 0x9e, 0x39, 0xb1
+            ],
+            &[
+                0xd0, 0x7c, 0xf6, 0xcb, 0xb7, 0xf3, 0x13, 0xbd, 0xde, 0x66, 0xb7, 0x27, 0xaf, 0xd3, 0xc5, 0xe8
+            ],
+            &[
+                0x84, 0x08, 0xdf, 0xff, 0x3c, 0x1a, 0x2b, 0x12, 0x92, 0xdc, 0x19, 0x9e, 0x46, 0xb7, 0xd6, 0x17
+            ],
+            &[
+                0x33, 0xcc, 0xe2, 0xea, 0xbf, 0xf5, 0xa7, 0x9d
+            ],
+            &[
+                0x63, 0x2a, 0x9d, 0x13, 0x1a, 0xd4, 0xc1, 0x68, 0xa4, 0x22, 0x5d, 0x8e, 0x1f, 0xf7, 0x55, 0x93,
+                0x99, 0x74, 0xa7, 0xbe, 0xde
+            ]
+        ),
+        (
+            &[
+                0x40, 0xd0, 0xc0, 0x7d, 0xa5, 0xe4
+            ],
+            &[
+                0x35, 0xb6, 0xd0, 0x58, 0x00, 0x05, 0xbb, 0xc1, 0x2b, 0x05, 0x87, 0x12, 0x45, 0x57, 0xd2, 0xc2
+            ],
+            &[
+                0xfd, 0xb6, 0xb0, 0x66, 0x76, 0xee, 0xdc, 0x5c, 0x61, 0xd7, 0x42, 0x76, 0xe1, 0xf8, 0xe8, 0x16
+            ],
+            &[
+                0xae, 0xb9, 0x6e, 0xae, 0xbe, 0x29, 0x70, 0xe9
+            ],
+            &[
+                0x07, 0x1d, 0xfe, 0x16, 0xc6, 0x75, 0xcb, 0x06, 0x77, 0xe5, 0x36, 0xf7, 0x3a, 0xfe, 0x6a, 0x14,
+                0xb7, 0x4e, 0xe4, 0x98, 0x44, 0xdd
+            ]
+        ),
+        (
+            &[
+                0x4d, 0xe3, 0xb3, 0x5c, 0x3f, 0xc0, 0x39, 0x24, 0x5b, 0xd1, 0xfb, 0x7d
+            ],
+            &[
+                0xbd, 0x8e, 0x6e, 0x11, 0x47, 0x5e, 0x60, 0xb2, 0x68, 0x78, 0x4c, 0x38, 0xc6, 0x2f, 0xeb, 0x22
+            ],
+            &[
+                0x6e, 0xac, 0x5c, 0x93, 0x07, 0x2d, 0x8e, 0x85, 0x13, 0xf7, 0x50, 0x93, 0x5e, 0x46, 0xda, 0x1b
+            ],
+            &[
+                0xd4, 0x48, 0x2d, 0x1c, 0xa7, 0x8d, 0xce, 0x0f
+            ],
+            &[
+                0x83, 0x5b, 0xb4, 0xf1, 0x5d, 0x74, 0x3e, 0x35, 0x0e, 0x72, 0x84, 0x14, 0xab, 0xb8, 0x64, 0x4f,
+                0xd6, 0xcc, 0xb8, 0x69, 0x47, 0xc5, 0xe1, 0x05, 0x90, 0x21, 0x0a, 0x4f
+            ]
+        ),
+        (
+            &[
+                0x8b, 0x0a, 0x79, 0x30, 0x6c, 0x9c, 0xe7, 0xed, 0x99, 0xda, 0xe4, 0xf8, 0x7f, 0x8d, 0xd6, 0x16,
+                0x36
+            ],
+            &[
+                0x7c, 0x77, 0xd6, 0xe8, 0x13, 0xbe, 0xd5, 0xac, 0x98, 0xba, 0xa4, 0x17, 0x47, 0x7a, 0x2e, 0x7d
+            ],
+            &[
+                0x1a, 0x8c, 0x98, 0xdc, 0xd7, 0x3d, 0x38, 0x39, 0x3b, 0x2b, 0xf1, 0x56, 0x9d, 0xee, 0xfc, 0x19
+            ],
+            &[
+                0x65, 0xd2, 0x01, 0x79, 0x90, 0xd6, 0x25, 0x28
+            ],
+            &[
+                0x02, 0x08, 0x3e, 0x39, 0x79, 0xda, 0x01, 0x48, 0x12, 0xf5, 0x9f, 0x11, 0xd5, 0x26, 0x30, 0xda,
+                0x30, 0x13, 0x73, 0x27, 0xd1, 0x06, 0x49, 0xb0, 0xaa, 0x6e, 0x1c, 0x18, 0x1d, 0xb6, 0x17, 0xd7,
+                0xf2
+            ]
+        ),
+        (
+            &[
+                0x1b, 0xda, 0x12, 0x2b, 0xce, 0x8a, 0x8d, 0xba, 0xf1, 0x87, 0x7d, 0x96, 0x2b, 0x85, 0x92, 0xdd,
+                0x2d, 0x56
+            ],
+            &[
+                0x5f, 0xff, 0x20, 0xca, 0xfa, 0xb1, 0x19, 0xca, 0x2f, 0xc7, 0x35, 0x49, 0xe2, 0x0f, 0x5b, 0x0d
+            ],
+            &[
+                0xdd, 0xe5, 0x9b, 0x97, 0xd7, 0x22, 0x15, 0x6d, 0x4d, 0x9a, 0xff, 0x2b, 0xc7, 0x55, 0x98, 0x26
+            ],
+            &[
+                0x54, 0xb9, 0xf0, 0x4e, 0x6a, 0x09, 0x18, 0x9a
+            ],
+            &[
+                0x2e, 0xc4, 0x7b, 0x2c, 0x49, 0x54, 0xa4, 0x89, 0xaf, 0xc7, 0xba, 0x48, 0x97, 0xed, 0xcd, 0xae,
+                0x8c, 0xc3, 0x3b, 0x60, 0x45, 0x05, 0x99, 0xbd, 0x02, 0xc9, 0x63, 0x82, 0x90, 0x2a, 0xef, 0x7f,
+                0x83, 0x2a
+            ]
+        ),
+        (
+            &[
+                0x6c, 0xf3, 0x67, 0x20, 0x87, 0x2b, 0x85, 0x13, 0xf6, 0xea, 0xb1, 0xa8, 0xa4, 0x44, 0x38, 0xd5,
+                0xef, 0x11
+            ],
+            &[
+                0xa4, 0xa4, 0x78, 0x2b, 0xcf, 0xfd, 0x3e, 0xc5, 0xe7, 0xef, 0x6d, 0x8c, 0x34, 0xa5, 0x61, 0x23
+            ],
+            &[
+                0xb7, 0x81, 0xfc, 0xf2, 0xf7, 0x5f, 0xa5, 0xa8, 0xde, 0x97, 0xa9, 0xca, 0x48, 0xe5, 0x22, 0xec
+            ],
+            &[
+                0x89, 0x9a, 0x17, 0x58, 0x97, 0x56, 0x1d, 0x7e
+            ],
+            &[
+                0x0d, 0xe1, 0x8f, 0xd0, 0xfd, 0xd9, 0x1e, 0x7a, 0xf1, 0x9f, 0x1d, 0x8e, 0xe8, 0x73, 0x39, 0x38,
+                0xb1, 0xe8, 0xe7, 0xf6, 0xd2, 0x23, 0x16, 0x18, 0x10, 0x2f, 0xdb, 0x7f, 0xe5, 0x5f, 0xf1, 0x99,
+                0x17, 0x00
+            ]
+        ),
+        (
+            &[
+                0xca, 0x40, 0xd7, 0x44, 0x6e, 0x54, 0x5f, 0xfa, 0xed, 0x3b, 0xd1, 0x2a, 0x74, 0x0a, 0x65, 0x9f,
+                0xfb, 0xbb, 0x3c, 0xea, 0xb7
+            ],
+            &[
+                0x83, 0x95, 0xfc, 0xf1, 0xe9, 0x5b, 0xeb, 0xd6, 0x97, 0xbd, 0x01, 0x0b, 0xc7, 0x66, 0xaa, 0xc3
+            ],
+            &[
+                0x22, 0xe7, 0xad, 0xd9, 0x3c, 0xfc, 0x63, 0x93, 0xc5, 0x7e, 0xc0, 0xb3, 0xc1, 0x7d, 0x6b, 0x44
+            ],
+            &[
+                0x12, 0x67, 0x35, 0xfc, 0xc3, 0x20, 0xd2, 0x5a
+            ],
+            &[
+                0xcb, 0x89, 0x20, 0xf8, 0x7a, 0x6c, 0x75, 0xcf, 0xf3, 0x96, 0x27, 0xb5, 0x6e, 0x3e, 0xd1, 0x97,
+                0xc5, 0x52, 0xd2, 0x95, 0xa7, 0xcf, 0xc4, 0x6a, 0xfc, 0x25, 0x3b, 0x46, 0x52, 0xb1, 0xaf, 0x37,
+                0x95, 0xb1, 0x24, 0xab, 0x6e
+            ]
+        )
+    ];
+
+    for &(msg, key, nonce, header, expected) in &vectors {
+        let mut k = [0; 16];
+        k.copy_from_slice(key);
+
+        let (ciphertext, tag) = Eax::<Aes128>::new(&k, nonce, header).unwrap().encrypt(msg).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(msg.len());
+        assert_eq!(Eax::<Aes128>::new(&k, nonce, header).unwrap().decrypt(ciphertext, tag).unwrap(), msg);
+    }
+}
+
+#[test]
+fn test_eax_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let nonce = rand!(rand!(choose 0..40));
+    let header = rand!(rand!(choose 0..40));
+    let plaintext = rand!(rand!(choose 0..65));
+
+    let mut cipher = Eax::<Aes128>::new(&key, &nonce, &header).unwrap();
+    let (ciphertext, tag) = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).err(), Some(DecryptFail::NonceReuse));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag), Ok(plaintext));
+
+    let mut bad_tag = tag.clone();
+    bad_tag[15] ^= 0x80;
+    assert_eq!(cipher.decrypt(&ciphertext, &bad_tag), Err(DecryptFail::Auth));
+    assert_eq!(
+        Eax::<Aes128>::new(&key, &nonce, &[&header[..], &[0]].concat()).unwrap().decrypt(&ciphertext, &tag),
+        Err(DecryptFail::Auth)
+    );
+}
@@ -1,36 +1,75 @@
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::{ AES, Aes128 };
 use aes::mode::Ecb;
 use aes::utils::padding::Pkcs7Padding;
-use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+use aes::utils::padding::NoPadding;
+use aes::cipher::{
+    BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt
+};
 
 
 #[test]
 fn test_ecb_encrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let plaintext = rand!(rand!(choose 15..65));
 
     assert_eq!(
-        Ecb::new(&key).encrypt::<Pkcs7Padding>(&plaintext),
+        Ecb::<Aes128>::new(&key).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap(),
         encrypt(Type::AES_128_ECB, &key, &[], &plaintext)
     );
 }
 
 #[test]
 fn test_ecb_decrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let plaintext = rand!(rand!(choose 15..55));
 
     assert_eq!(
-        Ecb::new(&key).decrypt::<Pkcs7Padding>(
-            &Ecb::new(&key).encrypt::<Pkcs7Padding>(&plaintext)
+        Ecb::<Aes128>::new(&key).unwrap().decrypt::<Pkcs7Padding>(
+            &Ecb::<Aes128>::new(&key).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap()
         ).unwrap(),
         plaintext
     );
 
     assert_eq!(
-        Ecb::new(&key).decrypt::<Pkcs7Padding>(
+        Ecb::<Aes128>::new(&key).unwrap().decrypt::<Pkcs7Padding>(
             &encrypt(Type::AES_128_ECB, &key, &[], &plaintext)
         ),
         Ok(plaintext)
     );
 }
+
+#[test]
+fn test_ecb_in_place() {
+    let key: [u8; 16] = rand!(_);
+    let plaintext = rand!(16 * rand!(choose 1..5));
+
+    let mut data = plaintext.clone();
+    Ecb::<Aes128>::new(&key).unwrap().encrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, Ecb::<Aes128>::new(&key).unwrap().encrypt::<NoPadding>(&plaintext).unwrap());
+    Ecb::<Aes128>::new(&key).unwrap().decrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+#[test]
+fn test_ecb_misaligned() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+    let mut data = rand!(17);
+
+    assert_eq!(Ecb::<AES>::new(&[0; 20]).err(), Some(DecryptFail::InvalidKeyLength));
+    assert_eq!(
+        Ecb::<Aes128>::new(&key).unwrap().encrypt::<NoPadding>(&data),
+        Err(DecryptFail::MisalignedInput)
+    );
+    assert_eq!(
+        Ecb::<Aes128>::new(&key).unwrap().decrypt::<Pkcs7Padding>(&data),
+        Err(DecryptFail::MisalignedInput)
+    );
+    assert_eq!(
+        Ecb::<Aes128>::new(&key).unwrap().encrypt_in_place(&mut data),
+        Err(DecryptFail::MisalignedInput)
+    );
+}
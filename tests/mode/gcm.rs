@@ -1,6 +1,7 @@
 use crypto::aes::KeySize;
 use crypto::aes_gcm::AesGcm;
 use crypto::aead::AeadEncryptor;
+use aes::{ AES, Aes128 };
 use aes::mode::Gcm;
 use aes::cipher::{
     DecryptFail,
@@ -10,7 +11,7 @@ use aes::cipher::{
 
 #[test]
 fn test_gcm_encrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let nonce = rand!(12);
     let plaintext = rand!(rand!(choose 15..65));
 
@@ -18,29 +19,43 @@ fn test_gcm_encrypt() {
     AesGcm::new(KeySize::KeySize128, &key, &nonce, &nonce)
         .encrypt(&plaintext, &mut crypto_out, &mut crypto_tag);
     assert_eq!(
-        Gcm::new(&key, &nonce, &nonce).encrypt(&plaintext),
+        Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().encrypt(&plaintext).unwrap(),
         (crypto_out, crypto_tag)
     );
 }
 
 #[test]
 fn test_gcm_decrypt() {
-    let key = rand!(16);
+    let key: [u8; 16] = rand!(_);
     let nonce = rand!(12);
     let plaintext = rand!(rand!(choose 15..65));
 
-    let (ciphertext, tag) = Gcm::new(&key, &nonce, &nonce).encrypt(&plaintext);
+    let (ciphertext, tag) = Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().encrypt(&plaintext).unwrap();
 
     assert_eq!(
-        Gcm::new(&key, &nonce, &nonce).decrypt(&ciphertext[1..], &tag),
+        Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().decrypt(&ciphertext[1..], &tag),
         Err(DecryptFail::Auth)
     );
     assert_eq!(
-        Gcm::new(&key, &nonce, &nonce).decrypt(&ciphertext, &nonce),
+        Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().decrypt(&ciphertext, &nonce),
         Err(DecryptFail::Auth)
     );
     assert_eq!(
-        Gcm::new(&key, &nonce, &nonce).decrypt(&ciphertext, &tag),
+        Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap().decrypt(&ciphertext, &tag),
         Ok(plaintext)
     );
 }
+
+#[test]
+fn test_gcm_bad_nonce() {
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(
+        Gcm::<Aes128>::new(&key, &rand!(8), &[]).err().unwrap(),
+        DecryptFail::InvalidNonceLength
+    );
+    assert_eq!(
+        Gcm::<AES>::new(&key[1..], &rand!(12), &[]).err().unwrap(),
+        DecryptFail::InvalidKeyLength
+    );
+}
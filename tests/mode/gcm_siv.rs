@@ -0,0 +1,1054 @@
+use aes::{ Aes128, Aes256 };
+use aes::mode::GcmSiv;
+use aes::cipher::{
+    DecryptFail,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// RFC 8452 C.1, AEAD_AES_128_GCM_SIV: key, nonce, associated data, plaintext, ciphertext followed by the tag.
+#[test]
+fn test_gcm_siv_aes128() {
+    let vectors: [(&[u8], &[u8], &[u8], &[u8], &[u8]); 24] = [
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[],
+            &[
+                0xdc, 0x20, 0xe2, 0xd8, 0x3f, 0x25, 0x70, 0x5b, 0xb4, 0x9e, 0x43, 0x9e, 0xca, 0x56, 0xde, 0x25
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xb5, 0xd8, 0x39, 0x33, 0x0a, 0xc7, 0xb7, 0x86, 0x57, 0x87, 0x82, 0xff, 0xf6, 0x01, 0x3b, 0x81,
+                0x5b, 0x28, 0x7c, 0x22, 0x49, 0x3a, 0x36, 0x4c
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x73, 0x23, 0xea, 0x61, 0xd0, 0x59, 0x32, 0x26, 0x00, 0x47, 0xd9, 0x42, 0xa4, 0x97, 0x8d, 0xb3,
+                0x57, 0x39, 0x1a, 0x0b, 0xc4, 0xfd, 0xec, 0x8b, 0x0d, 0x10, 0x66, 0x39
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x74, 0x3f, 0x7c, 0x80, 0x77, 0xab, 0x25, 0xf8, 0x62, 0x4e, 0x2e, 0x94, 0x85, 0x79, 0xcf, 0x77,
+                0x30, 0x3a, 0xaf, 0x90, 0xf6, 0xfe, 0x21, 0x19, 0x9c, 0x60, 0x68, 0x57, 0x74, 0x37, 0xa0, 0xc4
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x84, 0xe0, 0x7e, 0x62, 0xba, 0x83, 0xa6, 0x58, 0x54, 0x17, 0x24, 0x5d, 0x7e, 0xc4, 0x13, 0xa9,
+                0xfe, 0x42, 0x7d, 0x63, 0x15, 0xc0, 0x9b, 0x57, 0xce, 0x45, 0xf2, 0xe3, 0x93, 0x6a, 0x94, 0x45,
+                0x1a, 0x8e, 0x45, 0xdc, 0xd4, 0x57, 0x8c, 0x66, 0x7c, 0xd8, 0x68, 0x47, 0xbf, 0x61, 0x55, 0xff
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x3f, 0xd2, 0x4c, 0xe1, 0xf5, 0xa6, 0x7b, 0x75, 0xbf, 0x23, 0x51, 0xf1, 0x81, 0xa4, 0x75, 0xc7,
+                0xb8, 0x00, 0xa5, 0xb4, 0xd3, 0xdc, 0xf7, 0x01, 0x06, 0xb1, 0xee, 0xa8, 0x2f, 0xa1, 0xd6, 0x4d,
+                0xf4, 0x2b, 0xf7, 0x22, 0x61, 0x22, 0xfa, 0x92, 0xe1, 0x7a, 0x40, 0xee, 0xaa, 0xc1, 0x20, 0x1b,
+                0x5e, 0x6e, 0x31, 0x1d, 0xbf, 0x39, 0x5d, 0x35, 0xb0, 0xfe, 0x39, 0xc2, 0x71, 0x43, 0x88, 0xf8
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x24, 0x33, 0x66, 0x8f, 0x10, 0x58, 0x19, 0x0f, 0x6d, 0x43, 0xe3, 0x60, 0xf4, 0xf3, 0x5c, 0xd8,
+                0xe4, 0x75, 0x12, 0x7c, 0xfc, 0xa7, 0x02, 0x8e, 0xa8, 0xab, 0x5c, 0x20, 0xf7, 0xab, 0x2a, 0xf0,
+                0x25, 0x16, 0xa2, 0xbd, 0xcb, 0xc0, 0x8d, 0x52, 0x1b, 0xe3, 0x7f, 0xf2, 0x8c, 0x15, 0x2b, 0xba,
+                0x36, 0x69, 0x7f, 0x25, 0xb4, 0xcd, 0x16, 0x9c, 0x65, 0x90, 0xd1, 0xdd, 0x39, 0x56, 0x6d, 0x3f,
+                0x8a, 0x26, 0x3d, 0xd3, 0x17, 0xaa, 0x88, 0xd5, 0x6b, 0xdf, 0x39, 0x36, 0xdb, 0xa7, 0x5b, 0xb8
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x1e, 0x6d, 0xab, 0xa3, 0x56, 0x69, 0xf4, 0x27, 0x3b, 0x0a, 0x1a, 0x25, 0x60, 0x96, 0x9c, 0xdf,
+                0x79, 0x0d, 0x99, 0x75, 0x9a, 0xbd, 0x15, 0x08
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x29, 0x6c, 0x78, 0x89, 0xfd, 0x99, 0xf4, 0x19, 0x17, 0xf4, 0x46, 0x20, 0x08, 0x29, 0x9c, 0x51,
+                0x02, 0x74, 0x5a, 0xaa, 0x3a, 0x0c, 0x46, 0x9f, 0xad, 0x9e, 0x07, 0x5a
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xe2, 0xb0, 0xc5, 0xda, 0x79, 0xa9, 0x01, 0xc1, 0x74, 0x5f, 0x70, 0x05, 0x25, 0xcb, 0x33, 0x5b,
+                0x8f, 0x89, 0x36, 0xec, 0x03, 0x9e, 0x4e, 0x4b, 0xb9, 0x7e, 0xbd, 0x8c, 0x44, 0x57, 0x44, 0x1f
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x62, 0x00, 0x48, 0xef, 0x3c, 0x1e, 0x73, 0xe5, 0x7e, 0x02, 0xbb, 0x85, 0x62, 0xc4, 0x16, 0xa3,
+                0x19, 0xe7, 0x3e, 0x4c, 0xaa, 0xc8, 0xe9, 0x6a, 0x1e, 0xcb, 0x29, 0x33, 0x14, 0x5a, 0x1d, 0x71,
+                0xe6, 0xaf, 0x6a, 0x7f, 0x87, 0x28, 0x7d, 0xa0, 0x59, 0xa7, 0x16, 0x84, 0xed, 0x34, 0x98, 0xe1
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x50, 0xc8, 0x30, 0x3e, 0xa9, 0x39, 0x25, 0xd6, 0x40, 0x90, 0xd0, 0x7b, 0xd1, 0x09, 0xdf, 0xd9,
+                0x51, 0x5a, 0x5a, 0x33, 0x43, 0x10, 0x19, 0xc1, 0x7d, 0x93, 0x46, 0x59, 0x99, 0xa8, 0xb0, 0x05,
+                0x32, 0x01, 0xd7, 0x23, 0x12, 0x0a, 0x85, 0x62, 0xb8, 0x38, 0xcd, 0xff, 0x25, 0xbf, 0x9d, 0x1e,
+                0x6a, 0x8c, 0xc3, 0x86, 0x5f, 0x76, 0x89, 0x7c, 0x2e, 0x4b, 0x24, 0x5c, 0xf3, 0x1c, 0x51, 0xf2
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x2f, 0x5c, 0x64, 0x05, 0x9d, 0xb5, 0x5e, 0xe0, 0xfb, 0x84, 0x7e, 0xd5, 0x13, 0x00, 0x37, 0x46,
+                0xac, 0xa4, 0xe6, 0x1c, 0x71, 0x1b, 0x5d, 0xe2, 0xe7, 0xa7, 0x7f, 0xfd, 0x02, 0xda, 0x42, 0xfe,
+                0xec, 0x60, 0x19, 0x10, 0xd3, 0x46, 0x7b, 0xb8, 0xb3, 0x6e, 0xbb, 0xae, 0xbc, 0xe5, 0xfb, 0xa3,
+                0x0d, 0x36, 0xc9, 0x5f, 0x48, 0xa3, 0xe7, 0x98, 0x0f, 0x0e, 0x7a, 0xc2, 0x99, 0x33, 0x2a, 0x80,
+                0xcd, 0xc4, 0x6a, 0xe4, 0x75, 0x56, 0x3d, 0xe0, 0x37, 0x00, 0x1e, 0xf8, 0x4a, 0xe2, 0x17, 0x44
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xa8, 0xfe, 0x3e, 0x87, 0x07, 0xeb, 0x1f, 0x84, 0xfb, 0x28, 0xf8, 0xcb, 0x73, 0xde, 0x8e, 0x99,
+                0xe2, 0xf4, 0x8a, 0x14
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x6b, 0xb0, 0xfe, 0xcf, 0x5d, 0xed, 0x9b, 0x77, 0xf9, 0x02, 0xc7, 0xd5, 0xda, 0x23, 0x6a, 0x43,
+                0x91, 0xdd, 0x02, 0x97, 0x24, 0xaf, 0xc9, 0x80, 0x5e, 0x97, 0x6f, 0x45, 0x1e, 0x6d, 0x87, 0xf6,
+                0xfe, 0x10, 0x65, 0x14
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00
+            ],
+            &[
+                0x44, 0xd0, 0xaa, 0xf6, 0xfb, 0x2f, 0x1f, 0x34, 0xad, 0xd5, 0xe8, 0x06, 0x4e, 0x83, 0xe1, 0x2a,
+                0x2a, 0xda, 0xbf, 0xf9, 0xb2, 0xef, 0x00, 0xfb, 0x47, 0x92, 0x0c, 0xc7, 0x2a, 0x0c, 0x0f, 0x13,
+                0xb9, 0xfd
+            ]
+        ),
+        (
+            &[
+                0xe6, 0x60, 0x21, 0xd5, 0xeb, 0x8e, 0x4f, 0x40, 0x66, 0xd4, 0xad, 0xb9, 0xc3, 0x35, 0x60, 0xe4
+            ],
+            &[
+                0xf4, 0x6e, 0x44, 0xbb, 0x3d, 0xa0, 0x01, 0x5c, 0x94, 0xf7, 0x08, 0x87
+            ],
+            &[],
+            &[],
+            &[
+                0xa4, 0x19, 0x4b, 0x79, 0x07, 0x1b, 0x01, 0xa8, 0x7d, 0x65, 0xf7, 0x06, 0xe3, 0x94, 0x95, 0x78
+            ]
+        ),
+        (
+            &[
+                0x36, 0x86, 0x42, 0x00, 0xe0, 0xea, 0xf5, 0x28, 0x4d, 0x88, 0x4a, 0x0e, 0x77, 0xd3, 0x16, 0x46
+            ],
+            &[
+                0xba, 0xe8, 0xe3, 0x7f, 0xc8, 0x34, 0x41, 0xb1, 0x60, 0x34, 0x56, 0x6b
+            ],
+            &[
+                0x46, 0xbb, 0x91, 0xc3, 0xc5
+            ],
+            &[
+                0x7a, 0x80, 0x6c
+            ],
+            &[
+                0xaf, 0x60, 0xeb, 0x71, 0x1b, 0xd8, 0x5b, 0xc1, 0xe4, 0xd3, 0xe0, 0xa4, 0x62, 0xe0, 0x74, 0xee,
+                0xa4, 0x28, 0xa8
+            ]
+        ),
+        (
+            &[
+                0xae, 0xdb, 0x64, 0xa6, 0xc5, 0x90, 0xbc, 0x84, 0xd1, 0xa5, 0xe2, 0x69, 0xe4, 0xb4, 0x78, 0x01
+            ],
+            &[
+                0xaf, 0xc0, 0x57, 0x7e, 0x34, 0x69, 0x9b, 0x9e, 0x67, 0x1f, 0xdd, 0x4f
+            ],
+            &[
+                0xfc, 0x88, 0x0c, 0x94, 0xa9, 0x51, 0x98, 0x87, 0x42, 0x96
+            ],
+            &[
+                0xbd, 0xc6, 0x6f, 0x14, 0x65, 0x45
+            ],
+            &[
+                0xbb, 0x93, 0xa3, 0xe3, 0x4d, 0x3c, 0xd6, 0xa9, 0xc4, 0x55, 0x45, 0xcf, 0xc1, 0x1f, 0x03, 0xad,
+                0x74, 0x3d, 0xba, 0x20, 0xf9, 0x66
+            ]
+        ),
+        (
+            &[
+                0xd5, 0xcc, 0x1f, 0xd1, 0x61, 0x32, 0x0b, 0x69, 0x20, 0xce, 0x07, 0x78, 0x7f, 0x86, 0x74, 0x3b
+            ],
+            &[
+                0x27, 0x5d, 0x1a, 0xb3, 0x2f, 0x6d, 0x1f, 0x04, 0x34, 0xd8, 0x84, 0x8c
+            ],
+            &[
+                0x04, 0x67, 0x87, 0xf3, 0xea, 0x22, 0xc1, 0x27, 0xaa, 0xf1, 0x95, 0xd1, 0x89, 0x47, 0x28
+            ],
+            &[
+                0x11, 0x77, 0x44, 0x1f, 0x19, 0x54, 0x95, 0x86, 0x0f
+            ],
+            &[
+                0x4f, 0x37, 0x28, 0x1f, 0x7a, 0xd1, 0x29, 0x49, 0xd0, 0x1d, 0x02, 0xfd, 0x0c, 0xd1, 0x74, 0xc8,
+                0x4f, 0xc5, 0xda, 0xe2, 0xf6, 0x0f, 0x52, 0xfd, 0x2b
+            ]
+        ),
+        (
+            &[
+                0xb3, 0xfe, 0xd1, 0x47, 0x3c, 0x52, 0x8b, 0x84, 0x26, 0xa5, 0x82, 0x99, 0x59, 0x29, 0xa1, 0x49
+            ],
+            &[
+                0x9e, 0x9a, 0xd8, 0x78, 0x0c, 0x8d, 0x63, 0xd0, 0xab, 0x41, 0x49, 0xc0
+            ],
+            &[
+                0xc9, 0x88, 0x2e, 0x53, 0x86, 0xfd, 0x9f, 0x92, 0xec, 0x48, 0x9c, 0x8f, 0xde, 0x2b, 0xe2, 0xcf,
+                0x97, 0xe7, 0x4e, 0x93
+            ],
+            &[
+                0x9f, 0x57, 0x2c, 0x61, 0x4b, 0x47, 0x45, 0x91, 0x44, 0x74, 0xe7, 0xc7
+            ],
+            &[
+                0xf5, 0x46, 0x73, 0xc5, 0xdd, 0xf7, 0x10, 0xc7, 0x45, 0x64, 0x1c, 0x8b, 0xc1, 0xdc, 0x2f, 0x87,
+                0x1f, 0xb7, 0x56, 0x1d, 0xa1, 0x28, 0x6e, 0x65, 0x5e, 0x24, 0xb7, 0xb0
+            ]
+        ),
+        (
+            &[
+                0x2d, 0x4e, 0xd8, 0x7d, 0xa4, 0x41, 0x02, 0x95, 0x2e, 0xf9, 0x4b, 0x02, 0xb8, 0x05, 0x24, 0x9b
+            ],
+            &[
+                0xac, 0x80, 0xe6, 0xf6, 0x14, 0x55, 0xbf, 0xac, 0x83, 0x08, 0xa2, 0xd4
+            ],
+            &[
+                0x29, 0x50, 0xa7, 0x0d, 0x5a, 0x1d, 0xb2, 0x31, 0x6f, 0xd5, 0x68, 0x37, 0x8d, 0xa1, 0x07, 0xb5,
+                0x2b, 0x0d, 0xa5, 0x52, 0x10, 0xcc, 0x1c, 0x1b, 0x0a
+            ],
+            &[
+                0x0d, 0x8c, 0x84, 0x51, 0x17, 0x80, 0x82, 0x35, 0x5c, 0x9e, 0x94, 0x0f, 0xea, 0x2f, 0x58
+            ],
+            &[
+                0xc9, 0xff, 0x54, 0x5e, 0x07, 0xb8, 0x8a, 0x01, 0x5f, 0x05, 0xb2, 0x74, 0x54, 0x0a, 0xa1, 0x83,
+                0xb3, 0x44, 0x9b, 0x9f, 0x39, 0x55, 0x2d, 0xe9, 0x9d, 0xc2, 0x14, 0xa1, 0x19, 0x0b, 0x0b
+            ]
+        ),
+        (
+            &[
+                0xbd, 0xe3, 0xb2, 0xf2, 0x04, 0xd1, 0xe9, 0xf8, 0xb0, 0x6b, 0xc4, 0x7f, 0x97, 0x45, 0xb3, 0xd1
+            ],
+            &[
+                0xae, 0x06, 0x55, 0x6f, 0xb6, 0xaa, 0x78, 0x90, 0xbe, 0xbc, 0x18, 0xfe
+            ],
+            &[
+                0x18, 0x60, 0xf7, 0x62, 0xeb, 0xfb, 0xd0, 0x82, 0x84, 0xe4, 0x21, 0x70, 0x2d, 0xe0, 0xde, 0x18,
+                0xba, 0xa9, 0xc9, 0x59, 0x62, 0x91, 0xb0, 0x84, 0x66, 0xf3, 0x7d, 0xe2, 0x1c, 0x7f
+            ],
+            &[
+                0x6b, 0x3d, 0xb4, 0xda, 0x3d, 0x57, 0xaa, 0x94, 0x84, 0x2b, 0x98, 0x03, 0xa9, 0x6e, 0x07, 0xfb,
+                0x6d, 0xe7
+            ],
+            &[
+                0x62, 0x98, 0xb2, 0x96, 0xe2, 0x4e, 0x8c, 0xc3, 0x5d, 0xce, 0x0b, 0xed, 0x48, 0x4b, 0x7f, 0x30,
+                0xd5, 0x80, 0x3e, 0x37, 0x70, 0x94, 0xf0, 0x47, 0x09, 0xf6, 0x4d, 0x7b, 0x98, 0x53, 0x10, 0xa4,
+                0xdb, 0x84
+            ]
+        ),
+        (
+            &[
+                0xf9, 0x01, 0xcf, 0xe8, 0xa6, 0x96, 0x15, 0xa9, 0x3f, 0xdf, 0x7a, 0x98, 0xca, 0xd4, 0x81, 0x79
+            ],
+            &[
+                0x62, 0x45, 0x70, 0x9f, 0xb1, 0x88, 0x53, 0xf6, 0x8d, 0x83, 0x36, 0x40
+            ],
+            &[
+                0x75, 0x76, 0xf7, 0x02, 0x8e, 0xc6, 0xeb, 0x5e, 0xa7, 0xe2, 0x98, 0x34, 0x2a, 0x94, 0xd4, 0xb2,
+                0x02, 0xb3, 0x70, 0xef, 0x97, 0x68, 0xec, 0x65, 0x61, 0xc4, 0xfe, 0x6b, 0x7e, 0x72, 0x96, 0xfa,
+                0x85, 0x9c, 0x21
+            ],
+            &[
+                0xe4, 0x2a, 0x3c, 0x02, 0xc2, 0x5b, 0x64, 0x86, 0x9e, 0x14, 0x6d, 0x7b, 0x23, 0x39, 0x87, 0xbd,
+                0xdf, 0xc2, 0x40, 0x87, 0x1d
+            ],
+            &[
+                0x39, 0x1c, 0xc3, 0x28, 0xd4, 0x84, 0xa4, 0xf4, 0x64, 0x06, 0x18, 0x1b, 0xcd, 0x62, 0xef, 0xd9,
+                0xb3, 0xee, 0x19, 0x7d, 0x05, 0x2d, 0x15, 0x50, 0x6c, 0x84, 0xa9, 0xed, 0xd6, 0x5e, 0x13, 0xe9,
+                0xd2, 0x4a, 0x2a, 0x6e, 0x70
+            ]
+        )
+    ];
+
+    for &(key, nonce, aad, msg, expected) in &vectors {
+        let mut k = [0; 16];
+        k.copy_from_slice(key);
+
+        let (ciphertext, tag) = GcmSiv::<Aes128>::new(&k, nonce, aad).unwrap().encrypt(msg).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(msg.len());
+        assert_eq!(GcmSiv::<Aes128>::new(&k, nonce, aad).unwrap().decrypt(ciphertext, tag).unwrap(), msg);
+    }
+}
+
+/// RFC 8452 C.2, AEAD_AES_256_GCM_SIV.
+#[test]
+fn test_gcm_siv_aes256() {
+    let vectors: [(&[u8], &[u8], &[u8], &[u8], &[u8]); 24] = [
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[],
+            &[
+                0x07, 0xf5, 0xf4, 0x16, 0x9b, 0xbf, 0x55, 0xa8, 0x40, 0x0c, 0xd4, 0x7e, 0xa6, 0xfd, 0x40, 0x0f
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xc2, 0xef, 0x32, 0x8e, 0x5c, 0x71, 0xc8, 0x3b, 0x84, 0x31, 0x22, 0x13, 0x0f, 0x73, 0x64, 0xb7,
+                0x61, 0xe0, 0xb9, 0x74, 0x27, 0xe3, 0xdf, 0x28
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x9a, 0xab, 0x2a, 0xeb, 0x3f, 0xaa, 0x0a, 0x34, 0xae, 0xa8, 0xe2, 0xb1, 0x8c, 0xa5, 0x0d, 0xa9,
+                0xae, 0x65, 0x59, 0xe4, 0x8f, 0xd1, 0x0f, 0x6e, 0x5c, 0x9c, 0xa1, 0x7e
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x85, 0xa0, 0x1b, 0x63, 0x02, 0x5b, 0xa1, 0x9b, 0x7f, 0xd3, 0xdd, 0xfc, 0x03, 0x3b, 0x3e, 0x76,
+                0xc9, 0xea, 0xc6, 0xfa, 0x70, 0x09, 0x42, 0x70, 0x2e, 0x90, 0x86, 0x23, 0x83, 0xc6, 0xc3, 0x66
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x4a, 0x6a, 0x9d, 0xb4, 0xc8, 0xc6, 0x54, 0x92, 0x01, 0xb9, 0xed, 0xb5, 0x30, 0x06, 0xcb, 0xa8,
+                0x21, 0xec, 0x9c, 0xf8, 0x50, 0x94, 0x8a, 0x7c, 0x86, 0xc6, 0x8a, 0xc7, 0x53, 0x9d, 0x02, 0x7f,
+                0xe8, 0x19, 0xe6, 0x3a, 0xbc, 0xd0, 0x20, 0xb0, 0x06, 0xa9, 0x76, 0x39, 0x76, 0x32, 0xeb, 0x5d
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xc0, 0x0d, 0x12, 0x18, 0x93, 0xa9, 0xfa, 0x60, 0x3f, 0x48, 0xcc, 0xc1, 0xca, 0x3c, 0x57, 0xce,
+                0x74, 0x99, 0x24, 0x5e, 0xa0, 0x04, 0x6d, 0xb1, 0x6c, 0x53, 0xc7, 0xc6, 0x6f, 0xe7, 0x17, 0xe3,
+                0x9c, 0xf6, 0xc7, 0x48, 0x83, 0x7b, 0x61, 0xf6, 0xee, 0x3a, 0xdc, 0xee, 0x17, 0x53, 0x4e, 0xd5,
+                0x79, 0x0b, 0xc9, 0x68, 0x80, 0xa9, 0x9b, 0xa8, 0x04, 0xbd, 0x12, 0xc0, 0xe6, 0xa2, 0x2c, 0xc4
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xc2, 0xd5, 0x16, 0x0a, 0x1f, 0x86, 0x83, 0x83, 0x49, 0x10, 0xac, 0xda, 0xfc, 0x41, 0xfb, 0xb1,
+                0x63, 0x2d, 0x4a, 0x35, 0x3e, 0x8b, 0x90, 0x5e, 0xc9, 0xa5, 0x49, 0x9a, 0xc3, 0x4f, 0x96, 0xc7,
+                0xe1, 0x04, 0x9e, 0xb0, 0x80, 0x88, 0x38, 0x91, 0xa4, 0xdb, 0x8c, 0xaa, 0xa1, 0xf9, 0x9d, 0xd0,
+                0x04, 0xd8, 0x04, 0x87, 0x54, 0x07, 0x35, 0x23, 0x4e, 0x37, 0x44, 0x51, 0x2c, 0x6f, 0x90, 0xce,
+                0x11, 0x28, 0x64, 0xc2, 0x69, 0xfc, 0x0d, 0x9d, 0x88, 0xc6, 0x1f, 0xa4, 0x7e, 0x39, 0xaa, 0x08
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x1d, 0xe2, 0x29, 0x67, 0x23, 0x7a, 0x81, 0x32, 0x91, 0x21, 0x3f, 0x26, 0x7e, 0x3b, 0x45, 0x2f,
+                0x02, 0xd0, 0x1a, 0xe3, 0x3e, 0x4e, 0xc8, 0x54
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x16, 0x3d, 0x6f, 0x9c, 0xc1, 0xb3, 0x46, 0xcd, 0x45, 0x3a, 0x2e, 0x4c, 0xc1, 0xa4, 0xa1, 0x9a,
+                0xe8, 0x00, 0x94, 0x1c, 0xcd, 0xc5, 0x7c, 0xc8, 0x41, 0x3c, 0x27, 0x7f
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xc9, 0x15, 0x45, 0x82, 0x3c, 0xc2, 0x4f, 0x17, 0xdb, 0xb0, 0xe9, 0xe8, 0x07, 0xd5, 0xec, 0x17,
+                0xb2, 0x92, 0xd2, 0x8f, 0xf6, 0x11, 0x89, 0xe8, 0xe4, 0x9f, 0x38, 0x75, 0xef, 0x91, 0xaf, 0xf7
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x07, 0xda, 0xd3, 0x64, 0xbf, 0xc2, 0xb9, 0xda, 0x89, 0x11, 0x6d, 0x7b, 0xef, 0x6d, 0xaa, 0xaf,
+                0x6f, 0x25, 0x55, 0x10, 0xaa, 0x65, 0x4f, 0x92, 0x0a, 0xc8, 0x1b, 0x94, 0xe8, 0xba, 0xd3, 0x65,
+                0xae, 0xa1, 0xba, 0xd1, 0x27, 0x02, 0xe1, 0x96, 0x56, 0x04, 0x37, 0x4a, 0xab, 0x96, 0xdb, 0xbc
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0xc6, 0x7a, 0x1f, 0x0f, 0x56, 0x7a, 0x51, 0x98, 0xaa, 0x1f, 0xcc, 0x8e, 0x3f, 0x21, 0x31, 0x43,
+                0x36, 0xf7, 0xf5, 0x1c, 0xa8, 0xb1, 0xaf, 0x61, 0xfe, 0xac, 0x35, 0xa8, 0x64, 0x16, 0xfa, 0x47,
+                0xfb, 0xca, 0x3b, 0x5f, 0x74, 0x9c, 0xdf, 0x56, 0x45, 0x27, 0xf2, 0x31, 0x4f, 0x42, 0xfe, 0x25,
+                0x03, 0x33, 0x27, 0x42, 0xb2, 0x28, 0xc6, 0x47, 0x17, 0x36, 0x16, 0xcf, 0xd4, 0x4c, 0x54, 0xeb
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x67, 0xfd, 0x45, 0xe1, 0x26, 0xbf, 0xb9, 0xa7, 0x99, 0x30, 0xc4, 0x3a, 0xad, 0x2d, 0x36, 0x96,
+                0x7d, 0x3f, 0x0e, 0x4d, 0x21, 0x7c, 0x1e, 0x55, 0x1f, 0x59, 0x72, 0x78, 0x70, 0xbe, 0xef, 0xc9,
+                0x8c, 0xb9, 0x33, 0xa8, 0xfc, 0xe9, 0xde, 0x88, 0x7b, 0x1e, 0x40, 0x79, 0x99, 0x88, 0xdb, 0x1f,
+                0xc3, 0xf9, 0x18, 0x80, 0xed, 0x40, 0x5b, 0x2d, 0xd2, 0x98, 0x31, 0x88, 0x58, 0x46, 0x7c, 0x89,
+                0x5b, 0xde, 0x02, 0x85, 0x03, 0x7c, 0x5d, 0xe8, 0x1e, 0x5b, 0x57, 0x0a, 0x04, 0x9b, 0x62, 0xa0
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x02, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x22, 0xb3, 0xf4, 0xcd, 0x18, 0x35, 0xe5, 0x17, 0x74, 0x1d, 0xfd, 0xdc, 0xcf, 0xa0, 0x7f, 0xa4,
+                0x66, 0x1b, 0x74, 0xcf
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x43, 0xdd, 0x01, 0x63, 0xcd, 0xb4, 0x8f, 0x9f, 0xe3, 0x21, 0x2b, 0xf6, 0x1b, 0x20, 0x19, 0x76,
+                0x06, 0x7f, 0x34, 0x2b, 0xb8, 0x79, 0xad, 0x97, 0x6d, 0x82, 0x42, 0xac, 0xc1, 0x88, 0xab, 0x59,
+                0xca, 0xbf, 0xe3, 0x07
+            ]
+        ),
+        (
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x02, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x04, 0x00
+            ],
+            &[
+                0x46, 0x24, 0x01, 0x72, 0x4b, 0x5c, 0xe6, 0x58, 0x8d, 0x5a, 0x54, 0xaa, 0xe5, 0x37, 0x55, 0x13,
+                0xa0, 0x75, 0xcf, 0xcd, 0xf5, 0x04, 0x21, 0x12, 0xaa, 0x29, 0x68, 0x5c, 0x91, 0x2f, 0xc2, 0x05,
+                0x65, 0x43
+            ]
+        ),
+        (
+            &[
+                0xe6, 0x60, 0x21, 0xd5, 0xeb, 0x8e, 0x4f, 0x40, 0x66, 0xd4, 0xad, 0xb9, 0xc3, 0x35, 0x60, 0xe4,
+                0xf4, 0x6e, 0x44, 0xbb, 0x3d, 0xa0, 0x01, 0x5c, 0x94, 0xf7, 0x08, 0x87, 0x36, 0x86, 0x42, 0x00
+            ],
+            &[
+                0xe0, 0xea, 0xf5, 0x28, 0x4d, 0x88, 0x4a, 0x0e, 0x77, 0xd3, 0x16, 0x46
+            ],
+            &[],
+            &[],
+            &[
+                0x16, 0x9f, 0xbb, 0x2f, 0xbf, 0x38, 0x9a, 0x99, 0x5f, 0x63, 0x90, 0xaf, 0x22, 0x22, 0x8a, 0x62
+            ]
+        ),
+        (
+            &[
+                0xba, 0xe8, 0xe3, 0x7f, 0xc8, 0x34, 0x41, 0xb1, 0x60, 0x34, 0x56, 0x6b, 0x7a, 0x80, 0x6c, 0x46,
+                0xbb, 0x91, 0xc3, 0xc5, 0xae, 0xdb, 0x64, 0xa6, 0xc5, 0x90, 0xbc, 0x84, 0xd1, 0xa5, 0xe2, 0x69
+            ],
+            &[
+                0xe4, 0xb4, 0x78, 0x01, 0xaf, 0xc0, 0x57, 0x7e, 0x34, 0x69, 0x9b, 0x9e
+            ],
+            &[
+                0x4f, 0xbd, 0xc6, 0x6f, 0x14
+            ],
+            &[
+                0x67, 0x1f, 0xdd
+            ],
+            &[
+                0x0e, 0xac, 0xcb, 0x93, 0xda, 0x9b, 0xb8, 0x13, 0x33, 0xae, 0xe0, 0xc7, 0x85, 0xb2, 0x40, 0xd3,
+                0x19, 0x71, 0x9d
+            ]
+        ),
+        (
+            &[
+                0x65, 0x45, 0xfc, 0x88, 0x0c, 0x94, 0xa9, 0x51, 0x98, 0x87, 0x42, 0x96, 0xd5, 0xcc, 0x1f, 0xd1,
+                0x61, 0x32, 0x0b, 0x69, 0x20, 0xce, 0x07, 0x78, 0x7f, 0x86, 0x74, 0x3b, 0x27, 0x5d, 0x1a, 0xb3
+            ],
+            &[
+                0x2f, 0x6d, 0x1f, 0x04, 0x34, 0xd8, 0x84, 0x8c, 0x11, 0x77, 0x44, 0x1f
+            ],
+            &[
+                0x67, 0x87, 0xf3, 0xea, 0x22, 0xc1, 0x27, 0xaa, 0xf1, 0x95
+            ],
+            &[
+                0x19, 0x54, 0x95, 0x86, 0x0f, 0x04
+            ],
+            &[
+                0xa2, 0x54, 0xda, 0xd4, 0xf3, 0xf9, 0x6b, 0x62, 0xb8, 0x4d, 0xc4, 0x0c, 0x84, 0x63, 0x6a, 0x5e,
+                0xc1, 0x20, 0x20, 0xec, 0x8c, 0x2c
+            ]
+        ),
+        (
+            &[
+                0xd1, 0x89, 0x47, 0x28, 0xb3, 0xfe, 0xd1, 0x47, 0x3c, 0x52, 0x8b, 0x84, 0x26, 0xa5, 0x82, 0x99,
+                0x59, 0x29, 0xa1, 0x49, 0x9e, 0x9a, 0xd8, 0x78, 0x0c, 0x8d, 0x63, 0xd0, 0xab, 0x41, 0x49, 0xc0
+            ],
+            &[
+                0x9f, 0x57, 0x2c, 0x61, 0x4b, 0x47, 0x45, 0x91, 0x44, 0x74, 0xe7, 0xc7
+            ],
+            &[
+                0x48, 0x9c, 0x8f, 0xde, 0x2b, 0xe2, 0xcf, 0x97, 0xe7, 0x4e, 0x93, 0x2d, 0x4e, 0xd8, 0x7d
+            ],
+            &[
+                0xc9, 0x88, 0x2e, 0x53, 0x86, 0xfd, 0x9f, 0x92, 0xec
+            ],
+            &[
+                0x0d, 0xf9, 0xe3, 0x08, 0x67, 0x82, 0x44, 0xc4, 0x4b, 0xc0, 0xfd, 0x3d, 0xc6, 0x62, 0x8d, 0xfe,
+                0x55, 0xeb, 0xb0, 0xb9, 0xfb, 0x22, 0x95, 0xc8, 0xc2
+            ]
+        ),
+        (
+            &[
+                0xa4, 0x41, 0x02, 0x95, 0x2e, 0xf9, 0x4b, 0x02, 0xb8, 0x05, 0x24, 0x9b, 0xac, 0x80, 0xe6, 0xf6,
+                0x14, 0x55, 0xbf, 0xac, 0x83, 0x08, 0xa2, 0xd4, 0x0d, 0x8c, 0x84, 0x51, 0x17, 0x80, 0x82, 0x35
+            ],
+            &[
+                0x5c, 0x9e, 0x94, 0x0f, 0xea, 0x2f, 0x58, 0x29, 0x50, 0xa7, 0x0d, 0x5a
+            ],
+            &[
+                0x0d, 0xa5, 0x52, 0x10, 0xcc, 0x1c, 0x1b, 0x0a, 0xbd, 0xe3, 0xb2, 0xf2, 0x04, 0xd1, 0xe9, 0xf8,
+                0xb0, 0x6b, 0xc4, 0x7f
+            ],
+            &[
+                0x1d, 0xb2, 0x31, 0x6f, 0xd5, 0x68, 0x37, 0x8d, 0xa1, 0x07, 0xb5, 0x2b
+            ],
+            &[
+                0x8d, 0xbe, 0xb9, 0xf7, 0x25, 0x5b, 0xf5, 0x76, 0x9d, 0xd5, 0x66, 0x92, 0x40, 0x40, 0x99, 0xc2,
+                0x58, 0x7f, 0x64, 0x97, 0x9f, 0x21, 0x82, 0x67, 0x06, 0xd4, 0x97, 0xd5
+            ]
+        ),
+        (
+            &[
+                0x97, 0x45, 0xb3, 0xd1, 0xae, 0x06, 0x55, 0x6f, 0xb6, 0xaa, 0x78, 0x90, 0xbe, 0xbc, 0x18, 0xfe,
+                0x6b, 0x3d, 0xb4, 0xda, 0x3d, 0x57, 0xaa, 0x94, 0x84, 0x2b, 0x98, 0x03, 0xa9, 0x6e, 0x07, 0xfb
+            ],
+            &[
+                0x6d, 0xe7, 0x18, 0x60, 0xf7, 0x62, 0xeb, 0xfb, 0xd0, 0x82, 0x84, 0xe4
+            ],
+            &[
+                0xf3, 0x7d, 0xe2, 0x1c, 0x7f, 0xf9, 0x01, 0xcf, 0xe8, 0xa6, 0x96, 0x15, 0xa9, 0x3f, 0xdf, 0x7a,
+                0x98, 0xca, 0xd4, 0x81, 0x79, 0x62, 0x45, 0x70, 0x9f
+            ],
+            &[
+                0x21, 0x70, 0x2d, 0xe0, 0xde, 0x18, 0xba, 0xa9, 0xc9, 0x59, 0x62, 0x91, 0xb0, 0x84, 0x66
+            ],
+            &[
+                0x79, 0x35, 0x76, 0xdf, 0xa5, 0xc0, 0xf8, 0x87, 0x29, 0xa7, 0xed, 0x3c, 0x2f, 0x1b, 0xff, 0xb3,
+                0x08, 0x0d, 0x28, 0xf6, 0xeb, 0xb5, 0xd3, 0x64, 0x8c, 0xe9, 0x7b, 0xd5, 0xba, 0x67, 0xfd
+            ]
+        ),
+        (
+            &[
+                0xb1, 0x88, 0x53, 0xf6, 0x8d, 0x83, 0x36, 0x40, 0xe4, 0x2a, 0x3c, 0x02, 0xc2, 0x5b, 0x64, 0x86,
+                0x9e, 0x14, 0x6d, 0x7b, 0x23, 0x39, 0x87, 0xbd, 0xdf, 0xc2, 0x40, 0x87, 0x1d, 0x75, 0x76, 0xf7
+            ],
+            &[
+                0x02, 0x8e, 0xc6, 0xeb, 0x5e, 0xa7, 0xe2, 0x98, 0x34, 0x2a, 0x94, 0xd4
+            ],
+            &[
+                0x9c, 0x21, 0x59, 0x05, 0x8b, 0x1f, 0x0f, 0xe9, 0x14, 0x33, 0xa5, 0xbd, 0xc2, 0x0e, 0x21, 0x4e,
+                0xab, 0x7f, 0xec, 0xef, 0x44, 0x54, 0xa1, 0x0e, 0xf0, 0x65, 0x7d, 0xf2, 0x1a, 0xc7
+            ],
+            &[
+                0xb2, 0x02, 0xb3, 0x70, 0xef, 0x97, 0x68, 0xec, 0x65, 0x61, 0xc4, 0xfe, 0x6b, 0x7e, 0x72, 0x96,
+                0xfa, 0x85
+            ],
+            &[
+                0x85, 0x7e, 0x16, 0xa6, 0x49, 0x15, 0xa7, 0x87, 0x63, 0x76, 0x87, 0xdb, 0x4a, 0x95, 0x19, 0x63,
+                0x5c, 0xdd, 0x45, 0x4f, 0xc2, 0xa1, 0x54, 0xfe, 0xa9, 0x1f, 0x83, 0x63, 0xa3, 0x9f, 0xec, 0x7d,
+                0x0a, 0x49
+            ]
+        ),
+        (
+            &[
+                0x3c, 0x53, 0x5d, 0xe1, 0x92, 0xea, 0xed, 0x38, 0x22, 0xa2, 0xfb, 0xbe, 0x2c, 0xa9, 0xdf, 0xc8,
+                0x82, 0x55, 0xe1, 0x4a, 0x66, 0x1b, 0x8a, 0xa8, 0x2c, 0xc5, 0x42, 0x36, 0x09, 0x3b, 0xbc, 0x23
+            ],
+            &[
+                0x68, 0x80, 0x89, 0xe5, 0x55, 0x40, 0xdb, 0x18, 0x72, 0x50, 0x4e, 0x1c
+            ],
+            &[
+                0x73, 0x43, 0x20, 0xcc, 0xc9, 0xd9, 0xbb, 0xbb, 0x19, 0xcb, 0x81, 0xb2, 0xaf, 0x4e, 0xcb, 0xc3,
+                0xe7, 0x28, 0x34, 0x32, 0x1f, 0x7a, 0xa0, 0xf7, 0x0b, 0x72, 0x82, 0xb4, 0xf3, 0x3d, 0xf2, 0x3f,
+                0x16, 0x75, 0x41
+            ],
+            &[
+                0xce, 0xd5, 0x32, 0xce, 0x41, 0x59, 0xb0, 0x35, 0x27, 0x7d, 0x4d, 0xfb, 0xb7, 0xdb, 0x62, 0x96,
+                0x8b, 0x13, 0xcd, 0x4e, 0xec
+            ],
+            &[
+                0x62, 0x66, 0x60, 0xc2, 0x6e, 0xa6, 0x61, 0x2f, 0xb1, 0x7a, 0xd9, 0x1e, 0x8e, 0x76, 0x76, 0x39,
+                0xed, 0xd6, 0xc9, 0xfa, 0xee, 0x9d, 0x6c, 0x70, 0x29, 0x67, 0x5b, 0x89, 0xea, 0xf4, 0xba, 0x1d,
+                0xed, 0x1a, 0x28, 0x65, 0x94
+            ]
+        )
+    ];
+
+    for &(key, nonce, aad, msg, expected) in &vectors {
+        let mut k = [0; 32];
+        k.copy_from_slice(key);
+
+        let (ciphertext, tag) = GcmSiv::<Aes256>::new(&k, nonce, aad).unwrap().encrypt(msg).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(msg.len());
+        assert_eq!(GcmSiv::<Aes256>::new(&k, nonce, aad).unwrap().decrypt(ciphertext, tag).unwrap(), msg);
+    }
+}
+
+/// RFC 8452 C.3, the counter wraps from `0xffffffff` to zero without carrying into the rest of the block.
+#[test]
+fn test_gcm_siv_counter_wrap() {
+    let vectors: [(&[u8], &[u8], &[u8], &[u8], &[u8]); 2] = [
+        (
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x4d, 0xb9, 0x23, 0xdc, 0x79, 0x3e, 0xe6, 0x49, 0x7c, 0x76, 0xdc, 0xc0, 0x3a, 0x98, 0xe1, 0x08
+            ],
+            &[
+                0xf3, 0xf8, 0x0f, 0x2c, 0xf0, 0xcb, 0x2d, 0xd9, 0xc5, 0x98, 0x4f, 0xcd, 0xa9, 0x08, 0x45, 0x6c,
+                0xc5, 0x37, 0x70, 0x3b, 0x5b, 0xa7, 0x03, 0x24, 0xa6, 0x79, 0x3a, 0x7b, 0xf2, 0x18, 0xd3, 0xea,
+                0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        ),
+        (
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[],
+            &[
+                0xeb, 0x36, 0x40, 0x27, 0x7c, 0x7f, 0xfd, 0x13, 0x03, 0xc7, 0xa5, 0x42, 0xd0, 0x2d, 0x3e, 0x4c,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x18, 0xce, 0x4f, 0x0b, 0x8c, 0xb4, 0xd0, 0xca, 0xc6, 0x5f, 0xea, 0x8f, 0x79, 0x25, 0x7b, 0x20,
+                0x88, 0x8e, 0x53, 0xe7, 0x22, 0x99, 0xe5, 0x6d, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        )
+    ];
+
+    for &(key, nonce, aad, msg, expected) in &vectors {
+        let mut k = [0; 32];
+        k.copy_from_slice(key);
+
+        let (ciphertext, tag) = GcmSiv::<Aes256>::new(&k, nonce, aad).unwrap().encrypt(msg).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(msg.len());
+        assert_eq!(GcmSiv::<Aes256>::new(&k, nonce, aad).unwrap().decrypt(ciphertext, tag).unwrap(), msg);
+    }
+}
+
+#[test]
+fn test_gcm_siv_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let nonce = rand!(12);
+    let aad = rand!(rand!(choose 0..40));
+    let plaintext = rand!(rand!(choose 0..65));
+
+    let mut cipher = GcmSiv::<Aes128>::new(&key, &nonce, &aad).unwrap();
+    let (ciphertext, tag) = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).unwrap(), (ciphertext.clone(), tag.clone()));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag), Ok(plaintext.clone()));
+
+    let mut bad_tag = tag.clone();
+    bad_tag[rand!(choose 0..16)] ^= 1;
+    assert_eq!(cipher.decrypt(&ciphertext, &bad_tag), Err(DecryptFail::Auth));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag[..12]), Err(DecryptFail::Auth));
+    assert_eq!(
+        GcmSiv::<Aes128>::new(&key, &nonce, &[&aad[..], &[0]].concat()).unwrap().decrypt(&ciphertext, &tag),
+        Err(DecryptFail::Auth)
+    );
+}
+
+#[test]
+fn test_gcm_siv_bad_lengths() {
+    use aes::Aes192;
+
+    let key: [u8; 16] = rand!(_);
+    assert_eq!(GcmSiv::<Aes128>::new(&key, &rand!(16), &[]).err(), Some(DecryptFail::InvalidNonceLength));
+
+    let key: [u8; 24] = rand!(_);
+    assert_eq!(GcmSiv::<Aes192>::new(&key, &rand!(12), &[]).err(), Some(DecryptFail::InvalidKeyLength));
+}
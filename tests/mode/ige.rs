@@ -0,0 +1,83 @@
+use aes::Aes128;
+use aes::mode::Ige;
+use aes::utils::padding::{ NoPadding, Pkcs7Padding };
+use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+
+
+/// OpenSSL's `igetest.c`, key, IV, plaintext and ciphertext.
+#[test]
+fn test_ige_test() {
+    let vectors: [([u8; 16], &[u8], &[u8], &[u8]); 2] = [
+        (
+            [
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f
+            ],
+            &[
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+                0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f
+            ],
+            &[
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ],
+            &[
+                0x1a, 0x85, 0x19, 0xa6, 0x55, 0x7b, 0xe6, 0x52, 0xe9, 0xda, 0x8e, 0x43, 0xda, 0x4e, 0xf4, 0x45,
+                0x3c, 0xf4, 0x56, 0xb4, 0xca, 0x48, 0x8a, 0xa3, 0x83, 0xc7, 0x9c, 0x98, 0xb3, 0x47, 0x97, 0xcb
+            ]
+        ),
+        (
+            [
+                0x54, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65
+            ],
+            &[
+                0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x49, 0x47, 0x45,
+                0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x4f, 0x70, 0x65, 0x6e, 0x53, 0x53
+            ],
+            &[
+                0x99, 0x70, 0x64, 0x87, 0xa1, 0xcd, 0xe6, 0x13, 0xbc, 0x6d, 0xe0, 0xb6, 0xf2, 0x4b, 0x1c, 0x7a,
+                0xa4, 0x48, 0xc8, 0xb9, 0xc3, 0x40, 0x3e, 0x34, 0x67, 0xa8, 0xca, 0xd8, 0x93, 0x40, 0xf5, 0x3b
+            ],
+            &[
+                0x4c, 0x2e, 0x20, 0x4c, 0x65, 0x74, 0x27, 0x73, 0x20, 0x68, 0x6f, 0x70, 0x65, 0x20, 0x42, 0x65,
+                0x6e, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x69, 0x74, 0x20, 0x72, 0x69, 0x67, 0x68, 0x74, 0x21, 0x0a
+            ]
+        )
+    ];
+
+    for &(ref key, iv, plaintext, ciphertext) in &vectors {
+        assert_eq!(Ige::<Aes128>::new(key, iv).unwrap().encrypt::<NoPadding>(plaintext).unwrap(), ciphertext);
+        assert_eq!(Ige::<Aes128>::new(key, iv).unwrap().decrypt::<NoPadding>(ciphertext).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_ige_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(32);
+    let plaintext = rand!(rand!(choose 15..65));
+
+    let mut cipher = Ige::<Aes128>::new(&key, &iv).unwrap();
+    let ciphertext = cipher.encrypt::<Pkcs7Padding>(&plaintext).unwrap();
+    let next = cipher.encrypt::<Pkcs7Padding>(&plaintext).unwrap();
+
+    let mut cipher = Ige::<Aes128>::new(&key, &iv).unwrap();
+    assert_eq!(cipher.decrypt::<Pkcs7Padding>(&ciphertext).unwrap(), plaintext);
+    assert_eq!(cipher.decrypt::<Pkcs7Padding>(&next).unwrap(), plaintext);
+}
+
+#[test]
+fn test_ige_bad_iv() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Ige::<Aes128>::new(&key, &rand!(16)).err(), Some(DecryptFail::InvalidIvLength));
+    assert_eq!(
+        Ige::<Aes128>::new(&key, &rand!(32)).unwrap().set_iv(&rand!(31)).err(),
+        Some(DecryptFail::InvalidIvLength)
+    );
+    assert_eq!(
+        Ige::<Aes128>::new(&key, &rand!(32)).unwrap().decrypt::<NoPadding>(&rand!(17)).err(),
+        Some(DecryptFail::MisalignedInput)
+    );
+}
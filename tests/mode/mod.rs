@@ -1,6 +1,16 @@
 mod ecb;
 mod cbc;
+mod cbc_cts;
+mod pcbc;
 mod ctr;
+mod cfb;
+mod ofb;
+mod ige;
 mod xex;
 mod xts;
 mod gcm;
+mod gcm_siv;
+mod ccm;
+mod eax;
+mod ocb;
+mod siv;
@@ -0,0 +1,193 @@
+use aes::Aes128;
+use aes::mode::Ocb3;
+use aes::cipher::{
+    DecryptFail,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// `0x00, 0x01, ..`, the associated data and plaintext of every sample are a prefix of it.
+const DATA: [u8; 40] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27
+];
+
+fn nonce(n: u64) -> Vec<u8> {
+    (0..12).rev().map(|i| if i < 8 { (n >> (8 * i)) as u8 } else { 0 }).collect()
+}
+
+/// RFC 7253 Appendix A sample results: last nonce byte, associated data length,
+/// plaintext length, ciphertext followed by the tag.
+#[test]
+fn test_ocb3_test() {
+    let mut key = [0; 16];
+    key.copy_from_slice(&DATA[..16]);
+    let vectors: [(u8, usize, usize, &[u8]); 16] = [
+        (0x00, 0, 0, &[
+            0x78, 0x54, 0x07, 0xbf, 0xff, 0xc8, 0xad, 0x9e, 0xdc, 0xc5, 0x52, 0x0a, 0xc9, 0x11, 0x1e, 0xe6
+        ]),
+        (0x01, 8, 8, &[
+            0x68, 0x20, 0xb3, 0x65, 0x7b, 0x6f, 0x61, 0x5a, 0x57, 0x25, 0xbd, 0xa0, 0xd3, 0xb4, 0xeb, 0x3a,
+            0x25, 0x7c, 0x9a, 0xf1, 0xf8, 0xf0, 0x30, 0x09
+        ]),
+        (0x02, 8, 0, &[
+            0x81, 0x01, 0x7f, 0x82, 0x03, 0xf0, 0x81, 0x27, 0x71, 0x52, 0xfa, 0xde, 0x69, 0x4a, 0x0a, 0x00
+        ]),
+        (0x03, 0, 8, &[
+            0x45, 0xdd, 0x69, 0xf8, 0xf5, 0xaa, 0xe7, 0x24, 0x14, 0x05, 0x4c, 0xd1, 0xf3, 0x5d, 0x82, 0x76,
+            0x0b, 0x2c, 0xd0, 0x0d, 0x2f, 0x99, 0xbf, 0xa9
+        ]),
+        (0x04, 16, 16, &[
+            0x57, 0x1d, 0x53, 0x5b, 0x60, 0xb2, 0x77, 0x18, 0x8b, 0xe5, 0x14, 0x71, 0x70, 0xa9, 0xa2, 0x2c,
+            0x3a, 0xd7, 0xa4, 0xff, 0x38, 0x35, 0xb8, 0xc5, 0x70, 0x1c, 0x1c, 0xce, 0xc8, 0xfc, 0x33, 0x58
+        ]),
+        (0x05, 16, 0, &[
+            0x8c, 0xf7, 0x61, 0xb6, 0x90, 0x2e, 0xf7, 0x64, 0x46, 0x2a, 0xd8, 0x64, 0x98, 0xca, 0x6b, 0x97
+        ]),
+        (0x06, 0, 16, &[
+            0x5c, 0xe8, 0x8e, 0xc2, 0xe0, 0x69, 0x27, 0x06, 0xa9, 0x15, 0xc0, 0x0a, 0xeb, 0x8b, 0x23, 0x96,
+            0xf4, 0x0e, 0x1c, 0x74, 0x3f, 0x52, 0x43, 0x6b, 0xdf, 0x06, 0xd8, 0xfa, 0x1e, 0xca, 0x34, 0x3d
+        ]),
+        (0x07, 24, 24, &[
+            0x1c, 0xa2, 0x20, 0x73, 0x08, 0xc8, 0x7c, 0x01, 0x07, 0x56, 0x10, 0x4d, 0x88, 0x40, 0xce, 0x19,
+            0x52, 0xf0, 0x96, 0x73, 0xa4, 0x48, 0xa1, 0x22, 0xc9, 0x2c, 0x62, 0x24, 0x10, 0x51, 0xf5, 0x73,
+            0x56, 0xd7, 0xf3, 0xc9, 0x0b, 0xb0, 0xe0, 0x7f
+        ]),
+        (0x08, 24, 0, &[
+            0x6d, 0xc2, 0x25, 0xa0, 0x71, 0xfc, 0x1b, 0x9f, 0x7c, 0x69, 0xf9, 0x3b, 0x0f, 0x1e, 0x10, 0xde
+        ]),
+        (0x09, 0, 24, &[
+            0x22, 0x1b, 0xd0, 0xde, 0x7f, 0xa6, 0xfe, 0x99, 0x3e, 0xcc, 0xd7, 0x69, 0x46, 0x0a, 0x0a, 0xf2,
+            0xd6, 0xcd, 0xed, 0x0c, 0x39, 0x5b, 0x1c, 0x3c, 0xe7, 0x25, 0xf3, 0x24, 0x94, 0xb9, 0xf9, 0x14,
+            0xd8, 0x5c, 0x0b, 0x1e, 0xb3, 0x83, 0x57, 0xff
+        ]),
+        (0x0a, 32, 32, &[
+            0xbd, 0x6f, 0x6c, 0x49, 0x62, 0x01, 0xc6, 0x92, 0x96, 0xc1, 0x1e, 0xfd, 0x13, 0x8a, 0x46, 0x7a,
+            0xbd, 0x3c, 0x70, 0x79, 0x24, 0xb9, 0x64, 0xde, 0xaf, 0xfc, 0x40, 0x31, 0x9a, 0xf5, 0xa4, 0x85,
+            0x40, 0xfb, 0xba, 0x18, 0x6c, 0x55, 0x53, 0xc6, 0x8a, 0xd9, 0xf5, 0x92, 0xa7, 0x9a, 0x42, 0x40
+        ]),
+        (0x0b, 32, 0, &[
+            0xfe, 0x80, 0x69, 0x0b, 0xee, 0x8a, 0x48, 0x5d, 0x11, 0xf3, 0x29, 0x65, 0xbc, 0x9d, 0x2a, 0x32
+        ]),
+        (0x0c, 0, 32, &[
+            0x29, 0x42, 0xbf, 0xc7, 0x73, 0xbd, 0xa2, 0x3c, 0xab, 0xc6, 0xac, 0xfd, 0x9b, 0xfd, 0x58, 0x35,
+            0xbd, 0x30, 0x0f, 0x09, 0x73, 0x79, 0x2e, 0xf4, 0x60, 0x40, 0xc5, 0x3f, 0x14, 0x32, 0xbc, 0xdf,
+            0xb5, 0xe1, 0xdd, 0xe3, 0xbc, 0x18, 0xa5, 0xf8, 0x40, 0xb5, 0x2e, 0x65, 0x34, 0x44, 0xd5, 0xdf
+        ]),
+        (0x0d, 40, 40, &[
+            0xd5, 0xca, 0x91, 0x74, 0x84, 0x10, 0xc1, 0x75, 0x1f, 0xf8, 0xa2, 0xf6, 0x18, 0x25, 0x5b, 0x68,
+            0xa0, 0xa1, 0x2e, 0x09, 0x3f, 0xf4, 0x54, 0x60, 0x6e, 0x59, 0xf9, 0xc1, 0xd0, 0xdd, 0xc5, 0x4b,
+            0x65, 0xe8, 0x62, 0x8e, 0x56, 0x8b, 0xad, 0x7a, 0xed, 0x07, 0xba, 0x06, 0xa4, 0xa6, 0x94, 0x83,
+            0xa7, 0x03, 0x54, 0x90, 0xc5, 0x76, 0x9e, 0x60
+        ]),
+        (0x0e, 40, 0, &[
+            0xc5, 0xcd, 0x9d, 0x18, 0x50, 0xc1, 0x41, 0xe3, 0x58, 0x64, 0x99, 0x94, 0xee, 0x70, 0x1b, 0x68
+        ]),
+        (0x0f, 0, 40, &[
+            0x44, 0x12, 0x92, 0x34, 0x93, 0xc5, 0x7d, 0x5d, 0xe0, 0xd7, 0x00, 0xf7, 0x53, 0xcc, 0xe0, 0xd1,
+            0xd2, 0xd9, 0x50, 0x60, 0x12, 0x2e, 0x9f, 0x15, 0xa5, 0xdd, 0xbf, 0xc5, 0x78, 0x7e, 0x50, 0xb5,
+            0xcc, 0x55, 0xee, 0x50, 0x7b, 0xcb, 0x08, 0x4e, 0x47, 0x9a, 0xd3, 0x63, 0xac, 0x36, 0x6b, 0x95,
+            0xa9, 0x8c, 0xa5, 0xf3, 0x00, 0x0b, 0x14, 0x79
+        ])
+    ];
+
+    for &(n, aad_len, msg_len, expected) in &vectors {
+        let nonce = [0xbb, 0xaa, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, n];
+        let (aad, msg) = (&DATA[..aad_len], &DATA[..msg_len]);
+
+        let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce, aad, 16).unwrap().encrypt(msg).unwrap();
+        assert_eq!([ciphertext, tag].concat(), expected);
+
+        let (ciphertext, tag) = expected.split_at(msg_len);
+        assert_eq!(Ocb3::<Aes128>::new(&key, &nonce, aad, 16).unwrap().decrypt(ciphertext, tag).unwrap(), msg);
+    }
+}
+
+/// RFC 7253 Appendix A, the sample with a 96-bit tag.
+#[test]
+fn test_ocb3_taglen96() {
+    let mut key = [0; 16];
+    for (i, k) in key.iter_mut().enumerate() {
+        *k = 15 - i as u8;
+    }
+    let nonce = [0xbb, 0xaa, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x0d];
+    let expected: &[u8] = &[
+        0x17, 0x92, 0xa4, 0xe3, 0x1e, 0x07, 0x55, 0xfb, 0x03, 0xe3, 0x1b, 0x22, 0x11, 0x6e, 0x6c, 0x2d,
+        0xdf, 0x9e, 0xfd, 0x6e, 0x33, 0xd5, 0x36, 0xf1, 0xa0, 0x12, 0x4b, 0x0a, 0x55, 0xba, 0xe8, 0x84,
+        0xed, 0x93, 0x48, 0x15, 0x29, 0xc7, 0x6b, 0x6a, 0xd0, 0xc5, 0x15, 0xf4, 0xd1, 0xcd, 0xd4, 0xfd,
+        0xac, 0x4f, 0x02, 0xaa
+    ];
+
+    let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce, &DATA, 12).unwrap().encrypt(&DATA).unwrap();
+    assert_eq!(tag.len(), 12);
+    assert_eq!([ciphertext, tag].concat(), expected);
+}
+
+/// RFC 7253 Appendix A, the iterated test for each tag length.
+#[test]
+fn test_ocb3_iterated() {
+    let vectors: [(usize, &[u8]); 3] = [
+        (16, &[0x67, 0xe9, 0x44, 0xd2, 0x32, 0x56, 0xc5, 0xe0, 0xb6, 0xc6, 0x1f, 0xa2, 0x2f, 0xdf, 0x1e, 0xa2]),
+        (12, &[0x77, 0xa3, 0xd8, 0xe7, 0x35, 0x89, 0x15, 0x8d, 0x25, 0xd0, 0x12, 0x09]),
+        (8, &[0x19, 0x2c, 0x9b, 0x7b, 0xd9, 0x0b, 0xa0, 0x6a])
+    ];
+
+    for &(tag_len, expected) in &vectors {
+        let mut key = [0; 16];
+        key[15] = 8 * tag_len as u8;
+
+        let mut c = Vec::new();
+        for i in 0..128 {
+            let s = vec![0; i];
+            let n = 3 * i as u64;
+            let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce(n + 1), &s, tag_len).unwrap().encrypt(&s).unwrap();
+            c.extend(ciphertext.into_iter().chain(tag));
+            let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce(n + 2), &[], tag_len).unwrap().encrypt(&s).unwrap();
+            c.extend(ciphertext.into_iter().chain(tag));
+            let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce(n + 3), &s, tag_len).unwrap().encrypt(&[]).unwrap();
+            c.extend(ciphertext.into_iter().chain(tag));
+        }
+
+        let (ciphertext, tag) = Ocb3::<Aes128>::new(&key, &nonce(385), &c, tag_len).unwrap().encrypt(&[]).unwrap();
+        assert!(ciphertext.is_empty());
+        assert_eq!(tag, expected);
+    }
+}
+
+#[test]
+fn test_ocb3_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let nonce = rand!(rand!(choose 1..16));
+    let aad = rand!(rand!(choose 0..40));
+    let plaintext = rand!(rand!(choose 0..300));
+
+    let mut cipher = Ocb3::<Aes128>::new(&key, &nonce, &aad, 16).unwrap();
+    let (ciphertext, tag) = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).err(), Some(DecryptFail::NonceReuse));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag), Ok(plaintext.clone()));
+
+    let mut bad_tag = tag.clone();
+    bad_tag[rand!(choose 0..16)] ^= 1;
+    assert_eq!(cipher.decrypt(&ciphertext, &bad_tag), Err(DecryptFail::Auth));
+    assert_eq!(cipher.decrypt(&ciphertext, &tag[..12]), Err(DecryptFail::Auth));
+    assert_eq!(
+        Ocb3::<Aes128>::new(&key, &nonce, &aad, 8).unwrap().decrypt(&ciphertext, &tag[..8]),
+        Err(DecryptFail::Auth)
+    );
+    if !ciphertext.is_empty() {
+        let mut bad_ciphertext = ciphertext.clone();
+        bad_ciphertext[0] ^= 1;
+        assert_eq!(cipher.decrypt(&bad_ciphertext, &tag), Err(DecryptFail::Auth));
+    }
+}
+
+#[test]
+fn test_ocb3_bad_lengths() {
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Ocb3::<Aes128>::new(&key, &[], &[], 16).err(), Some(DecryptFail::InvalidNonceLength));
+    assert_eq!(Ocb3::<Aes128>::new(&key, &rand!(16), &[], 16).err(), Some(DecryptFail::InvalidNonceLength));
+    assert_eq!(Ocb3::<Aes128>::new(&key, &rand!(12), &[], 10).err(), Some(DecryptFail::InvalidTagLength));
+    assert_eq!(Ocb3::<Aes128>::new(&key, &rand!(12), &[], 4).err(), Some(DecryptFail::InvalidTagLength));
+}
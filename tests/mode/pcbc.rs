@@ -0,0 +1,81 @@
+use aes::Aes128;
+use aes::mode::{ Pcbc, Cbc };
+use aes::utils::padding::{ NoPadding, Pkcs7Padding };
+use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+
+
+/// The NIST SP 800-38A CBC example run through PCBC, the first block agrees with F.2.1.
+#[test]
+fn test_pcbc_test() {
+    let key = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
+    ];
+    let iv = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f
+    ];
+    let plaintext = [
+        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10
+    ];
+    let ciphertext = [
+        0x76, 0x49, 0xab, 0xac, 0x81, 0x19, 0xb2, 0x46, 0xce, 0xe9, 0x8e, 0x9b, 0x12, 0xe9, 0x19, 0x7d,
+        0x9e, 0x8b, 0xaf, 0xf1, 0x2a, 0xd5, 0x27, 0x0a, 0x0d, 0x1e, 0xef, 0x93, 0xd7, 0x03, 0x79, 0x94,
+        0x57, 0x00, 0xb3, 0x98, 0x03, 0x77, 0x9f, 0xa3, 0x5a, 0x3c, 0x60, 0x0a, 0x49, 0xa1, 0x63, 0xc0,
+        0x33, 0xae, 0x19, 0x9f, 0x27, 0x37, 0x9f, 0x21, 0xbe, 0x6d, 0xd5, 0x7d, 0x29, 0x5c, 0xc8, 0x7d
+    ];
+
+    assert_eq!(Pcbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<NoPadding>(&plaintext).unwrap(), &ciphertext[..]);
+    assert_eq!(Pcbc::<Aes128>::new(&key, &iv).unwrap().decrypt::<NoPadding>(&ciphertext).unwrap(), &plaintext[..]);
+}
+
+#[test]
+fn test_pcbc_decrypt() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(rand!(choose 15..65));
+
+    let ciphertext = Pcbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap();
+    assert_eq!(
+        &ciphertext[..16],
+        &Cbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap()[..16]
+    );
+    assert_eq!(
+        Pcbc::<Aes128>::new(&key, &iv).unwrap().decrypt::<Pkcs7Padding>(&ciphertext).unwrap(),
+        plaintext
+    );
+
+    let mut cipher = Pcbc::<Aes128>::new(&key, &rand!(16)).unwrap();
+    cipher.set_iv(&iv).unwrap();
+    assert_eq!(cipher.decrypt::<Pkcs7Padding>(&ciphertext).unwrap(), plaintext);
+}
+
+/// An error in one ciphertext block garbles every plaintext block after it.
+#[test]
+fn test_pcbc_propagation() {
+    let key: [u8; 16] = rand!(_);
+    let iv = rand!(16);
+    let plaintext = rand!(64);
+
+    let mut ciphertext = Pcbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<NoPadding>(&plaintext).unwrap();
+    ciphertext[20] ^= 1;
+    let garbled = Pcbc::<Aes128>::new(&key, &iv).unwrap().decrypt::<NoPadding>(&ciphertext).unwrap();
+    assert_eq!(&garbled[..16], &plaintext[..16]);
+    for (g, p) in garbled[16..].chunks(16).zip(plaintext[16..].chunks(16)) {
+        assert!(g != p);
+    }
+}
+
+#[test]
+fn test_pcbc_bad_iv() {
+    use aes::cipher::DecryptFail;
+
+    let key: [u8; 16] = rand!(_);
+
+    assert_eq!(Pcbc::<Aes128>::new(&key, &rand!(15)).err(), Some(DecryptFail::InvalidIvLength));
+    assert_eq!(
+        Pcbc::<Aes128>::new(&key, &rand!(16)).unwrap().set_iv(&rand!(17)).err(),
+        Some(DecryptFail::InvalidIvLength)
+    );
+}
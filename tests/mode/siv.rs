@@ -0,0 +1,145 @@
+use aes::{ Aes128, Aes256 };
+use aes::mode::Siv;
+use aes::cipher::{
+    DecryptFail,
+    AeadStreamEncrypt, AeadStreamDecrypt
+};
+
+
+/// RFC 5297 A.1, deterministic authenticated encryption.
+const A1_KEY: [u8; 32] = [
+    0xff, 0xfe, 0xfd, 0xfc, 0xfb, 0xfa, 0xf9, 0xf8, 0xf7, 0xf6, 0xf5, 0xf4, 0xf3, 0xf2, 0xf1, 0xf0,
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff
+];
+const A1_AD: [u8; 24] = [
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27
+];
+const A1_PLAINTEXT: [u8; 14] = [
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee
+];
+
+/// RFC 5297 A.2, nonce-based authenticated encryption.
+const A2_KEY: [u8; 32] = [
+    0x7f, 0x7e, 0x7d, 0x7c, 0x7b, 0x7a, 0x79, 0x78, 0x77, 0x76, 0x75, 0x74, 0x73, 0x72, 0x71, 0x70,
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f
+];
+const A2_AD1: [u8; 40] = [
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    0xde, 0xad, 0xda, 0xda, 0xde, 0xad, 0xda, 0xda, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+    0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00
+];
+const A2_AD2: [u8; 10] = [
+    0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80, 0x90, 0xa0
+];
+const A2_NONCE: [u8; 16] = [
+    0x09, 0xf9, 0x11, 0x02, 0x9d, 0x74, 0xe3, 0x5b, 0xd8, 0x41, 0x56, 0xc5, 0x63, 0x56, 0x88, 0xc0
+];
+const A2_PLAINTEXT: [u8; 47] = [
+    0x74, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x73, 0x6f, 0x6d, 0x65, 0x20, 0x70, 0x6c, 0x61,
+    0x69, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70,
+    0x74, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x53, 0x49, 0x56, 0x2d, 0x41, 0x45, 0x53
+];
+
+/// AES-SIV-CMAC-256, RFC 5297 Appendix A: the synthetic IV followed by the ciphertext.
+#[test]
+fn test_siv_cmac_256() {
+    let (mut key1, mut key2) = ([0; 16], [0; 16]);
+    key1.copy_from_slice(&A1_KEY[..16]);
+    key2.copy_from_slice(&A1_KEY[16..]);
+    let expected = [
+        0x85, 0x63, 0x2d, 0x07, 0xc6, 0xe8, 0xf3, 0x7f, 0x95, 0x0a, 0xcd, 0x32, 0x0a, 0x2e, 0xcc, 0x93,
+        0x40, 0xc0, 0x2b, 0x96, 0x90, 0xc4, 0xdc, 0x04, 0xda, 0xef, 0x7f, 0x6a, 0xfe, 0x5c
+    ];
+
+    let (ciphertext, v) = Siv::<Aes128>::new(&key1, &key2, &[&A1_AD]).unwrap().encrypt(&A1_PLAINTEXT).unwrap();
+    assert_eq!([v, ciphertext].concat(), &expected[..]);
+    let (v, ciphertext) = expected.split_at(16);
+    assert_eq!(Siv::<Aes128>::new(&key1, &key2, &[&A1_AD]).unwrap().decrypt(ciphertext, v).unwrap(), A1_PLAINTEXT);
+
+    key1.copy_from_slice(&A2_KEY[..16]);
+    key2.copy_from_slice(&A2_KEY[16..]);
+    let aad: [&[u8]; 3] = [&A2_AD1, &A2_AD2, &A2_NONCE];
+    let expected = [
+        0x7b, 0xdb, 0x6e, 0x3b, 0x43, 0x26, 0x67, 0xeb, 0x06, 0xf4, 0xd1, 0x4b, 0xff, 0x2f, 0xbd, 0x0f,
+        0xcb, 0x90, 0x0f, 0x2f, 0xdd, 0xbe, 0x40, 0x43, 0x26, 0x60, 0x19, 0x65, 0xc8, 0x89, 0xbf, 0x17,
+        0xdb, 0xa7, 0x7c, 0xeb, 0x09, 0x4f, 0xa6, 0x63, 0xb7, 0xa3, 0xf7, 0x48, 0xba, 0x8a, 0xf8, 0x29,
+        0xea, 0x64, 0xad, 0x54, 0x4a, 0x27, 0x2e, 0x9c, 0x48, 0x5b, 0x62, 0xa3, 0xfd, 0x5c, 0x0d
+    ];
+
+    let (ciphertext, v) = Siv::<Aes128>::new(&key1, &key2, &aad).unwrap().encrypt(&A2_PLAINTEXT).unwrap();
+    assert_eq!([v, ciphertext].concat(), &expected[..]);
+    let (v, ciphertext) = expected.split_at(16);
+    assert_eq!(Siv::<Aes128>::new(&key1, &key2, &aad).unwrap().decrypt(ciphertext, v).unwrap(), &A2_PLAINTEXT[..]);
+}
+
+/// AES-SIV-CMAC-512. RFC 5297 only publishes results for the 256-bit key, these are
+/// its Appendix A inputs under the A.1 key followed by the A.2 key, checked against OpenSSL.
+#[test]
+fn test_siv_cmac_512() {
+    let (mut key1, mut key2) = ([0; 32], [0; 32]);
+    key1.copy_from_slice(&A1_KEY);
+    key2.copy_from_slice(&A2_KEY);
+
+    let expected = [
+        0x72, 0x4d, 0xfb, 0x2e, 0xaf, 0x94, 0xdb, 0xb1, 0x9b, 0x0b, 0xa3, 0xa2, 0x99, 0xa0, 0x80, 0x1e,
+        0x0b, 0x62, 0x52, 0x26, 0x88, 0x8a, 0xaa, 0x54, 0x7f, 0x6e, 0xcb, 0xdd, 0x40, 0x5f
+    ];
+    let (ciphertext, v) = Siv::<Aes256>::new(&key1, &key2, &[&A1_AD]).unwrap().encrypt(&A1_PLAINTEXT).unwrap();
+    assert_eq!([v, ciphertext].concat(), &expected[..]);
+
+    let aad: [&[u8]; 3] = [&A2_AD1, &A2_AD2, &A2_NONCE];
+    let expected = [
+        0x81, 0x6e, 0xee, 0x7a, 0x1c, 0x5d, 0x67, 0x71, 0x18, 0xbc, 0xf0, 0x4f, 0x3b, 0xc0, 0xb8, 0xd9,
+        0x30, 0x0f, 0x31, 0xb7, 0xcd, 0x9e, 0xab, 0x43, 0xad, 0xa1, 0x32, 0x1e, 0x32, 0x14, 0xff, 0x11,
+        0x7c, 0x9a, 0x37, 0x44, 0x51, 0x8c, 0x04, 0x74, 0xbe, 0xdd, 0x4b, 0xd9, 0x23, 0x09, 0x0f, 0x60,
+        0x08, 0x00, 0xee, 0xeb, 0xa0, 0xcc, 0x10, 0x1c, 0x1b, 0x4e, 0x9c, 0xc7, 0x76, 0xc9, 0xf1
+    ];
+    let (ciphertext, v) = Siv::<Aes256>::new(&key1, &key2, &aad).unwrap().encrypt(&A2_PLAINTEXT).unwrap();
+    assert_eq!([v, ciphertext].concat(), &expected[..]);
+    let (v, ciphertext) = expected.split_at(16);
+    assert_eq!(Siv::<Aes256>::new(&key1, &key2, &aad).unwrap().decrypt(ciphertext, v).unwrap(), &A2_PLAINTEXT[..]);
+
+    assert_eq!(
+        Siv::<Aes256>::new(&key1, &key2, &[]).unwrap().encrypt(&[]).unwrap(),
+        (Vec::new(), vec![
+            0x99, 0xc6, 0x6f, 0x27, 0x24, 0x6f, 0xa9, 0x73, 0x0d, 0xcf, 0x37, 0x0b, 0xe0, 0x3c, 0xa7, 0xa3
+        ])
+    );
+}
+
+#[test]
+fn test_siv_decrypt() {
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
+    let ad = rand!(rand!(choose 0..40));
+    let nonce = rand!(16);
+    let plaintext = rand!(rand!(choose 0..65));
+
+    let mut cipher = Siv::<Aes128>::new(&key1, &key2, &[&ad, &nonce]).unwrap();
+    let (ciphertext, v) = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).unwrap(), (ciphertext.clone(), v.clone()));
+    assert_eq!(cipher.decrypt(&ciphertext, &v), Ok(plaintext.clone()));
+
+    let mut bad_v = v.clone();
+    bad_v[rand!(choose 0..16)] ^= 1;
+    assert_eq!(cipher.decrypt(&ciphertext, &bad_v), Err(DecryptFail::Auth));
+    assert_eq!(cipher.decrypt(&ciphertext, &v[..8]), Err(DecryptFail::Auth));
+    assert_eq!(
+        Siv::<Aes128>::new(&key1, &key2, &[&nonce, &ad]).unwrap().decrypt(&ciphertext, &v),
+        Err(DecryptFail::Auth)
+    );
+    assert_eq!(
+        Siv::<Aes128>::new(&key1, &key2, &[&[&ad[..], &nonce[..]].concat()]).unwrap().decrypt(&ciphertext, &v),
+        Err(DecryptFail::Auth)
+    );
+}
+
+#[test]
+fn test_siv_too_many_components() {
+    let key: [u8; 16] = rand!(_);
+    let aad: Vec<&[u8]> = vec![&[]; 127];
+
+    assert_eq!(Siv::<Aes128>::new(&key, &key, &aad).err(), Some(DecryptFail::LongInput));
+    assert!(Siv::<Aes128>::new(&key, &key, &aad[..126]).is_ok());
+}
@@ -1,19 +1,22 @@
-use aes::AES;
+use aes::Aes128;
 use aes::mode::Xex;
-use aes::utils::padding::Pkcs7Padding;
-use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+use aes::utils::padding::{ Pkcs7Padding, NoPadding };
+use aes::cipher::{
+    BlockEncrypt, BlockDecrypt,
+    InPlaceBlockEncrypt, InPlaceBlockDecrypt
+};
 
 
 #[test]
 fn test_xex_decrypt() {
-    let key1 = rand!(16);
-    let key2 = rand!(16);
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
     let i = rand!(16);
     let plaintext = rand!(rand!(choose 15..65));
 
     assert_eq!(
-        Xex::new(&key1, &key2, &i).decrypt::<Pkcs7Padding>(
-            &Xex::new(&key1, &key2, &i).encrypt::<Pkcs7Padding>(&plaintext)
+        Xex::<Aes128>::new(&key1, &key2, &i).unwrap().decrypt::<Pkcs7Padding>(
+            &Xex::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap()
         ),
         Ok(plaintext)
     );
@@ -22,11 +25,25 @@ fn test_xex_decrypt() {
 #[test]
 fn test_xex_next_tweak() {
     assert_eq!(
-        Xex::<AES>::next_tweak(&[0; 16]),
+        Xex::<Aes128>::next_tweak(&[0; 16]),
         [0; 16]
     );
     assert_eq!(
-        Xex::<AES>::next_tweak(&[[1, 2, 3, 4]; 4].concat()),
+        Xex::<Aes128>::next_tweak(&[[1, 2, 3, 4]; 4].concat()),
         [[2, 4, 6, 8]; 4].concat()
     );
 }
+
+#[test]
+fn test_xex_in_place() {
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
+    let i = rand!(16);
+    let plaintext = rand!(16 * rand!(choose 1..5));
+
+    let mut data = plaintext.clone();
+    Xex::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, Xex::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt::<NoPadding>(&plaintext).unwrap());
+    Xex::<Aes128>::new(&key1, &key2, &i).unwrap().decrypt_in_place(&mut data).unwrap();
+    assert_eq!(data, plaintext);
+}
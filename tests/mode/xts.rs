@@ -1,16 +1,17 @@
 use openssl::crypto::symm::{ encrypt, Type };
+use aes::Aes128;
 use aes::mode::Xts;
 use aes::cipher::{ CtsBlockEncrypt, CtsBlockDecrypt };
 
 
 #[test]
 fn test_xts_encrypt() {
-    let key1 = rand!(16);
-    let key2 = rand!(16);
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
     let i = rand!(16);
     let plaintext = rand!(rand!(choose 16..65));
 
-    let ciphertext = Xts::new(&key1, &key2, &i).encrypt(&plaintext);
+    let ciphertext = Xts::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt(&plaintext).unwrap();
     assert_eq!(ciphertext.len(), plaintext.len());
     assert_eq!(
         ciphertext,
@@ -20,25 +21,45 @@ fn test_xts_encrypt() {
 
 #[test]
 fn test_xts_decrypt() {
-    let key1 = rand!(16);
-    let key2 = rand!(16);
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
     let i = rand!(16);
     let plaintext = rand!(rand!(choose 16..65));
 
-    let ciphertext = Xts::new(&key1, &key2, &i).encrypt(&plaintext);
+    let ciphertext = Xts::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt(&plaintext).unwrap();
     assert_eq!(ciphertext.len(), plaintext.len());
     assert_eq!(
-        Xts::new(&key1, &key2, &i).decrypt(&ciphertext),
-        plaintext
+        Xts::<Aes128>::new(&key1, &key2, &i).unwrap().decrypt(&ciphertext),
+        Ok(plaintext.clone())
     );
 
     assert_eq!(
-        Xts::new(&key1, &key2, &i).decrypt(&encrypt(
+        Xts::<Aes128>::new(&key1, &key2, &i).unwrap().decrypt(&encrypt(
             Type::AES_128_XTS,
             &[key1, key2].concat(),
             &i,
             &plaintext
         )),
-        plaintext
+        Ok(plaintext)
     )
 }
+
+#[test]
+fn test_xts_short_input() {
+    use aes::cipher::DecryptFail;
+
+    let key1: [u8; 16] = rand!(_);
+    let key2: [u8; 16] = rand!(_);
+    let i = rand!(16);
+    let data = rand!(rand!(choose 0..16));
+
+    assert_eq!(Xts::<Aes128>::new(&key1, &key2, &i[1..]).err(), Some(DecryptFail::InvalidTweakLength));
+    assert_eq!(
+        Xts::<Aes128>::new(&key1, &key2, &i).unwrap().encrypt(&data),
+        Err(DecryptFail::ShortInput)
+    );
+    assert_eq!(
+        Xts::<Aes128>::new(&key1, &key2, &i).unwrap().decrypt(&data),
+        Err(DecryptFail::ShortInput)
+    );
+}
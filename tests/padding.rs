@@ -10,7 +10,7 @@ fn test_nopadding() {
     let plaintext = b"YELLOW SUBMARINE";
 
     assert_eq!(
-        NoPadding::padding(plaintext, 20),
+        NoPadding::padding(plaintext, 20).unwrap(),
         plaintext
     );
 
@@ -27,32 +27,32 @@ fn test_pkcs7padding() {
     let plaintext = b"YELLOW SUBMARINE";
 
     assert_eq!(
-        Pkcs7Padding::padding(plaintext, 20),
+        Pkcs7Padding::padding(plaintext, 20).unwrap(),
         b"YELLOW SUBMARINE\x04\x04\x04\x04"
     );
     assert_eq!(
-        Pkcs7Padding::padding(plaintext, 16),
+        Pkcs7Padding::padding(plaintext, 16).unwrap(),
         b"YELLOW SUBMARINE\
         \x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10"
     );
 
     assert_eq!(
         Pkcs7Padding::unpadding(
-            &Pkcs7Padding::padding(plaintext, 20),
+            &Pkcs7Padding::padding(plaintext, 20).unwrap(),
             20
         ).unwrap(),
         plaintext
     );
     assert_eq!(
         Pkcs7Padding::unpadding(
-            &Pkcs7Padding::padding(plaintext, 20),
+            &Pkcs7Padding::padding(plaintext, 20).unwrap(),
             16
         ),
         Err(PaddingError::BadData)
     );
     assert_eq!(
         Pkcs7Padding::unpadding(
-            &Pkcs7Padding::padding(plaintext, 16),
+            &Pkcs7Padding::padding(plaintext, 16).unwrap(),
             20
         ),
         Err(PaddingError::BadData)
@@ -61,4 +61,90 @@ fn test_pkcs7padding() {
         Pkcs7Padding::unpadding(plaintext, 16),
         Err(PaddingError::BadPadding)
     );
+
+    assert_eq!(Pkcs7Padding::padding(plaintext, 0), Err(PaddingError::Other));
+    assert_eq!(Pkcs7Padding::padding(plaintext, 256), Err(PaddingError::Other));
+    assert_eq!(Pkcs7Padding::unpadding(b"", 16), Err(PaddingError::BadData));
+    assert_eq!(Pkcs7Padding::unpadding(&[0; 16], 16), Err(PaddingError::BadPadding));
+}
+
+#[test]
+fn test_zeropadding() {
+    use aes::utils::padding::{ ZeroPadding, PaddingError };
+
+    let plaintext = b"YELLOW SUBMARINE";
+
+    assert_eq!(ZeroPadding::padding(plaintext, 16).unwrap(), plaintext);
+    assert_eq!(
+        ZeroPadding::padding(plaintext, 20).unwrap(),
+        [&plaintext[..], &[0; 4]].concat()
+    );
+    assert_eq!(ZeroPadding::padding(b"", 16).unwrap(), [0; 16]);
+
+    assert_eq!(ZeroPadding::padding(plaintext, 0), Err(PaddingError::Other));
+    assert_eq!(ZeroPadding::unpadding(plaintext, 16), Err(PaddingError::Other));
+}
+
+#[test]
+fn test_bitpadding() {
+    use aes::utils::padding::{ BitPadding, PaddingError };
+
+    let plaintext = b"YELLOW SUBMARINE";
+
+    assert_eq!(
+        BitPadding::padding(plaintext, 20).unwrap(),
+        [&plaintext[..], &[0x80, 0, 0, 0]].concat()
+    );
+    assert_eq!(
+        BitPadding::padding(plaintext, 16).unwrap(),
+        [&plaintext[..], &[0x80], &[0; 15]].concat()
+    );
+
+    for &len in &[8, 16, 20] {
+        assert_eq!(
+            BitPadding::unpadding(&BitPadding::padding(plaintext, len).unwrap(), len).unwrap(),
+            plaintext
+        );
+    }
+
+    assert_eq!(BitPadding::padding(plaintext, 0), Err(PaddingError::Other));
+    assert_eq!(BitPadding::unpadding(b"", 16), Err(PaddingError::BadData));
+    assert_eq!(BitPadding::unpadding(&plaintext[..15], 16), Err(PaddingError::BadData));
+    assert_eq!(BitPadding::unpadding(plaintext, 16), Err(PaddingError::BadPadding));
+    assert_eq!(BitPadding::unpadding(&[0; 16], 16), Err(PaddingError::BadPadding));
+}
+
+#[test]
+fn test_lengthpadding() {
+    use aes::utils::padding::{ LengthPadding, PaddingError };
+
+    let plaintext = b"YELLOW SUBMARINE";
+
+    assert_eq!(
+        LengthPadding::padding(plaintext, 16).unwrap(),
+        [&[0; 15][..], &[0x80], &plaintext[..]].concat()
+    );
+    assert_eq!(
+        LengthPadding::padding(&plaintext[..10], 16).unwrap(),
+        [&[0; 15][..], &[0x50], &plaintext[..10], &[0; 6]].concat()
+    );
+    assert_eq!(LengthPadding::padding(b"", 16).unwrap(), [0; 16]);
+
+    for &len in &[8, 16, 20] {
+        assert_eq!(
+            LengthPadding::unpadding(&LengthPadding::padding(plaintext, len).unwrap(), len).unwrap(),
+            plaintext
+        );
+    }
+
+    assert_eq!(LengthPadding::padding(plaintext, 4), Err(PaddingError::Other));
+    assert_eq!(LengthPadding::unpadding(b"", 16), Err(PaddingError::BadData));
+    assert_eq!(
+        LengthPadding::unpadding(&[&[0; 15][..], &[0x88], &plaintext[..]].concat(), 16),
+        Err(PaddingError::BadPadding)
+    );
+    assert_eq!(
+        LengthPadding::unpadding(&[&[0; 15][..], &[0x50], &plaintext[..]].concat(), 16),
+        Err(PaddingError::BadPadding)
+    );
 }
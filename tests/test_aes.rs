@@ -7,6 +7,7 @@ extern crate aes;
 #[macro_use] mod rand_macro;
 
 mod mode;
+mod mac;
 
 use aes::AES;
 use aes::cipher::{ SingleBlockEncrypt, SingleBlockDecrypt };
@@ -67,7 +68,7 @@ fn test_aes_rsbox() {
 #[test]
 fn test_aes_rcon() {
     use aes::aes::RCON;
-    let rcon: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+    let rcon: [u8; 14] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36, 0x6c, 0xd8, 0xab, 0x4d];
     for (i, &n) in rcon.iter().enumerate() {
         assert_eq!(RCON[i], n);
     }
@@ -122,7 +123,7 @@ fn test_encrypt() {
     use crypto::buffer::{ ReadBuffer, WriteBuffer };
 
     assert_eq!(
-        AES::new(&[0; 16]).encrypt(&[0; 16]),
+        AES::new(&[0; 16]).unwrap().encrypt(&[0; 16]).unwrap(),
         [102, 233, 75, 212, 239, 138, 44, 59, 136, 76, 250, 89, 202, 52, 43, 46]
     );
 
@@ -133,7 +134,7 @@ fn test_encrypt() {
     os_cipher.pad(false);
 
     assert_eq!(
-        AES::new(&key).encrypt(&plaintext),
+        AES::new(&key).unwrap().encrypt(&plaintext).unwrap(),
         os_cipher.update(&plaintext)
     );
 
@@ -149,7 +150,7 @@ fn test_encrypt() {
     crypto_cipher.encrypt(&mut read_buffer, &mut write_buffer, true).ok();
 
     assert_eq!(
-        AES::new(&key).encrypt(&plaintext),
+        AES::new(&key).unwrap().encrypt(&plaintext).unwrap(),
         write_buffer.take_read_buffer().take_remaining().iter().map(|&i| i).collect::<Vec<u8>>()
     );
 }
@@ -157,7 +158,7 @@ fn test_encrypt() {
 #[test]
 fn test_decrypt() {
     assert_eq!(
-        AES::new(&[0; 16]).decrypt(&[0; 16]),
+        AES::new(&[0; 16]).unwrap().decrypt(&[0; 16]).unwrap(),
         [20, 15, 15, 16, 17, 181, 34, 61, 121, 88, 119, 23, 255, 217, 236, 58]
     );
 
@@ -165,7 +166,402 @@ fn test_decrypt() {
     let plaintext = rand!(16);
 
     assert_eq!(
-        AES::new(&key).decrypt(&AES::new(&key).encrypt(&plaintext)),
+        AES::new(&key).unwrap().decrypt(
+            &AES::new(&key).unwrap().encrypt(&plaintext).unwrap()
+        ).unwrap(),
         plaintext
     );
 }
+
+#[test]
+fn test_bad_length() {
+    use aes::cipher::DecryptFail;
+
+    assert_eq!(AES::new(&[0; 20]).err(), Some(DecryptFail::InvalidKeyLength));
+    assert_eq!(AES::portable(&[]).err(), Some(DecryptFail::InvalidKeyLength));
+
+    let cipher = AES::new(&rand!(16)).unwrap();
+    assert_eq!(cipher.encrypt(&[0; 15]), Err(DecryptFail::MisalignedInput));
+    assert_eq!(cipher.decrypt(&[0; 17]), Err(DecryptFail::MisalignedInput));
+}
+
+#[test]
+fn test_encrypt_block() {
+    use aes::cipher::{ FixedBlockEncrypt, FixedBlockDecrypt };
+
+    let key = rand!(16);
+    let plaintext = rand!(16);
+    let cipher = AES::new(&key).unwrap();
+
+    let mut block = [0; 16];
+    block.copy_from_slice(&plaintext);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&block[..], &cipher.encrypt(&plaintext).unwrap()[..]);
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&block[..], &plaintext[..]);
+}
+
+#[test]
+fn test_fixed_key() {
+    use aes::{ Aes128, Aes192, Aes256 };
+
+    let plaintext = rand!(16);
+
+    let key: [u8; 16] = rand!(_);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(Aes128::new(&key).encrypt(&plaintext), reference.encrypt(&plaintext));
+    assert_eq!(Aes128::portable(&key).decrypt(&plaintext), reference.decrypt(&plaintext));
+
+    let key: [u8; 24] = rand!(_);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(Aes192::new(&key).encrypt(&plaintext), reference.encrypt(&plaintext));
+    assert_eq!(Aes192::portable(&key).decrypt(&plaintext), reference.decrypt(&plaintext));
+
+    let key: [u8; 32] = rand!(_);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(Aes256::new(&key).encrypt(&plaintext), reference.encrypt(&plaintext));
+    assert_eq!(Aes256::portable(&key).decrypt(&plaintext), reference.decrypt(&plaintext));
+}
+
+#[test]
+fn test_rijndael() {
+    use aes::rijndael::{ Rijndael, N4, N6, N8 };
+
+    let plaintext = rand!(16);
+
+    let key: [u8; 16] = rand!(_);
+    let cipher = Rijndael::<N4, N4>::new(&key);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).unwrap(), reference.encrypt(&plaintext).unwrap());
+    assert_eq!(cipher.decrypt(&plaintext).unwrap(), reference.decrypt(&plaintext).unwrap());
+
+    let key: [u8; 24] = rand!(_);
+    let cipher = Rijndael::<N4, N6>::new(&key);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).unwrap(), reference.encrypt(&plaintext).unwrap());
+
+    let key: [u8; 32] = rand!(_);
+    let cipher = Rijndael::<N4, N8>::new(&key);
+    let reference = AES::portable(&key).unwrap();
+    assert_eq!(cipher.encrypt(&plaintext).unwrap(), reference.encrypt(&plaintext).unwrap());
+
+    assert!(Rijndael::<N8, N8>::new(&key).encrypt(&plaintext).is_err());
+}
+
+/// Gladman's Rijndael vectors for every block and key size: the plaintext and key are
+/// the leading bytes of `PT` and `KEY`, the digits of pi and e.
+#[test]
+fn test_rijndael_kat() {
+    use aes::rijndael::{ Rijndael, N4, N5, N6, N7, N8 };
+
+    const PT: [u8; 32] = [
+        0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d, 0x31, 0x31, 0x98, 0xa2, 0xe0, 0x37, 0x07, 0x34,
+        0x4a, 0x40, 0x93, 0x82, 0x22, 0x99, 0xf3, 0x1d, 0x00, 0x82, 0xef, 0xa9, 0x8e, 0xc4, 0xe6, 0xc8
+    ];
+    const KEY: [u8; 32] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+        0x76, 0x2e, 0x71, 0x60, 0xf3, 0x8b, 0x4d, 0xa5, 0x6a, 0x78, 0x4d, 0x90, 0x45, 0x19, 0x0c, 0xfe
+    ];
+
+    macro_rules! kat {
+        ( $nb:ident, $nk:ident, $key_len:expr, $expected:expr ) => {{
+            type C = Rijndael<$nb, $nk>;
+            let bs = <C as SingleBlockEncrypt>::bs();
+            let mut key = [0; $key_len];
+            key.copy_from_slice(&KEY[..$key_len]);
+            let expected: &[u8] = &$expected;
+
+            let cipher = C::new(&key);
+            assert_eq!(cipher.encrypt(&PT[..bs]).unwrap(), expected);
+            assert_eq!(cipher.decrypt(expected).unwrap(), &PT[..bs]);
+        }}
+    }
+
+    kat!(N4, N4, 16, [
+        0x39, 0x25, 0x84, 0x1d, 0x02, 0xdc, 0x09, 0xfb, 0xdc, 0x11, 0x85, 0x97, 0x19, 0x6a, 0x0b, 0x32
+    ]);
+    kat!(N4, N5, 20, [
+        0x23, 0x1d, 0x84, 0x46, 0x39, 0xb3, 0x1b, 0x41, 0x22, 0x11, 0xcf, 0xe9, 0x37, 0x12, 0xb8, 0x80
+    ]);
+    kat!(N4, N6, 24, [
+        0xf9, 0xfb, 0x29, 0xae, 0xfc, 0x38, 0x4a, 0x25, 0x03, 0x40, 0xd8, 0x33, 0xb8, 0x7e, 0xbc, 0x00
+    ]);
+    kat!(N4, N7, 28, [
+        0x8f, 0xaa, 0x8f, 0xe4, 0xde, 0xe9, 0xeb, 0x17, 0xca, 0xa4, 0x79, 0x75, 0x02, 0xfc, 0x9d, 0x3f
+    ]);
+    kat!(N4, N8, 32, [
+        0x1a, 0x6e, 0x6c, 0x2c, 0x66, 0x2e, 0x7d, 0xa6, 0x50, 0x1f, 0xfb, 0x62, 0xbc, 0x9e, 0x93, 0xf3
+    ]);
+    kat!(N5, N4, 16, [
+        0x16, 0xe7, 0x3a, 0xec, 0x92, 0x13, 0x14, 0xc2, 0x9d, 0xf9, 0x05, 0x43, 0x2b, 0xc8, 0x96, 0x8a,
+        0xb6, 0x4b, 0x1f, 0x51
+    ]);
+    kat!(N5, N5, 20, [
+        0x05, 0x53, 0xeb, 0x69, 0x16, 0x70, 0xdd, 0x8a, 0x5a, 0x5b, 0x5a, 0xdd, 0xf1, 0xaa, 0x74, 0x50,
+        0xf7, 0xa0, 0xe5, 0x87
+    ]);
+    kat!(N5, N6, 24, [
+        0x73, 0xcd, 0x6f, 0x34, 0x23, 0x03, 0x67, 0x90, 0x46, 0x3a, 0xa9, 0xe1, 0x9c, 0xfc, 0xde, 0x89,
+        0x4e, 0xa1, 0x66, 0x23
+    ]);
+    kat!(N5, N7, 28, [
+        0x60, 0x1b, 0x5d, 0xcd, 0x1c, 0xf4, 0xec, 0xe9, 0x54, 0xc7, 0x40, 0x44, 0x53, 0x40, 0xbf, 0x0a,
+        0xfd, 0xc0, 0x48, 0xdf
+    ]);
+    kat!(N5, N8, 32, [
+        0x57, 0x9e, 0x93, 0x0b, 0x36, 0xc1, 0x52, 0x9a, 0xa3, 0xe8, 0x66, 0x28, 0xba, 0xcf, 0xe1, 0x46,
+        0x94, 0x28, 0x82, 0xcf
+    ]);
+    kat!(N6, N4, 16, [
+        0xb2, 0x4d, 0x27, 0x54, 0x89, 0xe8, 0x2b, 0xb8, 0xf7, 0x37, 0x5e, 0x0d, 0x5f, 0xcd, 0xb1, 0xf4,
+        0x81, 0x75, 0x7c, 0x53, 0x8b, 0x65, 0x14, 0x8a
+    ]);
+    kat!(N6, N5, 20, [
+        0x73, 0x8d, 0xae, 0x25, 0x62, 0x0d, 0x3d, 0x3b, 0xef, 0xf4, 0xa0, 0x37, 0xa0, 0x42, 0x90, 0xd7,
+        0x3e, 0xb3, 0x35, 0x21, 0xa6, 0x3e, 0xa5, 0x68
+    ]);
+    kat!(N6, N6, 24, [
+        0x72, 0x5a, 0xe4, 0x3b, 0x5f, 0x31, 0x61, 0xde, 0x80, 0x6a, 0x7c, 0x93, 0xe0, 0xbc, 0xa9, 0x3c,
+        0x96, 0x7e, 0xc1, 0xae, 0x1b, 0x71, 0xe1, 0xcf
+    ]);
+    kat!(N6, N7, 28, [
+        0xbb, 0xfc, 0x14, 0x18, 0x0a, 0xfb, 0xf6, 0xa3, 0x63, 0x82, 0xa0, 0x61, 0x84, 0x3f, 0x0b, 0x63,
+        0xe7, 0x69, 0xac, 0xdc, 0x98, 0x76, 0x91, 0x30
+    ]);
+    kat!(N6, N8, 32, [
+        0x0e, 0xba, 0xcf, 0x19, 0x9e, 0x33, 0x15, 0xc2, 0xe3, 0x4b, 0x24, 0xfc, 0xc7, 0xc4, 0x6e, 0xf4,
+        0x38, 0x8a, 0xa4, 0x75, 0xd6, 0x6c, 0x19, 0x4c
+    ]);
+    kat!(N7, N4, 16, [
+        0xb0, 0xa8, 0xf7, 0x8f, 0x6b, 0x3c, 0x66, 0x21, 0x3f, 0x79, 0x2f, 0xfd, 0x2a, 0x61, 0x63, 0x1f,
+        0x79, 0x33, 0x14, 0x07, 0xa5, 0xe5, 0xc8, 0xd3, 0x79, 0x3a, 0xce, 0xb1
+    ]);
+    kat!(N7, N5, 20, [
+        0x08, 0xb9, 0x99, 0x44, 0xed, 0xfc, 0xe3, 0x3a, 0x2a, 0xcb, 0x13, 0x11, 0x83, 0xab, 0x01, 0x68,
+        0x44, 0x6b, 0x2d, 0x15, 0xe9, 0x58, 0x48, 0x00, 0x10, 0xf5, 0x45, 0xe3
+    ]);
+    kat!(N7, N6, 24, [
+        0xbe, 0x4c, 0x59, 0x7d, 0x8f, 0x7e, 0xfe, 0x22, 0xa2, 0xf7, 0xe5, 0xb1, 0x93, 0x8e, 0x25, 0x64,
+        0xd4, 0x52, 0xa5, 0xbf, 0xe7, 0x23, 0x99, 0xc7, 0xaf, 0x11, 0x01, 0xe2
+    ]);
+    kat!(N7, N7, 28, [
+        0xef, 0x52, 0x95, 0x98, 0xec, 0xbc, 0xe2, 0x97, 0x81, 0x1b, 0x49, 0xbb, 0xed, 0x2c, 0x33, 0xbb,
+        0xe1, 0x24, 0x1d, 0x6e, 0x1a, 0x83, 0x3d, 0xbe, 0x11, 0x95, 0x69, 0xe8
+    ]);
+    kat!(N7, N8, 32, [
+        0x02, 0xfa, 0xfc, 0x20, 0x01, 0x76, 0xed, 0x05, 0xde, 0xb8, 0xed, 0xb8, 0x2a, 0x35, 0x55, 0xb0,
+        0xb1, 0x0d, 0x47, 0xa3, 0x88, 0xdf, 0xd5, 0x9c, 0xab, 0x2f, 0x6c, 0x11
+    ]);
+    kat!(N8, N4, 16, [
+        0x7d, 0x15, 0x47, 0x90, 0x76, 0xb6, 0x9a, 0x46, 0xff, 0xb3, 0xb3, 0xbe, 0xae, 0x97, 0xad, 0x83,
+        0x13, 0xf6, 0x22, 0xf6, 0x7f, 0xed, 0xb4, 0x87, 0xde, 0x9f, 0x06, 0xb9, 0xed, 0x9c, 0x8f, 0x19
+    ]);
+    kat!(N8, N5, 20, [
+        0x51, 0x4f, 0x93, 0xfb, 0x29, 0x6b, 0x5a, 0xd1, 0x6a, 0xa7, 0xdf, 0x8b, 0x57, 0x7a, 0xbc, 0xbd,
+        0x48, 0x4d, 0xec, 0xac, 0xcc, 0xc7, 0xfb, 0x1f, 0x18, 0xdc, 0x56, 0x73, 0x09, 0xce, 0xef, 0xfd
+    ]);
+    kat!(N8, N6, 24, [
+        0x5d, 0x71, 0x01, 0x72, 0x7b, 0xb2, 0x57, 0x81, 0xbf, 0x67, 0x15, 0xb0, 0xe6, 0x95, 0x52, 0x82,
+        0xb9, 0x61, 0x0e, 0x23, 0xa4, 0x3c, 0x2e, 0xb0, 0x62, 0x69, 0x9f, 0x0e, 0xbf, 0x58, 0x87, 0xb2
+    ]);
+    kat!(N8, N7, 28, [
+        0xd5, 0x6c, 0x5a, 0x63, 0x62, 0x74, 0x32, 0x57, 0x9e, 0x1d, 0xd3, 0x08, 0xb2, 0xc8, 0xf1, 0x57,
+        0xb4, 0x0a, 0x4b, 0xfb, 0x56, 0xfe, 0xa1, 0x37, 0x7b, 0x25, 0xd3, 0xed, 0x3d, 0x6d, 0xbf, 0x80
+    ]);
+    kat!(N8, N8, 32, [
+        0xa4, 0x94, 0x06, 0x11, 0x5d, 0xfb, 0x30, 0xa4, 0x04, 0x18, 0xaa, 0xfa, 0x48, 0x69, 0xb7, 0xc6,
+        0xa8, 0x86, 0xff, 0x31, 0x60, 0x2a, 0x7d, 0xd1, 0x9c, 0x88, 0x9d, 0xc6, 0x4f, 0x7e, 0x4e, 0x7a
+    ]);
+}
+
+#[test]
+fn test_rijndael_modes() {
+    use aes::rijndael::{ Rijndael, N4, N5, N6, N7, N8 };
+    use aes::mode::{ Ecb, Cbc };
+    use aes::utils::padding::Pkcs7Padding;
+    use aes::cipher::{ BlockEncrypt, BlockDecrypt };
+
+    macro_rules! check {
+        ( $nb:ident, $nk:ident ) => {{
+            type C = Rijndael<$nb, $nk>;
+            let bs = <C as SingleBlockEncrypt>::bs();
+            let key = rand!(_);
+            let iv = rand!(bs);
+            let plaintext = rand!(rand!(choose 32..97));
+
+            let cipher = C::new(&key);
+            let ciphertext = cipher.encrypt(&plaintext[..bs]).unwrap();
+            assert_eq!(ciphertext.len(), bs);
+            assert_eq!(cipher.decrypt(&ciphertext).unwrap(), &plaintext[..bs]);
+
+            let ciphertext = Ecb::<C>::new(&key).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap();
+            assert_eq!(ciphertext.len() % bs, 0);
+            assert_eq!(
+                Ecb::<C>::new(&key).unwrap().decrypt::<Pkcs7Padding>(&ciphertext),
+                Ok(plaintext.clone())
+            );
+
+            let ciphertext = Cbc::<C>::new(&key, &iv).unwrap().encrypt::<Pkcs7Padding>(&plaintext).unwrap();
+            assert_eq!(
+                Cbc::<C>::new(&key, &iv).unwrap().decrypt::<Pkcs7Padding>(&ciphertext),
+                Ok(plaintext)
+            );
+        }}
+    }
+
+    check!(N4, N4); check!(N4, N5); check!(N4, N6); check!(N4, N7); check!(N4, N8);
+    check!(N5, N4); check!(N5, N5); check!(N5, N6); check!(N5, N7); check!(N5, N8);
+    check!(N6, N4); check!(N6, N5); check!(N6, N6); check!(N6, N7); check!(N6, N8);
+    check!(N7, N4); check!(N7, N5); check!(N7, N6); check!(N7, N7); check!(N7, N8);
+    check!(N8, N4); check!(N8, N5); check!(N8, N6); check!(N8, N7); check!(N8, N8);
+}
+
+#[test]
+fn test_reduced_rounds() {
+    use aes::aes::{ ReducedAes, WithFinalMix, R1, R3, R4, R10, R12, R14 };
+    use aes::mode::Ctr;
+    use aes::cipher::{ StreamEncrypt, StreamDecrypt };
+
+    let plaintext = rand!(16);
+    let key = rand!(16);
+    assert_eq!(
+        ReducedAes::<R10>::new(&key).unwrap().encrypt(&plaintext),
+        AES::portable(&key).unwrap().encrypt(&plaintext)
+    );
+    let key = rand!(24);
+    assert_eq!(
+        ReducedAes::<R12>::new(&key).unwrap().encrypt(&plaintext),
+        AES::portable(&key).unwrap().encrypt(&plaintext)
+    );
+    let key = rand!(32);
+    assert_eq!(
+        ReducedAes::<R14>::new(&key).unwrap().decrypt(&plaintext),
+        AES::portable(&key).unwrap().decrypt(&plaintext)
+    );
+
+    let key: Vec<u8> = (0..16).collect();
+    let plaintext = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+    ];
+    let cipher = ReducedAes::<WithFinalMix<R4>>::new(&key).unwrap();
+    let ciphertext = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(ciphertext, [
+        0x24, 0x72, 0x40, 0x23, 0x69, 0x66, 0xb3, 0xfa, 0x6e, 0xd2, 0x75, 0x32, 0x88, 0x42, 0x5b, 0x6c
+    ]);
+    assert_eq!(cipher.decrypt(&ciphertext).unwrap(), plaintext);
+
+    let cipher = ReducedAes::<R14>::new(&key).unwrap();
+    let ciphertext = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(ciphertext, [
+        0x6c, 0x3d, 0xc1, 0xb3, 0x5a, 0x16, 0xbc, 0x1a, 0x32, 0x38, 0x1b, 0xdc, 0x3b, 0x93, 0x81, 0x71
+    ]);
+    assert_eq!(cipher.decrypt(&ciphertext).unwrap(), plaintext);
+
+    let key: Vec<u8> = (0..24).collect();
+    let cipher = ReducedAes::<R1>::new(&key).unwrap();
+    let ciphertext = cipher.encrypt(&plaintext).unwrap();
+    assert_eq!(ciphertext, [
+        0x73, 0x42, 0xf2, 0x9f, 0x1d, 0x75, 0xf7, 0x13, 0x95, 0x36, 0x45, 0xa8, 0xe6, 0x89, 0x24, 0x19
+    ]);
+    assert_eq!(cipher.decrypt(&ciphertext).unwrap(), plaintext);
+
+    // Square property: over a set taking every value in one byte, three rounds leave every byte balanced.
+    let key = rand!(16);
+    let mut plaintext: [u8; 16] = rand!(_);
+    let mut sums = [[0; 16]; 3];
+    let ciphers = (
+        ReducedAes::<R3>::new(&key).unwrap(),
+        ReducedAes::<WithFinalMix<R3>>::new(&key).unwrap(),
+        ReducedAes::<R4>::new(&key).unwrap()
+    );
+    for b in 0..256 {
+        plaintext[0] = b as u8;
+        let outputs = [
+            ciphers.0.encrypt(&plaintext).unwrap(),
+            ciphers.1.encrypt(&plaintext).unwrap(),
+            ciphers.2.encrypt(&plaintext).unwrap()
+        ];
+        for (sum, output) in sums.iter_mut().zip(&outputs) {
+            for (s, o) in sum.iter_mut().zip(output) {
+                *s ^= *o;
+            }
+        }
+    }
+    assert_eq!(sums[0], [0; 16]);
+    assert_eq!(sums[1], [0; 16]);
+    assert!(sums[2] != [0; 16]);
+
+    assert!(ReducedAes::<R4>::new(&rand!(20)).is_err());
+
+    let counter = rand!(16);
+    let data = rand!(rand!(choose 1..64));
+    let ciphertext = Ctr::<ReducedAes<R4>>::new(&key, &counter).unwrap().encrypt(&data).unwrap();
+    assert_eq!(Ctr::<ReducedAes<R4>>::new(&key, &counter).unwrap().decrypt(&ciphertext).unwrap(), data);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_aesni() {
+    use aes::aesni::AesNi;
+
+    if !AesNi::is_supported() { return };
+
+    for &len in &[16, 24, 32] {
+        let key = rand!(len);
+        let plaintext = rand!(16);
+        let ni = AesNi::new(&key).unwrap();
+        let portable = AES::portable(&key).unwrap();
+
+        assert_eq!(ni.encrypt(&plaintext).unwrap(), portable.encrypt(&plaintext).unwrap());
+        assert_eq!(ni.decrypt(&plaintext).unwrap(), portable.decrypt(&plaintext).unwrap());
+        assert_eq!(ni.decrypt(&ni.encrypt(&plaintext).unwrap()).unwrap(), plaintext);
+    }
+
+    assert!(AesNi::new(&[0; 20]).is_none());
+}
+
+#[test]
+fn test_bitslice() {
+    use aes::bitslice::AesBitslice;
+    use aes::cipher::{ FixedBlockEncrypt, FixedBlockDecrypt };
+
+    for &len in &[16, 24, 32] {
+        let key = rand!(len);
+        let plaintext = rand!(16 * rand!(choose 1..9));
+        let cipher = AesBitslice::new(&key).unwrap();
+        let reference = AES::portable(&key).unwrap();
+
+        assert_eq!(cipher.encrypt(&plaintext[..16]).unwrap(), reference.encrypt(&plaintext[..16]).unwrap());
+        assert_eq!(cipher.decrypt(&plaintext[..16]).unwrap(), reference.decrypt(&plaintext[..16]).unwrap());
+
+        let mut blocks = plaintext.chunks(16)
+            .map(|b| {
+                let mut block = [0; 16];
+                block.copy_from_slice(b);
+                block
+            })
+            .collect::<Vec<_>>();
+        cipher.encrypt_blocks(&mut blocks);
+        assert_eq!(
+            blocks.concat(),
+            plaintext.chunks(16).map(|b| reference.encrypt(b).unwrap()).collect::<Vec<_>>().concat()
+        );
+        cipher.decrypt_blocks(&mut blocks);
+        assert_eq!(blocks.concat(), plaintext);
+    }
+}
+
+#[test]
+fn test_ttable() {
+    use aes::ttable::AesTable;
+
+    for &len in &[16, 24, 32] {
+        let key = rand!(len);
+        let plaintext = rand!(16);
+        let cipher = AesTable::new(&key).unwrap();
+        let reference = AES::portable(&key).unwrap();
+
+        assert_eq!(cipher.encrypt(&plaintext).unwrap(), reference.encrypt(&plaintext).unwrap());
+        assert_eq!(cipher.decrypt(&plaintext).unwrap(), reference.decrypt(&plaintext).unwrap());
+    }
+}
@@ -0,0 +1,558 @@
+extern crate aes;
+
+use aes::{ AES, Aes128, Aes192, Aes256 };
+use aes::aes::Trace;
+
+
+/// FIPS-197 Appendix B, written in the Appendix C format.
+const APPENDIX_B_CIPHER: &'static str = "\
+round[ 0].input 3243f6a8885a308d313198a2e0370734\n\
+round[ 0].k_sch 2b7e151628aed2a6abf7158809cf4f3c\n\
+round[ 1].start 193de3bea0f4e22b9ac68d2ae9f84808\n\
+round[ 1].s_box d42711aee0bf98f1b8b45de51e415230\n\
+round[ 1].s_row d4bf5d30e0b452aeb84111f11e2798e5\n\
+round[ 1].m_col 046681e5e0cb199a48f8d37a2806264c\n\
+round[ 1].k_sch a0fafe1788542cb123a339392a6c7605\n\
+round[ 2].start a49c7ff2689f352b6b5bea43026a5049\n\
+round[ 2].s_box 49ded28945db96f17f39871a7702533b\n\
+round[ 2].s_row 49db873b453953897f02d2f177de961a\n\
+round[ 2].m_col 584dcaf11b4b5aacdbe7caa81b6bb0e5\n\
+round[ 2].k_sch f2c295f27a96b9435935807a7359f67f\n\
+round[ 3].start aa8f5f0361dde3ef82d24ad26832469a\n\
+round[ 3].s_box ac73cf7befc111df13b5d6b545235ab8\n\
+round[ 3].s_row acc1d6b8efb55a7b1323cfdf457311b5\n\
+round[ 3].m_col 75ec0993200b633353c0cf7cbb25d0dc\n\
+round[ 3].k_sch 3d80477d4716fe3e1e237e446d7a883b\n\
+round[ 4].start 486c4eee671d9d0d4de3b138d65f58e7\n\
+round[ 4].s_box 52502f2885a45ed7e311c807f6cf6a94\n\
+round[ 4].s_row 52a4c89485116a28e3cf2fd7f6505e07\n\
+round[ 4].m_col 0fd6daa9603138bf6fc0106b5eb31301\n\
+round[ 4].k_sch ef44a541a8525b7fb671253bdb0bad00\n\
+round[ 5].start e0927fe8c86363c0d9b1355085b8be01\n\
+round[ 5].s_box e14fd29be8fbfbba35c89653976cae7c\n\
+round[ 5].s_row e1fb967ce8c8ae9b356cd2ba974ffb53\n\
+round[ 5].m_col 25d1a9adbd11d168b63a338e4c4cc0b0\n\
+round[ 5].k_sch d4d1c6f87c839d87caf2b8bc11f915bc\n\
+round[ 6].start f1006f55c1924cef7cc88b325db5d50c\n\
+round[ 6].s_box a163a8fc784f29df10e83d234cd503fe\n\
+round[ 6].s_row a14f3dfe78e803fc10d5a8df4c632923\n\
+round[ 6].m_col 4b868d6d2c4a8980339df4e837d218d8\n\
+round[ 6].k_sch 6d88a37a110b3efddbf98641ca0093fd\n\
+round[ 7].start 260e2e173d41b77de86472a9fdd28b25\n\
+round[ 7].s_box f7ab31f02783a9ff9b4340d354b53d3f\n\
+round[ 7].s_row f783403f27433df09bb531ff54aba9d3\n\
+round[ 7].m_col 1415b5bf461615ec274656d7342ad843\n\
+round[ 7].k_sch 4e54f70e5f5fc9f384a64fb24ea6dc4f\n\
+round[ 8].start 5a4142b11949dc1fa3e019657a8c040c\n\
+round[ 8].s_box be832cc8d43b86c00ae1d44dda64f2fe\n\
+round[ 8].s_row be3bd4fed4e1f2c80a642cc0da83864d\n\
+round[ 8].m_col 00512fd1b1c889ff54766dcdfa1b99ea\n\
+round[ 8].k_sch ead27321b58dbad2312bf5607f8d292f\n\
+round[ 9].start ea835cf00445332d655d98ad8596b0c5\n\
+round[ 9].s_box 87ec4a8cf26ec3d84d4c46959790e7a6\n\
+round[ 9].s_row 876e46a6f24ce78c4d904ad897ecc395\n\
+round[ 9].m_col 473794ed40d4e4a5a3703aa64c9f42bc\n\
+round[ 9].k_sch ac7766f319fadc2128d12941575c006e\n\
+round[10].start eb40f21e592e38848ba113e71bc342d2\n\
+round[10].s_box e9098972cb31075f3d327d94af2e2cb5\n\
+round[10].s_row e9317db5cb322c723d2e895faf090794\n\
+round[10].k_sch d014f9a8c9ee2589e13f0cc8b6630ca6\n\
+round[10].output 3925841d02dc09fbdc118597196a0b32";
+
+const APPENDIX_B_INV_CIPHER: &'static str = "\
+round[ 0].iinput 3925841d02dc09fbdc118597196a0b32\n\
+round[ 0].ik_sch d014f9a8c9ee2589e13f0cc8b6630ca6\n\
+round[ 1].istart e9317db5cb322c723d2e895faf090794\n\
+round[ 1].is_row e9098972cb31075f3d327d94af2e2cb5\n\
+round[ 1].is_box eb40f21e592e38848ba113e71bc342d2\n\
+round[ 1].ik_sch ac7766f319fadc2128d12941575c006e\n\
+round[ 1].ik_add 473794ed40d4e4a5a3703aa64c9f42bc\n\
+round[ 2].istart 876e46a6f24ce78c4d904ad897ecc395\n\
+round[ 2].is_row 87ec4a8cf26ec3d84d4c46959790e7a6\n\
+round[ 2].is_box ea835cf00445332d655d98ad8596b0c5\n\
+round[ 2].ik_sch ead27321b58dbad2312bf5607f8d292f\n\
+round[ 2].ik_add 00512fd1b1c889ff54766dcdfa1b99ea\n\
+round[ 3].istart be3bd4fed4e1f2c80a642cc0da83864d\n\
+round[ 3].is_row be832cc8d43b86c00ae1d44dda64f2fe\n\
+round[ 3].is_box 5a4142b11949dc1fa3e019657a8c040c\n\
+round[ 3].ik_sch 4e54f70e5f5fc9f384a64fb24ea6dc4f\n\
+round[ 3].ik_add 1415b5bf461615ec274656d7342ad843\n\
+round[ 4].istart f783403f27433df09bb531ff54aba9d3\n\
+round[ 4].is_row f7ab31f02783a9ff9b4340d354b53d3f\n\
+round[ 4].is_box 260e2e173d41b77de86472a9fdd28b25\n\
+round[ 4].ik_sch 6d88a37a110b3efddbf98641ca0093fd\n\
+round[ 4].ik_add 4b868d6d2c4a8980339df4e837d218d8\n\
+round[ 5].istart a14f3dfe78e803fc10d5a8df4c632923\n\
+round[ 5].is_row a163a8fc784f29df10e83d234cd503fe\n\
+round[ 5].is_box f1006f55c1924cef7cc88b325db5d50c\n\
+round[ 5].ik_sch d4d1c6f87c839d87caf2b8bc11f915bc\n\
+round[ 5].ik_add 25d1a9adbd11d168b63a338e4c4cc0b0\n\
+round[ 6].istart e1fb967ce8c8ae9b356cd2ba974ffb53\n\
+round[ 6].is_row e14fd29be8fbfbba35c89653976cae7c\n\
+round[ 6].is_box e0927fe8c86363c0d9b1355085b8be01\n\
+round[ 6].ik_sch ef44a541a8525b7fb671253bdb0bad00\n\
+round[ 6].ik_add 0fd6daa9603138bf6fc0106b5eb31301\n\
+round[ 7].istart 52a4c89485116a28e3cf2fd7f6505e07\n\
+round[ 7].is_row 52502f2885a45ed7e311c807f6cf6a94\n\
+round[ 7].is_box 486c4eee671d9d0d4de3b138d65f58e7\n\
+round[ 7].ik_sch 3d80477d4716fe3e1e237e446d7a883b\n\
+round[ 7].ik_add 75ec0993200b633353c0cf7cbb25d0dc\n\
+round[ 8].istart acc1d6b8efb55a7b1323cfdf457311b5\n\
+round[ 8].is_row ac73cf7befc111df13b5d6b545235ab8\n\
+round[ 8].is_box aa8f5f0361dde3ef82d24ad26832469a\n\
+round[ 8].ik_sch f2c295f27a96b9435935807a7359f67f\n\
+round[ 8].ik_add 584dcaf11b4b5aacdbe7caa81b6bb0e5\n\
+round[ 9].istart 49db873b453953897f02d2f177de961a\n\
+round[ 9].is_row 49ded28945db96f17f39871a7702533b\n\
+round[ 9].is_box a49c7ff2689f352b6b5bea43026a5049\n\
+round[ 9].ik_sch a0fafe1788542cb123a339392a6c7605\n\
+round[ 9].ik_add 046681e5e0cb199a48f8d37a2806264c\n\
+round[10].istart d4bf5d30e0b452aeb84111f11e2798e5\n\
+round[10].is_row d42711aee0bf98f1b8b45de51e415230\n\
+round[10].is_box 193de3bea0f4e22b9ac68d2ae9f84808\n\
+round[10].ik_sch 2b7e151628aed2a6abf7158809cf4f3c\n\
+round[10].ioutput 3243f6a8885a308d313198a2e0370734";
+
+/// FIPS-197 Appendix C.1, AES-128.
+const C1_CIPHER: &'static str = "\
+round[ 0].input 00112233445566778899aabbccddeeff\n\
+round[ 0].k_sch 000102030405060708090a0b0c0d0e0f\n\
+round[ 1].start 00102030405060708090a0b0c0d0e0f0\n\
+round[ 1].s_box 63cab7040953d051cd60e0e7ba70e18c\n\
+round[ 1].s_row 6353e08c0960e104cd70b751bacad0e7\n\
+round[ 1].m_col 5f72641557f5bc92f7be3b291db9f91a\n\
+round[ 1].k_sch d6aa74fdd2af72fadaa678f1d6ab76fe\n\
+round[ 2].start 89d810e8855ace682d1843d8cb128fe4\n\
+round[ 2].s_box a761ca9b97be8b45d8ad1a611fc97369\n\
+round[ 2].s_row a7be1a6997ad739bd8c9ca451f618b61\n\
+round[ 2].m_col ff87968431d86a51645151fa773ad009\n\
+round[ 2].k_sch b692cf0b643dbdf1be9bc5006830b3fe\n\
+round[ 3].start 4915598f55e5d7a0daca94fa1f0a63f7\n\
+round[ 3].s_box 3b59cb73fcd90ee05774222dc067fb68\n\
+round[ 3].s_row 3bd92268fc74fb735767cbe0c0590e2d\n\
+round[ 3].m_col 4c9c1e66f771f0762c3f868e534df256\n\
+round[ 3].k_sch b6ff744ed2c2c9bf6c590cbf0469bf41\n\
+round[ 4].start fa636a2825b339c940668a3157244d17\n\
+round[ 4].s_box 2dfb02343f6d12dd09337ec75b36e3f0\n\
+round[ 4].s_row 2d6d7ef03f33e334093602dd5bfb12c7\n\
+round[ 4].m_col 6385b79ffc538df997be478e7547d691\n\
+round[ 4].k_sch 47f7f7bc95353e03f96c32bcfd058dfd\n\
+round[ 5].start 247240236966b3fa6ed2753288425b6c\n\
+round[ 5].s_box 36400926f9336d2d9fb59d23c42c3950\n\
+round[ 5].s_row 36339d50f9b539269f2c092dc4406d23\n\
+round[ 5].m_col f4bcd45432e554d075f1d6c51dd03b3c\n\
+round[ 5].k_sch 3caaa3e8a99f9deb50f3af57adf622aa\n\
+round[ 6].start c81677bc9b7ac93b25027992b0261996\n\
+round[ 6].s_box e847f56514dadde23f77b64fe7f7d490\n\
+round[ 6].s_row e8dab6901477d4653ff7f5e2e747dd4f\n\
+round[ 6].m_col 9816ee7400f87f556b2c049c8e5ad036\n\
+round[ 6].k_sch 5e390f7df7a69296a7553dc10aa31f6b\n\
+round[ 7].start c62fe109f75eedc3cc79395d84f9cf5d\n\
+round[ 7].s_box b415f8016858552e4bb6124c5f998a4c\n\
+round[ 7].s_row b458124c68b68a014b99f82e5f15554c\n\
+round[ 7].m_col c57e1c159a9bd286f05f4be098c63439\n\
+round[ 7].k_sch 14f9701ae35fe28c440adf4d4ea9c026\n\
+round[ 8].start d1876c0f79c4300ab45594add66ff41f\n\
+round[ 8].s_box 3e175076b61c04678dfc2295f6a8bfc0\n\
+round[ 8].s_row 3e1c22c0b6fcbf768da85067f6170495\n\
+round[ 8].m_col baa03de7a1f9b56ed5512cba5f414d23\n\
+round[ 8].k_sch 47438735a41c65b9e016baf4aebf7ad2\n\
+round[ 9].start fde3bad205e5d0d73547964ef1fe37f1\n\
+round[ 9].s_box 5411f4b56bd9700e96a0902fa1bb9aa1\n\
+round[ 9].s_row 54d990a16ba09ab596bbf40ea111702f\n\
+round[ 9].m_col e9f74eec023020f61bf2ccf2353c21c7\n\
+round[ 9].k_sch 549932d1f08557681093ed9cbe2c974e\n\
+round[10].start bd6e7c3df2b5779e0b61216e8b10b689\n\
+round[10].s_box 7a9f102789d5f50b2beffd9f3dca4ea7\n\
+round[10].s_row 7ad5fda789ef4e272bca100b3d9ff59f\n\
+round[10].k_sch 13111d7fe3944a17f307a78b4d2b30c5\n\
+round[10].output 69c4e0d86a7b0430d8cdb78070b4c55a";
+
+const C1_INV_CIPHER: &'static str = "\
+round[ 0].iinput 69c4e0d86a7b0430d8cdb78070b4c55a\n\
+round[ 0].ik_sch 13111d7fe3944a17f307a78b4d2b30c5\n\
+round[ 1].istart 7ad5fda789ef4e272bca100b3d9ff59f\n\
+round[ 1].is_row 7a9f102789d5f50b2beffd9f3dca4ea7\n\
+round[ 1].is_box bd6e7c3df2b5779e0b61216e8b10b689\n\
+round[ 1].ik_sch 549932d1f08557681093ed9cbe2c974e\n\
+round[ 1].ik_add e9f74eec023020f61bf2ccf2353c21c7\n\
+round[ 2].istart 54d990a16ba09ab596bbf40ea111702f\n\
+round[ 2].is_row 5411f4b56bd9700e96a0902fa1bb9aa1\n\
+round[ 2].is_box fde3bad205e5d0d73547964ef1fe37f1\n\
+round[ 2].ik_sch 47438735a41c65b9e016baf4aebf7ad2\n\
+round[ 2].ik_add baa03de7a1f9b56ed5512cba5f414d23\n\
+round[ 3].istart 3e1c22c0b6fcbf768da85067f6170495\n\
+round[ 3].is_row 3e175076b61c04678dfc2295f6a8bfc0\n\
+round[ 3].is_box d1876c0f79c4300ab45594add66ff41f\n\
+round[ 3].ik_sch 14f9701ae35fe28c440adf4d4ea9c026\n\
+round[ 3].ik_add c57e1c159a9bd286f05f4be098c63439\n\
+round[ 4].istart b458124c68b68a014b99f82e5f15554c\n\
+round[ 4].is_row b415f8016858552e4bb6124c5f998a4c\n\
+round[ 4].is_box c62fe109f75eedc3cc79395d84f9cf5d\n\
+round[ 4].ik_sch 5e390f7df7a69296a7553dc10aa31f6b\n\
+round[ 4].ik_add 9816ee7400f87f556b2c049c8e5ad036\n\
+round[ 5].istart e8dab6901477d4653ff7f5e2e747dd4f\n\
+round[ 5].is_row e847f56514dadde23f77b64fe7f7d490\n\
+round[ 5].is_box c81677bc9b7ac93b25027992b0261996\n\
+round[ 5].ik_sch 3caaa3e8a99f9deb50f3af57adf622aa\n\
+round[ 5].ik_add f4bcd45432e554d075f1d6c51dd03b3c\n\
+round[ 6].istart 36339d50f9b539269f2c092dc4406d23\n\
+round[ 6].is_row 36400926f9336d2d9fb59d23c42c3950\n\
+round[ 6].is_box 247240236966b3fa6ed2753288425b6c\n\
+round[ 6].ik_sch 47f7f7bc95353e03f96c32bcfd058dfd\n\
+round[ 6].ik_add 6385b79ffc538df997be478e7547d691\n\
+round[ 7].istart 2d6d7ef03f33e334093602dd5bfb12c7\n\
+round[ 7].is_row 2dfb02343f6d12dd09337ec75b36e3f0\n\
+round[ 7].is_box fa636a2825b339c940668a3157244d17\n\
+round[ 7].ik_sch b6ff744ed2c2c9bf6c590cbf0469bf41\n\
+round[ 7].ik_add 4c9c1e66f771f0762c3f868e534df256\n\
+round[ 8].istart 3bd92268fc74fb735767cbe0c0590e2d\n\
+round[ 8].is_row 3b59cb73fcd90ee05774222dc067fb68\n\
+round[ 8].is_box 4915598f55e5d7a0daca94fa1f0a63f7\n\
+round[ 8].ik_sch b692cf0b643dbdf1be9bc5006830b3fe\n\
+round[ 8].ik_add ff87968431d86a51645151fa773ad009\n\
+round[ 9].istart a7be1a6997ad739bd8c9ca451f618b61\n\
+round[ 9].is_row a761ca9b97be8b45d8ad1a611fc97369\n\
+round[ 9].is_box 89d810e8855ace682d1843d8cb128fe4\n\
+round[ 9].ik_sch d6aa74fdd2af72fadaa678f1d6ab76fe\n\
+round[ 9].ik_add 5f72641557f5bc92f7be3b291db9f91a\n\
+round[10].istart 6353e08c0960e104cd70b751bacad0e7\n\
+round[10].is_row 63cab7040953d051cd60e0e7ba70e18c\n\
+round[10].is_box 00102030405060708090a0b0c0d0e0f0\n\
+round[10].ik_sch 000102030405060708090a0b0c0d0e0f\n\
+round[10].ioutput 00112233445566778899aabbccddeeff";
+
+/// FIPS-197 Appendix C.2, AES-192.
+const C2_CIPHER: &'static str = "\
+round[ 0].input 00112233445566778899aabbccddeeff\n\
+round[ 0].k_sch 000102030405060708090a0b0c0d0e0f\n\
+round[ 1].start 00102030405060708090a0b0c0d0e0f0\n\
+round[ 1].s_box 63cab7040953d051cd60e0e7ba70e18c\n\
+round[ 1].s_row 6353e08c0960e104cd70b751bacad0e7\n\
+round[ 1].m_col 5f72641557f5bc92f7be3b291db9f91a\n\
+round[ 1].k_sch 10111213141516175846f2f95c43f4fe\n\
+round[ 2].start 4f63760643e0aa85aff8c9d041fa0de4\n\
+round[ 2].s_box 84fb386f1ae1ac977941dd70832dd769\n\
+round[ 2].s_row 84e1dd691a41d76f792d389783fbac70\n\
+round[ 2].m_col 9f487f794f955f662afc86abd7f1ab29\n\
+round[ 2].k_sch 544afef55847f0fa4856e2e95c43f4fe\n\
+round[ 3].start cb02818c17d2af9c62aa64428bb25fd7\n\
+round[ 3].s_box 1f770c64f0b579deaaac432c3d37cf0e\n\
+round[ 3].s_row 1fb5430ef0accf64aa370cde3d77792c\n\
+round[ 3].m_col b7a53ecbbf9d75a0c40efc79b674cc11\n\
+round[ 3].k_sch 40f949b31cbabd4d48f043b810b7b342\n\
+round[ 4].start f75c7778a327c8ed8cfebfc1a6c37f53\n\
+round[ 4].s_box 684af5bc0acce85564bb0878242ed2ed\n\
+round[ 4].s_row 68cc08ed0abbd2bc642ef555244ae878\n\
+round[ 4].m_col 7a1e98bdacb6d1141a6944dd06eb2d3e\n\
+round[ 4].k_sch 58e151ab04a2a5557effb5416245080c\n\
+round[ 5].start 22ffc916a81474416496f19c64ae2532\n\
+round[ 5].s_box 9316dd47c2fa92834390a1de43e43f23\n\
+round[ 5].s_row 93faa123c2903f4743e4dd83431692de\n\
+round[ 5].m_col aaa755b34cffe57cef6f98e1f01c13e6\n\
+round[ 5].k_sch 2ab54bb43a02f8f662e3a95d66410c08\n\
+round[ 6].start 80121e0776fd1d8a8d8c31bc965d1fee\n\
+round[ 6].s_box cdc972c53854a47e5d64c765904cc028\n\
+round[ 6].s_row cd54c7283864c0c55d4c727e90c9a465\n\
+round[ 6].m_col 921f748fd96e937d622d7725ba8ba50c\n\
+round[ 6].k_sch f501857297448d7ebdf1c6ca87f33e3c\n\
+round[ 7].start 671ef1fd4e2a1e03dfdcb1ef3d789b30\n\
+round[ 7].s_box 8572a1542fe5727b9e86c8df27bc1404\n\
+round[ 7].s_row 85e5c8042f8614549ebca17b277272df\n\
+round[ 7].m_col e913e7b18f507d4b227ef652758acbcc\n\
+round[ 7].k_sch e510976183519b6934157c9ea351f1e0\n\
+round[ 8].start 0c0370d00c01e622166b8accd6db3a2c\n\
+round[ 8].s_box fe7b5170fe7c8e93477f7e4bf6b98071\n\
+round[ 8].s_row fe7c7e71fe7f807047b95193f67b8e4b\n\
+round[ 8].m_col 6cf5edf996eb0a069c4ef21cbfc25762\n\
+round[ 8].k_sch 1ea0372a995309167c439e77ff12051e\n\
+round[ 9].start 7255dad30fb80310e00d6c6b40d0527c\n\
+round[ 9].s_box 40fc5766766c7bcae1d7507f09700010\n\
+round[ 9].s_row 406c501076d70066e17057ca09fc7b7f\n\
+round[ 9].m_col 7478bcdce8a50b81d4327a9009188262\n\
+round[ 9].k_sch dd7e0e887e2fff68608fc842f9dcc154\n\
+round[10].start a906b254968af4e9b4bdb2d2f0c44336\n\
+round[10].s_box d36f3720907ebf1e8d7a37b58c1c1a05\n\
+round[10].s_row d37e3705907a1a208d1c371e8c6fbfb5\n\
+round[10].m_col 0d73cc2d8f6abe8b0cf2dd9bb83d422e\n\
+round[10].k_sch 859f5f237a8d5a3dc0c02952beefd63a\n\
+round[11].start 88ec930ef5e7e4b6cc32f4c906d29414\n\
+round[11].s_box c4cedcabe694694e4b23bfdd6fb522fa\n\
+round[11].s_row c494bffae62322ab4bb5dc4e6fce69dd\n\
+round[11].m_col 71d720933b6d677dc00b8f28238e0fb7\n\
+round[11].k_sch de601e7827bcdf2ca223800fd8aeda32\n\
+round[12].start afb73eeb1cd1b85162280f27fb20d585\n\
+round[12].s_box 79a9b2e99c3e6cd1aa3476cc0fb70397\n\
+round[12].s_row 793e76979c3403e9aab7b2d10fa96ccc\n\
+round[12].k_sch a4970a331a78dc09c418c271e3a41d5d\n\
+round[12].output dda97ca4864cdfe06eaf70a0ec0d7191";
+
+const C2_INV_CIPHER: &'static str = "\
+round[ 0].iinput dda97ca4864cdfe06eaf70a0ec0d7191\n\
+round[ 0].ik_sch a4970a331a78dc09c418c271e3a41d5d\n\
+round[ 1].istart 793e76979c3403e9aab7b2d10fa96ccc\n\
+round[ 1].is_row 79a9b2e99c3e6cd1aa3476cc0fb70397\n\
+round[ 1].is_box afb73eeb1cd1b85162280f27fb20d585\n\
+round[ 1].ik_sch de601e7827bcdf2ca223800fd8aeda32\n\
+round[ 1].ik_add 71d720933b6d677dc00b8f28238e0fb7\n\
+round[ 2].istart c494bffae62322ab4bb5dc4e6fce69dd\n\
+round[ 2].is_row c4cedcabe694694e4b23bfdd6fb522fa\n\
+round[ 2].is_box 88ec930ef5e7e4b6cc32f4c906d29414\n\
+round[ 2].ik_sch 859f5f237a8d5a3dc0c02952beefd63a\n\
+round[ 2].ik_add 0d73cc2d8f6abe8b0cf2dd9bb83d422e\n\
+round[ 3].istart d37e3705907a1a208d1c371e8c6fbfb5\n\
+round[ 3].is_row d36f3720907ebf1e8d7a37b58c1c1a05\n\
+round[ 3].is_box a906b254968af4e9b4bdb2d2f0c44336\n\
+round[ 3].ik_sch dd7e0e887e2fff68608fc842f9dcc154\n\
+round[ 3].ik_add 7478bcdce8a50b81d4327a9009188262\n\
+round[ 4].istart 406c501076d70066e17057ca09fc7b7f\n\
+round[ 4].is_row 40fc5766766c7bcae1d7507f09700010\n\
+round[ 4].is_box 7255dad30fb80310e00d6c6b40d0527c\n\
+round[ 4].ik_sch 1ea0372a995309167c439e77ff12051e\n\
+round[ 4].ik_add 6cf5edf996eb0a069c4ef21cbfc25762\n\
+round[ 5].istart fe7c7e71fe7f807047b95193f67b8e4b\n\
+round[ 5].is_row fe7b5170fe7c8e93477f7e4bf6b98071\n\
+round[ 5].is_box 0c0370d00c01e622166b8accd6db3a2c\n\
+round[ 5].ik_sch e510976183519b6934157c9ea351f1e0\n\
+round[ 5].ik_add e913e7b18f507d4b227ef652758acbcc\n\
+round[ 6].istart 85e5c8042f8614549ebca17b277272df\n\
+round[ 6].is_row 8572a1542fe5727b9e86c8df27bc1404\n\
+round[ 6].is_box 671ef1fd4e2a1e03dfdcb1ef3d789b30\n\
+round[ 6].ik_sch f501857297448d7ebdf1c6ca87f33e3c\n\
+round[ 6].ik_add 921f748fd96e937d622d7725ba8ba50c\n\
+round[ 7].istart cd54c7283864c0c55d4c727e90c9a465\n\
+round[ 7].is_row cdc972c53854a47e5d64c765904cc028\n\
+round[ 7].is_box 80121e0776fd1d8a8d8c31bc965d1fee\n\
+round[ 7].ik_sch 2ab54bb43a02f8f662e3a95d66410c08\n\
+round[ 7].ik_add aaa755b34cffe57cef6f98e1f01c13e6\n\
+round[ 8].istart 93faa123c2903f4743e4dd83431692de\n\
+round[ 8].is_row 9316dd47c2fa92834390a1de43e43f23\n\
+round[ 8].is_box 22ffc916a81474416496f19c64ae2532\n\
+round[ 8].ik_sch 58e151ab04a2a5557effb5416245080c\n\
+round[ 8].ik_add 7a1e98bdacb6d1141a6944dd06eb2d3e\n\
+round[ 9].istart 68cc08ed0abbd2bc642ef555244ae878\n\
+round[ 9].is_row 684af5bc0acce85564bb0878242ed2ed\n\
+round[ 9].is_box f75c7778a327c8ed8cfebfc1a6c37f53\n\
+round[ 9].ik_sch 40f949b31cbabd4d48f043b810b7b342\n\
+round[ 9].ik_add b7a53ecbbf9d75a0c40efc79b674cc11\n\
+round[10].istart 1fb5430ef0accf64aa370cde3d77792c\n\
+round[10].is_row 1f770c64f0b579deaaac432c3d37cf0e\n\
+round[10].is_box cb02818c17d2af9c62aa64428bb25fd7\n\
+round[10].ik_sch 544afef55847f0fa4856e2e95c43f4fe\n\
+round[10].ik_add 9f487f794f955f662afc86abd7f1ab29\n\
+round[11].istart 84e1dd691a41d76f792d389783fbac70\n\
+round[11].is_row 84fb386f1ae1ac977941dd70832dd769\n\
+round[11].is_box 4f63760643e0aa85aff8c9d041fa0de4\n\
+round[11].ik_sch 10111213141516175846f2f95c43f4fe\n\
+round[11].ik_add 5f72641557f5bc92f7be3b291db9f91a\n\
+round[12].istart 6353e08c0960e104cd70b751bacad0e7\n\
+round[12].is_row 63cab7040953d051cd60e0e7ba70e18c\n\
+round[12].is_box 00102030405060708090a0b0c0d0e0f0\n\
+round[12].ik_sch 000102030405060708090a0b0c0d0e0f\n\
+round[12].ioutput 00112233445566778899aabbccddeeff";
+
+/// FIPS-197 Appendix C.3, AES-256.
+const C3_CIPHER: &'static str = "\
+round[ 0].input 00112233445566778899aabbccddeeff\n\
+round[ 0].k_sch 000102030405060708090a0b0c0d0e0f\n\
+round[ 1].start 00102030405060708090a0b0c0d0e0f0\n\
+round[ 1].s_box 63cab7040953d051cd60e0e7ba70e18c\n\
+round[ 1].s_row 6353e08c0960e104cd70b751bacad0e7\n\
+round[ 1].m_col 5f72641557f5bc92f7be3b291db9f91a\n\
+round[ 1].k_sch 101112131415161718191a1b1c1d1e1f\n\
+round[ 2].start 4f63760643e0aa85efa7213201a4e705\n\
+round[ 2].s_box 84fb386f1ae1ac97df5cfd237c49946b\n\
+round[ 2].s_row 84e1fd6b1a5c946fdf4938977cfbac23\n\
+round[ 2].m_col bd2a395d2b6ac438d192443e615da195\n\
+round[ 2].k_sch a573c29fa176c498a97fce93a572c09c\n\
+round[ 3].start 1859fbc28a1c00a078ed8aadc42f6109\n\
+round[ 3].s_box adcb0f257e9c63e0bc557e951c15ef01\n\
+round[ 3].s_row ad9c7e017e55ef25bc150fe01ccb6395\n\
+round[ 3].m_col 810dce0cc9db8172b3678c1e88a1b5bd\n\
+round[ 3].k_sch 1651a8cd0244beda1a5da4c10640bade\n\
+round[ 4].start 975c66c1cb9f3fa8a93a28df8ee10f63\n\
+round[ 4].s_box 884a33781fdb75c2d380349e19f876fb\n\
+round[ 4].s_row 88db34fb1f807678d3f833c2194a759e\n\
+round[ 4].m_col b2822d81abe6fb275faf103a078c0033\n\
+round[ 4].k_sch ae87dff00ff11b68a68ed5fb03fc1567\n\
+round[ 5].start 1c05f271a417e04ff921c5c104701554\n\
+round[ 5].s_box 9c6b89a349f0e18499fda678f2515920\n\
+round[ 5].s_row 9cf0a62049fd59a399518984f26be178\n\
+round[ 5].m_col aeb65ba974e0f822d73f567bdb64c877\n\
+round[ 5].k_sch 6de1f1486fa54f9275f8eb5373b8518d\n\
+round[ 6].start c357aae11b45b7b0a2c7bd28a8dc99fa\n\
+round[ 6].s_box 2e5bacf8af6ea9e73ac67a34c286ee2d\n\
+round[ 6].s_row 2e6e7a2dafc6eef83a86ace7c25ba934\n\
+round[ 6].m_col b951c33c02e9bd29ae25cdb1efa08cc7\n\
+round[ 6].k_sch c656827fc9a799176f294cec6cd5598b\n\
+round[ 7].start 7f074143cb4e243ec10c815d8375d54c\n\
+round[ 7].s_box d2c5831a1f2f36b278fe0c4cec9d0329\n\
+round[ 7].s_row d22f0c291ffe031a789d83b2ecc5364c\n\
+round[ 7].m_col ebb19e1c3ee7c9e87d7535e9ed6b9144\n\
+round[ 7].k_sch 3de23a75524775e727bf9eb45407cf39\n\
+round[ 8].start d653a4696ca0bc0f5acaab5db96c5e7d\n\
+round[ 8].s_box f6ed49f950e06576be74624c565058ff\n\
+round[ 8].s_row f6e062ff507458f9be50497656ed654c\n\
+round[ 8].m_col 5174c8669da98435a8b3e62ca974a5ea\n\
+round[ 8].k_sch 0bdc905fc27b0948ad5245a4c1871c2f\n\
+round[ 9].start 5aa858395fd28d7d05e1a38868f3b9c5\n\
+round[ 9].s_box bec26a12cfb55dff6bf80ac4450d56a6\n\
+round[ 9].s_row beb50aa6cff856126b0d6aff45c25dc4\n\
+round[ 9].m_col 0f77ee31d2ccadc05430a83f4ef96ac3\n\
+round[ 9].k_sch 45f5a66017b2d387300d4d33640a820a\n\
+round[10].start 4a824851c57e7e47643de50c2af3e8c9\n\
+round[10].s_box d61352d1a6f3f3a04327d9fee50d9bdd\n\
+round[10].s_row d6f3d9dda6279bd1430d52a0e513f3fe\n\
+round[10].m_col bd86f0ea748fc4f4630f11c1e9331233\n\
+round[10].k_sch 7ccff71cbeb4fe5413e6bbf0d261a7df\n\
+round[11].start c14907f6ca3b3aa070e9aa313b52b5ec\n\
+round[11].s_box 783bc54274e280e0511eacc7e200d5ce\n\
+round[11].s_row 78e2acce741ed5425100c5e0e23b80c7\n\
+round[11].m_col af8690415d6e1dd387e5fbedd5c89013\n\
+round[11].k_sch f01afafee7a82979d7a5644ab3afe640\n\
+round[12].start 5f9c6abfbac634aa50409fa766677653\n\
+round[12].s_box cfde0208f4b418ac5309db5c338538ed\n\
+round[12].s_row cfb4dbedf4093808538502ac33de185c\n\
+round[12].m_col 7427fae4d8a695269ce83d315be0392b\n\
+round[12].k_sch 2541fe719bf500258813bbd55a721c0a\n\
+round[13].start 516604954353950314fb86e401922521\n\
+round[13].s_box d133f22a1aed2a7bfa0f44697c4f3ffd\n\
+round[13].s_row d1ed44fd1a0f3f2afa4ff27b7c332a69\n\
+round[13].m_col 2c21a820306f154ab712c75eee0da04f\n\
+round[13].k_sch 4e5a6699a9f24fe07e572baacdf8cdea\n\
+round[14].start 627bceb9999d5aaac945ecf423f56da5\n\
+round[14].s_box aa218b56ee5ebeacdd6ecebf26e63c06\n\
+round[14].s_row aa5ece06ee6e3c56dde68bac2621bebf\n\
+round[14].k_sch 24fc79ccbf0979e9371ac23c6d68de36\n\
+round[14].output 8ea2b7ca516745bfeafc49904b496089";
+
+const C3_INV_CIPHER: &'static str = "\
+round[ 0].iinput 8ea2b7ca516745bfeafc49904b496089\n\
+round[ 0].ik_sch 24fc79ccbf0979e9371ac23c6d68de36\n\
+round[ 1].istart aa5ece06ee6e3c56dde68bac2621bebf\n\
+round[ 1].is_row aa218b56ee5ebeacdd6ecebf26e63c06\n\
+round[ 1].is_box 627bceb9999d5aaac945ecf423f56da5\n\
+round[ 1].ik_sch 4e5a6699a9f24fe07e572baacdf8cdea\n\
+round[ 1].ik_add 2c21a820306f154ab712c75eee0da04f\n\
+round[ 2].istart d1ed44fd1a0f3f2afa4ff27b7c332a69\n\
+round[ 2].is_row d133f22a1aed2a7bfa0f44697c4f3ffd\n\
+round[ 2].is_box 516604954353950314fb86e401922521\n\
+round[ 2].ik_sch 2541fe719bf500258813bbd55a721c0a\n\
+round[ 2].ik_add 7427fae4d8a695269ce83d315be0392b\n\
+round[ 3].istart cfb4dbedf4093808538502ac33de185c\n\
+round[ 3].is_row cfde0208f4b418ac5309db5c338538ed\n\
+round[ 3].is_box 5f9c6abfbac634aa50409fa766677653\n\
+round[ 3].ik_sch f01afafee7a82979d7a5644ab3afe640\n\
+round[ 3].ik_add af8690415d6e1dd387e5fbedd5c89013\n\
+round[ 4].istart 78e2acce741ed5425100c5e0e23b80c7\n\
+round[ 4].is_row 783bc54274e280e0511eacc7e200d5ce\n\
+round[ 4].is_box c14907f6ca3b3aa070e9aa313b52b5ec\n\
+round[ 4].ik_sch 7ccff71cbeb4fe5413e6bbf0d261a7df\n\
+round[ 4].ik_add bd86f0ea748fc4f4630f11c1e9331233\n\
+round[ 5].istart d6f3d9dda6279bd1430d52a0e513f3fe\n\
+round[ 5].is_row d61352d1a6f3f3a04327d9fee50d9bdd\n\
+round[ 5].is_box 4a824851c57e7e47643de50c2af3e8c9\n\
+round[ 5].ik_sch 45f5a66017b2d387300d4d33640a820a\n\
+round[ 5].ik_add 0f77ee31d2ccadc05430a83f4ef96ac3\n\
+round[ 6].istart beb50aa6cff856126b0d6aff45c25dc4\n\
+round[ 6].is_row bec26a12cfb55dff6bf80ac4450d56a6\n\
+round[ 6].is_box 5aa858395fd28d7d05e1a38868f3b9c5\n\
+round[ 6].ik_sch 0bdc905fc27b0948ad5245a4c1871c2f\n\
+round[ 6].ik_add 5174c8669da98435a8b3e62ca974a5ea\n\
+round[ 7].istart f6e062ff507458f9be50497656ed654c\n\
+round[ 7].is_row f6ed49f950e06576be74624c565058ff\n\
+round[ 7].is_box d653a4696ca0bc0f5acaab5db96c5e7d\n\
+round[ 7].ik_sch 3de23a75524775e727bf9eb45407cf39\n\
+round[ 7].ik_add ebb19e1c3ee7c9e87d7535e9ed6b9144\n\
+round[ 8].istart d22f0c291ffe031a789d83b2ecc5364c\n\
+round[ 8].is_row d2c5831a1f2f36b278fe0c4cec9d0329\n\
+round[ 8].is_box 7f074143cb4e243ec10c815d8375d54c\n\
+round[ 8].ik_sch c656827fc9a799176f294cec6cd5598b\n\
+round[ 8].ik_add b951c33c02e9bd29ae25cdb1efa08cc7\n\
+round[ 9].istart 2e6e7a2dafc6eef83a86ace7c25ba934\n\
+round[ 9].is_row 2e5bacf8af6ea9e73ac67a34c286ee2d\n\
+round[ 9].is_box c357aae11b45b7b0a2c7bd28a8dc99fa\n\
+round[ 9].ik_sch 6de1f1486fa54f9275f8eb5373b8518d\n\
+round[ 9].ik_add aeb65ba974e0f822d73f567bdb64c877\n\
+round[10].istart 9cf0a62049fd59a399518984f26be178\n\
+round[10].is_row 9c6b89a349f0e18499fda678f2515920\n\
+round[10].is_box 1c05f271a417e04ff921c5c104701554\n\
+round[10].ik_sch ae87dff00ff11b68a68ed5fb03fc1567\n\
+round[10].ik_add b2822d81abe6fb275faf103a078c0033\n\
+round[11].istart 88db34fb1f807678d3f833c2194a759e\n\
+round[11].is_row 884a33781fdb75c2d380349e19f876fb\n\
+round[11].is_box 975c66c1cb9f3fa8a93a28df8ee10f63\n\
+round[11].ik_sch 1651a8cd0244beda1a5da4c10640bade\n\
+round[11].ik_add 810dce0cc9db8172b3678c1e88a1b5bd\n\
+round[12].istart ad9c7e017e55ef25bc150fe01ccb6395\n\
+round[12].is_row adcb0f257e9c63e0bc557e951c15ef01\n\
+round[12].is_box 1859fbc28a1c00a078ed8aadc42f6109\n\
+round[12].ik_sch a573c29fa176c498a97fce93a572c09c\n\
+round[12].ik_add bd2a395d2b6ac438d192443e615da195\n\
+round[13].istart 84e1fd6b1a5c946fdf4938977cfbac23\n\
+round[13].is_row 84fb386f1ae1ac97df5cfd237c49946b\n\
+round[13].is_box 4f63760643e0aa85efa7213201a4e705\n\
+round[13].ik_sch 101112131415161718191a1b1c1d1e1f\n\
+round[13].ik_add 5f72641557f5bc92f7be3b291db9f91a\n\
+round[14].istart 6353e08c0960e104cd70b751bacad0e7\n\
+round[14].is_row 63cab7040953d051cd60e0e7ba70e18c\n\
+round[14].is_box 00102030405060708090a0b0c0d0e0f0\n\
+round[14].ik_sch 000102030405060708090a0b0c0d0e0f\n\
+round[14].ioutput 00112233445566778899aabbccddeeff";
+
+
+fn unhex(s: &str) -> [u8; 16] {
+    let mut out = [0; 16];
+    for (i, b) in out.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    out
+}
+
+fn lines(trace: &[Trace]) -> String {
+    trace.iter().map(|t| t.to_string()).collect::<Vec<_>>().join("\n")
+}
+
+macro_rules! check {
+    ( $cipher:expr, $plaintext:expr, $expected:expr, $inv_expected:expr ) => {{
+        let cipher = $cipher;
+        let mut block = unhex($plaintext);
+        let mut trace = Vec::new();
+        cipher.encrypt_trace(&mut block, |t| trace.push(t));
+        assert_eq!(lines(&trace), $expected);
+
+        let mut inv_trace = Vec::new();
+        cipher.decrypt_trace(&mut block, |t| inv_trace.push(t));
+        assert_eq!(lines(&inv_trace), $inv_expected);
+        assert_eq!(block, unhex($plaintext));
+    }}
+}
+
+
+#[test]
+fn test_trace_appendix_b() {
+    check!(
+        AES::new(&unhex("2b7e151628aed2a6abf7158809cf4f3c")).unwrap(),
+        "3243f6a8885a308d313198a2e0370734",
+        APPENDIX_B_CIPHER, APPENDIX_B_INV_CIPHER
+    );
+}
+
+#[test]
+fn test_trace_appendix_c() {
+    let key: Vec<u8> = (0..32).collect();
+    let plaintext = "00112233445566778899aabbccddeeff";
+
+    let mut key128 = [0; 16];
+    key128.copy_from_slice(&key[..16]);
+    check!(Aes128::new(&key128), plaintext, C1_CIPHER, C1_INV_CIPHER);
+    check!(AES::new(&key[..16]).unwrap(), plaintext, C1_CIPHER, C1_INV_CIPHER);
+
+    let mut key192 = [0; 24];
+    key192.copy_from_slice(&key[..24]);
+    check!(Aes192::new(&key192), plaintext, C2_CIPHER, C2_INV_CIPHER);
+
+    let mut key256 = [0; 32];
+    key256.copy_from_slice(&key);
+    check!(Aes256::new(&key256), plaintext, C3_CIPHER, C3_INV_CIPHER);
+}
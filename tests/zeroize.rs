@@ -0,0 +1,354 @@
+extern crate rand;
+extern crate aes;
+
+#[path = "./rand.rs"]
+#[macro_use] mod rand_macro;
+
+use std::{ fmt, mem, ptr, slice };
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::sync::atomic::{ AtomicBool, Ordering };
+use aes::{ AES, Aes128, Aes256 };
+use aes::mode::{ Ctr, Xex, Gcm, Cfb128, Ofb, Ige, Pcbc, Ccm, Eax, Ocb3, Siv, GcmSiv };
+use aes::mac::{ CbcMac, RetailMac, Cmac, Gmac, Pmac, Xcbc };
+use aes::utils::ghash::Ghash;
+use aes::utils::dbl;
+use aes::utils::xor;
+use aes::utils::padding::NoPadding;
+use aes::utils::zeroize::Zeroize;
+use aes::cipher::{ SingleBlockEncrypt, BlockEncrypt, StreamEncrypt, AeadStreamEncrypt };
+
+
+/// Flags any freed heap block that still holds `NEEDLE`.
+struct Watch;
+
+static ARMED: AtomicBool = AtomicBool::new(false);
+static FOUND: AtomicBool = AtomicBool::new(false);
+static mut NEEDLE: [u8; 8] = [0; 8];
+
+unsafe impl GlobalAlloc for Watch {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ARMED.load(Ordering::SeqCst) {
+            let freed = slice::from_raw_parts(ptr, layout.size());
+            if freed.windows(8).any(|w| w == &NEEDLE[..]) {
+                FOUND.store(true, Ordering::SeqCst);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static WATCH: Watch = Watch;
+
+/// Does `f` free heap memory that still holds the first 8 bytes of `secret`?
+fn leaks<F: FnOnce()>(secret: &[u8], f: F) -> bool {
+    unsafe { NEEDLE.copy_from_slice(&secret[..8]) };
+    FOUND.store(false, Ordering::SeqCst);
+    ARMED.store(true, Ordering::SeqCst);
+    f();
+    ARMED.store(false, Ordering::SeqCst);
+    FOUND.load(Ordering::SeqCst)
+}
+
+fn contains<T>(value: &T, secret: &[u8]) -> bool {
+    let bytes = unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) };
+    bytes.windows(secret.len()).any(|w| w == secret)
+}
+
+/// Does the `Debug` output show the bytes of `secret`, as a `Vec` field would print them?
+fn prints<T: fmt::Debug>(value: &T, secret: &[u8]) -> bool {
+    let secret = format!("{:?}", secret);
+    format!("{:?}", value).contains(&secret[1..secret.len() - 1])
+}
+
+/// Run the destructor, then look at what it left behind.
+fn contains_after_drop<T>(value: T, secret: &[u8]) -> bool {
+    let mut value = mem::ManuallyDrop::new(value);
+    unsafe { ptr::drop_in_place(&mut *value) };
+    contains(&*value, secret)
+}
+
+
+#[test]
+fn test_zeroize() {
+    let mut data: Vec<u8> = rand!(33);
+    data.truncate(7);
+    data.zeroize();
+    assert!(data.is_empty());
+
+    let mut block: [u8; 16] = rand!(_);
+    block.zeroize();
+    assert_eq!(block, [0; 16]);
+
+    let mut ghash = Ghash::new(&rand!(16)).unwrap();
+    ghash.update_aad(&rand!(20)).unwrap().update_ciphertext(&rand!(20));
+    ghash.zeroize();
+    assert_eq!(ghash.finalize(), [0; 16]);
+}
+
+#[test]
+fn test_drop_inline() {
+    let key: [u8; 16] = rand!(_);
+    let cipher = Aes128::portable(&key);
+    assert!(contains(&cipher, &key));
+    assert!(!contains_after_drop(cipher, &key));
+
+    let key: [u8; 32] = rand!(_);
+    let cipher = Aes256::new(&key);
+    assert!(contains(&cipher, &key));
+    assert!(!contains_after_drop(cipher, &key[..16]));
+    assert!(!contains_after_drop(Aes256::new(&key), &key[16..]));
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_drop_aesni() {
+    use aes::aesni::AesNi;
+
+    let key: [u8; 16] = rand!(_);
+    if let Some(cipher) = AesNi::new(&key) {
+        assert!(contains(&cipher, &key));
+        assert!(!contains_after_drop(cipher, &key));
+    }
+}
+
+#[test]
+fn test_drop_heap() {
+    let key: Vec<u8> = rand!(32);
+    assert!(leaks(&key, || drop(key.clone())));
+    assert!(!leaks(&key, || drop(AES::portable(&key).unwrap())));
+    assert!(!leaks(&key[16..], || drop(AES::new(&key).unwrap())));
+
+    let key: [u8; 16] = rand!(_);
+    let counter = rand!(16);
+    let keystream = Aes128::new(&key).encrypt(&counter).unwrap();
+    assert!(!leaks(&keystream[1..], || {
+        let mut cipher = Ctr::<Aes128>::new(&key, &counter).unwrap();
+        cipher.encrypt(&[0]).unwrap();
+    }));
+
+    let i = rand!(16);
+    let tweak = Aes128::new(&key).encrypt(&i).unwrap();
+    assert!(!leaks(&tweak, || {
+        let mut cipher = Xex::<Aes128>::new(&key, &key, &i).unwrap();
+        cipher.encrypt::<NoPadding>(&rand!(32)).unwrap();
+    }));
+
+    let nonce = rand!(12);
+    let hash_key = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    let end_tag = Aes128::new(&key).encrypt(&[&nonce[..], &[0, 0, 0, 1]].concat()).unwrap();
+    for secret in &[hash_key, end_tag] {
+        assert!(!leaks(secret, || {
+            let mut cipher = Gcm::<Aes128>::new(&key, &nonce, &nonce).unwrap();
+            cipher.encrypt(&rand!(20)).unwrap();
+        }));
+    }
+
+    let data = rand!(40);
+    let tag = CbcMac::<Aes128>::new(&key).unwrap().compute(&data).unwrap();
+    assert!(!leaks(&tag, || {
+        let mut mac = CbcMac::<Aes128>::new(&key).unwrap();
+        mac.set_tag_len(4).unwrap().compute(&data).unwrap();
+    }));
+    let key2: [u8; 16] = rand!(_);
+    let tag = RetailMac::<Aes128>::new(&key, &key2).unwrap().compute(&data).unwrap();
+    assert!(!leaks(&tag, || {
+        let mut mac = RetailMac::<Aes128>::new(&key, &key2).unwrap();
+        mac.set_tag_len(4).unwrap().compute(&data).unwrap();
+    }));
+
+    let iv = rand!(16);
+    let keystream = Aes128::new(&key).encrypt(&iv).unwrap();
+    assert!(!leaks(&keystream[1..], || {
+        let mut cipher = Cfb128::<Aes128>::new(&key, &iv).unwrap();
+        cipher.encrypt(&[0]).unwrap();
+    }));
+
+    let iv = rand!(16);
+    let keystream = Aes128::new(&key).encrypt(&iv).unwrap();
+    assert!(!leaks(&keystream[1..], || {
+        let mut cipher = Ofb::<Aes128>::new(&key, &iv).unwrap();
+        cipher.encrypt(&[0]).unwrap();
+    }));
+
+    let plaintext = rand!(32);
+    assert!(!leaks(&plaintext[16..], || {
+        let mut cipher = Ige::<Aes128>::new(&key, &rand!(32)).unwrap();
+        cipher.encrypt::<NoPadding>(&plaintext).unwrap();
+    }));
+
+    let plaintext = rand!(16);
+    let iv = rand!(16);
+    let ciphertext = Pcbc::<Aes128>::new(&key, &iv).unwrap().encrypt::<NoPadding>(&plaintext).unwrap();
+    assert!(!leaks(&xor(&plaintext, &ciphertext), || {
+        let mut cipher = Pcbc::<Aes128>::new(&key, &iv).unwrap();
+        cipher.encrypt::<NoPadding>(&plaintext).unwrap();
+    }));
+
+    let nonce = rand!(13);
+    let s0 = Aes128::new(&key).encrypt(&[&[1][..], &nonce, &[0, 0]].concat()).unwrap();
+    let keystream = Aes128::new(&key).encrypt(&[&[1][..], &nonce, &[0, 1]].concat()).unwrap();
+    for secret in &[s0, keystream[1..].to_vec()] {
+        assert!(!leaks(secret, || {
+            let mut cipher = Ccm::<Aes128>::new(&key, &nonce, &nonce, 16).unwrap();
+            cipher.encrypt(&[0]).unwrap();
+        }));
+    }
+
+    let nonce = rand!(16);
+    let mut omac = Cmac::<Aes128>::new(&key).unwrap();
+    let n = omac.input(&[0; 16]).unwrap().input(&nonce).unwrap().result().unwrap();
+    let h = omac.reset().input(&[&[0; 15][..], &[1]].concat()).unwrap().input(&data).unwrap().result().unwrap();
+    let tag_mask = xor(&n, &h);
+    assert!(!leaks(&tag_mask, || {
+        let mut cipher = Eax::<Aes128>::new(&key, &nonce, &data).unwrap();
+        cipher.encrypt(&rand!(20)).unwrap();
+    }));
+
+    let l_star = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    assert!(!leaks(&l_star, || {
+        let mut cipher = Ocb3::<Aes128>::new(&key, &rand!(12), &data, 16).unwrap();
+        cipher.encrypt(&rand!(40)).unwrap();
+    }));
+
+    let mut k1 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    dbl(&mut k1).unwrap();
+    assert!(!leaks(&k1, || {
+        let mut cipher = Siv::<Aes128>::new(&key, &key2, &[&data]).unwrap();
+        cipher.encrypt(&rand!(20)).unwrap();
+    }));
+
+    let nonce = rand!(12);
+    let hash_key = Aes128::new(&key).encrypt(&[&[0; 4][..], &nonce].concat()).unwrap();
+    assert!(!leaks(&hash_key, || {
+        let mut cipher = GcmSiv::<Aes128>::new(&key, &nonce, &data).unwrap();
+        cipher.encrypt(&rand!(20)).unwrap();
+    }));
+
+    let mut k1 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    dbl(&mut k1).unwrap();
+    let mut k2 = k1.clone();
+    dbl(&mut k2).unwrap();
+    for secret in &[k1, k2] {
+        assert!(!leaks(secret, || {
+            let mut mac = Cmac::<Aes128>::new(&key).unwrap();
+            mac.input(&data).unwrap().result().unwrap();
+        }));
+    }
+
+    let nonce = rand!(12);
+    let end_tag = Aes128::new(&key).encrypt(&[&nonce[..], &[0, 0, 0, 1]].concat()).unwrap();
+    assert!(!leaks(&end_tag, || {
+        let mut mac = Gmac::<Aes128>::new(&key, &nonce).unwrap();
+        mac.input(&data).unwrap().result();
+    }));
+
+    let l0 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    assert!(!leaks(&l0, || {
+        let mut mac = Pmac::<Aes128>::new(&key).unwrap();
+        let mut tail = mac.part(1);
+        tail.input(&data[16..]).unwrap();
+        mac.input(&data[..16]).unwrap().merge(&tail).unwrap().result().unwrap();
+    }));
+
+    let k2 = Aes128::new(&key).encrypt(&[0x02; 16]).unwrap();
+    let k3 = Aes128::new(&key).encrypt(&[0x03; 16]).unwrap();
+    for secret in &[k2, k3] {
+        assert!(!leaks(secret, || {
+            let mut mac = Xcbc::new(&key).unwrap();
+            mac.input(&data).unwrap().result().unwrap();
+        }));
+    }
+}
+
+#[test]
+fn test_debug_redacted() {
+    let key: [u8; 16] = rand!(_);
+    let i = rand!(16);
+
+    assert_eq!(format!("{:?}", AES::new(&key).unwrap()), "AES { .. }");
+    assert_eq!(format!("{:?}", Aes128::new(&key)), "Aes128 { .. }");
+    assert_eq!(format!("{:?}", Ghash::new(&key).unwrap()), "Ghash { .. }");
+
+    let tweak = Aes128::new(&key).encrypt(&i).unwrap();
+    let xex = format!("{:?}", Xex::<Aes128>::new(&key, &key, &i).unwrap());
+    assert!(!xex.contains(&format!("{:?}", tweak)));
+
+    let data = rand!(40);
+    let mut mac = CbcMac::<Aes128>::new(&key).unwrap();
+    let tag = mac.compute(&data).unwrap();
+    mac.set_tag_len(4).unwrap().compute(&data).unwrap();
+    assert_eq!(format!("{:?}", mac), "CbcMac { tag_len: 4 }");
+    assert!(!format!("{:?}", mac).contains(&format!("{:?}", &tag[4..])));
+
+    let iv = rand!(16);
+    let keystream = Aes128::new(&key).encrypt(&iv).unwrap();
+    let mut cfb = Cfb128::<Aes128>::new(&key, &iv).unwrap();
+    cfb.encrypt(&[0]).unwrap();
+    assert!(!prints(&cfb, &keystream[1..]));
+
+    let iv = rand!(16);
+    let keystream = Aes128::new(&key).encrypt(&iv).unwrap();
+    let mut ofb = Ofb::<Aes128>::new(&key, &iv).unwrap();
+    ofb.encrypt(&[0]).unwrap();
+    assert!(!prints(&ofb, &keystream));
+
+    let plaintext = rand!(16);
+    let mut ige = Ige::<Aes128>::new(&key, &rand!(32)).unwrap();
+    ige.encrypt::<NoPadding>(&plaintext).unwrap();
+    assert!(!prints(&ige, &plaintext));
+
+    let plaintext = rand!(16);
+    let mut pcbc = Pcbc::<Aes128>::new(&key, &i).unwrap();
+    let ciphertext = pcbc.encrypt::<NoPadding>(&plaintext).unwrap();
+    assert!(!prints(&pcbc, &xor(&plaintext, &ciphertext)));
+
+    let nonce = rand!(13);
+    let keystream = Aes128::new(&key).encrypt(&[&[1][..], &nonce, &[0, 1]].concat()).unwrap();
+    let mut ccm = Ccm::<Aes128>::new(&key, &nonce, &nonce, 16).unwrap();
+    ccm.encrypt(&[0]).unwrap();
+    assert!(!prints(&ccm, &keystream[1..]));
+
+    let mut omac = Cmac::<Aes128>::new(&key).unwrap();
+    let n = omac.input(&[0; 16]).unwrap().input(&i).unwrap().result().unwrap();
+    let h = omac.reset().input(&[&[0; 15][..], &[1]].concat()).unwrap().input(&data).unwrap().result().unwrap();
+    let eax = Eax::<Aes128>::new(&key, &i, &data).unwrap();
+    assert!(!prints(&eax, &xor(&n, &h)));
+
+    let l_star = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    let ocb = Ocb3::<Aes128>::new(&key, &rand!(12), &data, 16).unwrap();
+    assert!(!prints(&ocb, &l_star));
+
+    let mut k1 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    dbl(&mut k1).unwrap();
+    let siv = Siv::<Aes128>::new(&key, &key, &[&data]).unwrap();
+    assert!(!prints(&siv, &k1));
+
+    let nonce = rand!(12);
+    let hash_key = Aes128::new(&key).encrypt(&[&[0; 4][..], &nonce].concat()).unwrap();
+    let siv = GcmSiv::<Aes128>::new(&key, &nonce, &data).unwrap();
+    assert!(!prints(&siv, &hash_key[..8]));
+
+    let mut k1 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    dbl(&mut k1).unwrap();
+    let cmac = Cmac::<Aes128>::new(&key).unwrap();
+    assert!(!prints(&cmac, &k1));
+
+    let nonce = rand!(12);
+    let end_tag = Aes128::new(&key).encrypt(&[&nonce[..], &[0, 0, 0, 1]].concat()).unwrap();
+    let gmac = Gmac::<Aes128>::new(&key, &nonce).unwrap();
+    assert!(!prints(&gmac, &end_tag));
+
+    let l0 = Aes128::new(&key).encrypt(&[0; 16]).unwrap();
+    let pmac = Pmac::<Aes128>::new(&key).unwrap();
+    assert!(!prints(&pmac, &l0));
+    assert!(!prints(&pmac.part(1), &l0));
+
+    let k2 = Aes128::new(&key).encrypt(&[0x02; 16]).unwrap();
+    let xcbc = Xcbc::new(&key).unwrap();
+    assert!(!prints(&xcbc, &k2));
+}